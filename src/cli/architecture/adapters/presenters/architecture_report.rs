@@ -0,0 +1,156 @@
+use crate::cli::architecture::{
+    application::{
+        commands::diagnose_project_use_case::DiagnoseProjectResponseModel,
+        queries::check_architecture_use_case::{
+            CheckArchitectureResponseModel,
+            DependencyCycleReadModel,
+        },
+    },
+    domain::enums::structural_issue_kind::StructuralIssueKind,
+};
+
+pub struct ReportFinding {
+    pub rule: String,
+    pub level: String,
+    pub message: String,
+    pub path: Option<String>,
+    pub line: Option<usize>,
+}
+
+pub struct ArchitectureReport {
+    pub name: String,
+    pub rules: Vec<String>,
+    pub findings: Vec<ReportFinding>,
+}
+
+impl ArchitectureReport {
+    const AGGREGATE_CYCLE_RULE: &'static str = "aggregate-dependency-cycle";
+    const BOUNDED_CONTEXT_CYCLE_RULE: &'static str = "bounded-context-dependency-cycle";
+    const GLOSSARY_RULE: &'static str = "glossary-forbidden-term";
+
+    // Every format fails on the same findings, so a tree passing in CI passes
+    // locally as well.
+    pub fn has_errors(&self) -> bool {
+        self.findings.iter().any(|finding| finding.level == "error")
+    }
+
+    fn get_cycle_finding(rule: &str, cycle: &DependencyCycleReadModel) -> ReportFinding {
+        let import_chain = cycle.imports
+            .iter()
+            .map(|import| format!("{}:{}: {}", import.file_path, import.line, import.statement))
+            .collect::<Vec<_>>()
+            .join("\n");
+        ReportFinding {
+            rule: rule.to_string(),
+            level: "error".to_string(),
+            message: format!("Dependency cycle {}\n{}", cycle.modules.join(" -> "), import_chain),
+            path: cycle.imports.first().map(|import| import.file_path.clone()),
+            line: cycle.imports.first().map(|import| import.line),
+        }
+    }
+}
+
+impl From<&CheckArchitectureResponseModel> for ArchitectureReport {
+    fn from(response_model: &CheckArchitectureResponseModel) -> Self {
+        let mut findings = response_model.aggregate_cycles
+            .iter()
+            .map(|cycle| Self::get_cycle_finding(Self::AGGREGATE_CYCLE_RULE, cycle))
+            .collect::<Vec<_>>();
+        findings.extend(
+            response_model.bounded_context_cycles
+                .iter()
+                .map(|cycle| Self::get_cycle_finding(Self::BOUNDED_CONTEXT_CYCLE_RULE, cycle))
+        );
+        findings.extend(
+            response_model.glossary_violations.iter().map(|violation| ReportFinding {
+                rule: Self::GLOSSARY_RULE.to_string(),
+                level: (if violation.is_strict { "error" } else { "warning" }).to_string(),
+                message: format!(
+                    "<{}> uses the forbidden term <{}>; the <{}> glossary prefers <{}>",
+                    violation.name,
+                    violation.forbidden_term,
+                    violation.bounded_context_name,
+                    violation.preferred_term
+                ),
+                path: Some(violation.path.clone()),
+                line: None,
+            })
+        );
+        Self {
+            name: "arpeggio check".to_string(),
+            rules: vec![
+                Self::AGGREGATE_CYCLE_RULE.to_string(),
+                Self::BOUNDED_CONTEXT_CYCLE_RULE.to_string(),
+                Self::GLOSSARY_RULE.to_string()
+            ],
+            findings,
+        }
+    }
+}
+
+impl From<&DiagnoseProjectResponseModel> for ArchitectureReport {
+    fn from(response_model: &DiagnoseProjectResponseModel) -> Self {
+        Self {
+            name: "arpeggio doctor".to_string(),
+            rules: StructuralIssueKind::get_kinds()
+                .iter()
+                .map(|kind| kind.to_string())
+                .collect(),
+            findings: response_model.issues
+                .iter()
+                // Like the human report, any issue left unfixed fails `doctor`,
+                // whether `--fix` could repair it or not.
+                .map(|issue| ReportFinding {
+                    rule: issue.rule.clone(),
+                    level: "error".to_string(),
+                    message: issue.description.clone(),
+                    path: Some(issue.path.clone()),
+                    line: None,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::architecture::{
+            adapters::presenters::{
+                diagnose_project_presenter::DiagnoseProjectPresenter,
+                junit_report_presenter::JunitReportPresenter,
+                sarif_report_presenter::SarifReportPresenter,
+            },
+            application::commands::diagnose_project_use_case::{
+                DiagnoseProjectResponseModel,
+                StructuralIssueReadModel,
+            },
+        },
+        core::application::use_case_output_port::UseCaseOutputPort,
+    };
+
+    fn get_response_model() -> DiagnoseProjectResponseModel {
+        DiagnoseProjectResponseModel {
+            issues: vec![StructuralIssueReadModel {
+                rule: "undeclared-module".to_string(),
+                path: "src/sales/order/domain/entities/order.rs".to_string(),
+                description: "Module <order> is not declared".to_string(),
+                is_fixable: true,
+            }],
+            fixed_issues: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn should_fail_on_fixable_issues_in_every_format() {
+        let human_presenter = DiagnoseProjectPresenter::default();
+        human_presenter.success(get_response_model()).await;
+        let sarif_presenter = SarifReportPresenter::default();
+        sarif_presenter.success(get_response_model()).await;
+        let junit_presenter = JunitReportPresenter::default();
+        junit_presenter.success(get_response_model()).await;
+        assert!(human_presenter.has_failed());
+        assert!(sarif_presenter.has_failed());
+        assert!(junit_presenter.has_failed());
+    }
+}
@@ -0,0 +1,74 @@
+use std::{ error::Error, sync::atomic::{ AtomicBool, Ordering } };
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::application::queries::check_architecture_use_case::{
+    CheckArchitectureResponseModel,
+    DependencyCycleReadModel,
+    GlossaryViolationReadModel,
+};
+
+#[derive(Default)]
+pub struct CheckArchitecturePresenter {
+    has_failed: AtomicBool,
+}
+
+impl CheckArchitecturePresenter {
+    pub fn has_failed(&self) -> bool {
+        self.has_failed.load(Ordering::SeqCst)
+    }
+
+    fn print_cycles(&self, granularity: &str, cycles: &[DependencyCycleReadModel]) {
+        if cycles.is_empty() {
+            println!("No dependency cycles found between {}.", granularity);
+            return;
+        }
+        println!("Found {} dependency cycle(s) between {}:", cycles.len(), granularity);
+        cycles.iter().enumerate().for_each(|(index, cycle)| {
+            println!("  {}. {}", index + 1, cycle.modules.join(" -> "));
+            cycle.imports.iter().for_each(|import| {
+                println!("     {} -> {}", import.source_module, import.target_module);
+                println!("       {}:{}: {}", import.file_path, import.line, import.statement);
+            });
+        });
+    }
+
+    fn print_glossary_violations(&self, glossary_violations: &[GlossaryViolationReadModel]) {
+        if glossary_violations.is_empty() {
+            println!("No glossary violations found.");
+            return;
+        }
+        println!("Found {} glossary violation(s):", glossary_violations.len());
+        glossary_violations.iter().for_each(|violation| {
+            println!(
+                "  {}{}: <{}> uses the forbidden term <{}>; the <{}> glossary prefers <{}>",
+                if violation.is_strict { "" } else { "(warning) " },
+                violation.path,
+                violation.name,
+                violation.forbidden_term,
+                violation.bounded_context_name,
+                violation.preferred_term
+            );
+        });
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<CheckArchitectureResponseModel> for CheckArchitecturePresenter {
+    async fn success(&self, response_model: CheckArchitectureResponseModel) {
+        self.print_cycles("aggregates", &response_model.aggregate_cycles);
+        self.print_cycles("bounded contexts", &response_model.bounded_context_cycles);
+        self.print_glossary_violations(&response_model.glossary_violations);
+        if
+            !response_model.aggregate_cycles.is_empty() ||
+            !response_model.bounded_context_cycles.is_empty() ||
+            response_model.glossary_violations.iter().any(|violation| violation.is_strict)
+        {
+            self.has_failed.store(true, Ordering::SeqCst);
+        }
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        self.has_failed.store(true, Ordering::SeqCst);
+        eprintln!("Failed to check architecture due to: {}", error)
+    }
+}
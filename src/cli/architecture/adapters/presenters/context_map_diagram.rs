@@ -0,0 +1,74 @@
+use crate::cli::architecture::application::queries::find_context_map_use_case::{
+    FindContextMapResponseModel,
+};
+
+pub struct DiagramNode {
+    pub id: String,
+    pub title: String,
+    pub items: Vec<String>,
+}
+
+pub struct DiagramEdge {
+    pub source: String,
+    pub target: String,
+    pub label: String,
+    pub is_directed: bool,
+    pub is_observed: bool,
+}
+
+pub struct ContextMapDiagram {
+    pub nodes: Vec<DiagramNode>,
+    pub edges: Vec<DiagramEdge>,
+    pub warnings: Vec<String>,
+}
+
+impl ContextMapDiagram {
+    fn get_import_label(import_count: usize) -> String {
+        match import_count {
+            1 => "1 import".to_string(),
+            _ => format!("{} imports", import_count),
+        }
+    }
+}
+
+// Edges point from the downstream bounded context to the upstream one, the
+// same direction as the `use` statements they were discovered from.
+impl From<&FindContextMapResponseModel> for ContextMapDiagram {
+    fn from(response_model: &FindContextMapResponseModel) -> Self {
+        Self {
+            nodes: response_model.bounded_contexts
+                .iter()
+                .map(|bounded_context| DiagramNode {
+                    id: bounded_context.name.clone(),
+                    title: bounded_context.name.clone(),
+                    items: bounded_context.aggregates.clone(),
+                })
+                .collect(),
+            edges: response_model.links
+                .iter()
+                .map(|link| {
+                    let label = match (&link.relationship_type, link.import_count) {
+                        (Some(relationship_type), 0) => relationship_type.get_label().to_string(),
+                        (Some(relationship_type), import_count) =>
+                            format!(
+                                "{} ({})",
+                                relationship_type.get_label(),
+                                Self::get_import_label(import_count)
+                            ),
+                        (None, import_count) => Self::get_import_label(import_count),
+                    };
+                    DiagramEdge {
+                        source: link.downstream.clone(),
+                        target: link.upstream.clone(),
+                        label,
+                        is_directed: link.relationship_type
+                            .iter()
+                            .all(|relationship_type| !relationship_type.is_symmetric()),
+                        is_observed: link.import_count > 0,
+                    }
+                })
+                .collect(),
+            warnings: response_model.warnings.clone(),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::{ error::Error, sync::atomic::{ AtomicBool, Ordering } };
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::application::commands::diagnose_project_use_case::DiagnoseProjectResponseModel;
+
+#[derive(Default)]
+pub struct DiagnoseProjectPresenter {
+    has_failed: AtomicBool,
+}
+
+impl DiagnoseProjectPresenter {
+    pub fn has_failed(&self) -> bool {
+        self.has_failed.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<DiagnoseProjectResponseModel> for DiagnoseProjectPresenter {
+    async fn success(&self, response_model: DiagnoseProjectResponseModel) {
+        response_model.fixed_issues.iter().for_each(|issue| {
+            println!("Fixed [{}] {}: {}", issue.rule, issue.path, issue.description);
+        });
+        if response_model.issues.is_empty() {
+            println!("No structural issues found.");
+            return;
+        }
+        self.has_failed.store(true, Ordering::SeqCst);
+        println!("Found {} structural issue(s):", response_model.issues.len());
+        response_model.issues.iter().for_each(|issue| {
+            println!("  [{}] {}: {}", issue.rule, issue.path, issue.description);
+        });
+        if response_model.issues.iter().any(|issue| issue.is_fixable) {
+            println!("Run `arpeggio doctor --fix` to repair module declarations.");
+        }
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        self.has_failed.store(true, Ordering::SeqCst);
+        eprintln!("Failed to diagnose project due to: {}", error)
+    }
+}
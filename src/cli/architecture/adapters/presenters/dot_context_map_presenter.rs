@@ -0,0 +1,46 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::{
+    adapters::presenters::context_map_diagram::ContextMapDiagram,
+    application::queries::find_context_map_use_case::FindContextMapResponseModel,
+};
+
+pub struct DotContextMapPresenter;
+
+#[async_trait]
+impl UseCaseOutputPort<FindContextMapResponseModel> for DotContextMapPresenter {
+    async fn success(&self, response_model: FindContextMapResponseModel) {
+        let diagram = ContextMapDiagram::from(&response_model);
+        diagram.warnings.iter().for_each(|warning| eprintln!("Warning: {}", warning));
+        println!("digraph context_map {{");
+        println!("    rankdir=LR;");
+        println!("    node [shape=box, style=rounded];");
+        diagram.nodes.iter().for_each(|node| {
+            let label = std::iter
+                ::once(format!("<b>{}</b>", node.title))
+                .chain(node.items.iter().cloned())
+                .collect::<Vec<_>>()
+                .join("<br/>");
+            println!("    \"{}\" [label=<{}>];", node.id, label);
+        });
+        diagram.edges.iter().for_each(|edge| {
+            let mut attributes = vec![format!("label=\"{}\"", edge.label)];
+            if edge.is_directed {
+                attributes.push("taillabel=\"D\"".to_string());
+                attributes.push("headlabel=\"U\"".to_string());
+            } else {
+                attributes.push("dir=none".to_string());
+            }
+            if !edge.is_observed {
+                attributes.push("style=dashed".to_string());
+            }
+            println!("    \"{}\" -> \"{}\" [{}];", edge.source, edge.target, attributes.join(", "));
+        });
+        println!("}}");
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to build context map due to: {}", error)
+    }
+}
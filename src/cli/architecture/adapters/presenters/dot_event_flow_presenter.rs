@@ -0,0 +1,40 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::{
+    adapters::presenters::event_flow_diagram::EventFlowDiagram,
+    application::queries::find_event_flow_use_case::FindEventFlowResponseModel,
+};
+
+pub struct DotEventFlowPresenter;
+
+#[async_trait]
+impl UseCaseOutputPort<FindEventFlowResponseModel> for DotEventFlowPresenter {
+    async fn success(&self, response_model: FindEventFlowResponseModel) {
+        let diagram = EventFlowDiagram::from(&response_model);
+        diagram.warnings.iter().for_each(|warning| eprintln!("Warning: {}", warning));
+        println!("digraph event_flow {{");
+        println!("    rankdir=LR;");
+        println!("    node [shape=box, style=rounded];");
+        diagram.groups.iter().for_each(|group| {
+            println!("    subgraph cluster_{} {{", group.id);
+            println!("        label=\"{}\";", group.title);
+            group.nodes.iter().for_each(|node| {
+                let shape = match node.is_event {
+                    true => ", shape=ellipse",
+                    false => "",
+                };
+                println!("        \"{}\" [label=\"{}\"{}];", node.id, node.title, shape);
+            });
+            println!("    }}");
+        });
+        diagram.edges.iter().for_each(|edge| {
+            println!("    \"{}\" -> \"{}\" [label=\"{}\"];", edge.source, edge.target, edge.label);
+        });
+        println!("}}");
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to build event flow due to: {}", error)
+    }
+}
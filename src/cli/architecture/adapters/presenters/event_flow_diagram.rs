@@ -0,0 +1,144 @@
+use crate::cli::architecture::application::queries::find_event_flow_use_case::{
+    EventEndpointReadModel,
+    FindEventFlowResponseModel,
+};
+
+pub struct EventFlowNode {
+    pub id: String,
+    pub title: String,
+    pub is_event: bool,
+}
+
+pub struct EventFlowGroup {
+    pub id: String,
+    pub title: String,
+    pub nodes: Vec<EventFlowNode>,
+}
+
+#[derive(PartialEq)]
+pub struct EventFlowEdge {
+    pub source: String,
+    pub target: String,
+    pub label: String,
+}
+
+pub struct EventFlowDiagram {
+    pub groups: Vec<EventFlowGroup>,
+    pub edges: Vec<EventFlowEdge>,
+    pub warnings: Vec<String>,
+}
+
+impl EventFlowDiagram {
+    fn get_module_id(module_name: &str) -> String {
+        format!("module_{}", module_name.replace('/', "_"))
+    }
+
+    fn get_event_id(type_name: &str) -> String {
+        format!("event_{}", type_name)
+    }
+
+    fn get_location(endpoint: &EventEndpointReadModel) -> String {
+        format!("{}:{}", endpoint.path, endpoint.line)
+    }
+
+    fn add_node(&mut self, module_name: &str, node: EventFlowNode) {
+        let group_title = module_name.split('/').next().unwrap_or_default();
+        let group_id = format!("context_{}", group_title);
+        let group = match self.groups.iter().position(|group| group.id == group_id) {
+            Some(position) => &mut self.groups[position],
+            None => {
+                self.groups.push(EventFlowGroup {
+                    id: group_id,
+                    title: group_title.to_string(),
+                    nodes: vec![],
+                });
+                self.groups.last_mut().unwrap()
+            }
+        };
+        if !group.nodes.iter().any(|group_node| group_node.id == node.id) {
+            group.nodes.push(node);
+        }
+    }
+
+    fn add_module(&mut self, module_name: &str) -> String {
+        let id = Self::get_module_id(module_name);
+        self.add_node(module_name, EventFlowNode {
+            id: id.clone(),
+            title: module_name.to_string(),
+            is_event: false,
+        });
+        id
+    }
+
+    fn add_edge(&mut self, edge: EventFlowEdge) {
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+}
+
+// Events sit in the bounded context that declares them, with edges running
+// from the raising module to the event and from the event to each subscriber.
+impl From<&FindEventFlowResponseModel> for EventFlowDiagram {
+    fn from(response_model: &FindEventFlowResponseModel) -> Self {
+        let mut diagram = Self {
+            groups: vec![],
+            edges: vec![],
+            warnings: vec![],
+        };
+        for event in &response_model.events {
+            let event_id = Self::get_event_id(&event.declaration.name);
+            diagram.add_node(&event.declaration.module_name, EventFlowNode {
+                id: event_id.clone(),
+                title: event.event_name.clone(),
+                is_event: true,
+            });
+            for publisher in &event.publishers {
+                let module_id = diagram.add_module(&publisher.module_name);
+                diagram.add_edge(EventFlowEdge {
+                    source: module_id,
+                    target: event_id.clone(),
+                    label: "raises".to_string(),
+                });
+            }
+            for subscriber in &event.subscribers {
+                let module_id = diagram.add_module(&subscriber.module_name);
+                diagram.add_edge(EventFlowEdge {
+                    source: event_id.clone(),
+                    target: module_id,
+                    label: subscriber.name.clone(),
+                });
+            }
+        }
+        for unmatched_subscriber in &response_model.unmatched_subscribers {
+            let subscriber = &unmatched_subscriber.subscriber;
+            diagram.warnings.push(match &unmatched_subscriber.subscribed_to {
+                Some(event_name) =>
+                    format!(
+                        "{} ({}) subscribes to <{}>, which matches no known event",
+                        subscriber.name,
+                        Self::get_location(subscriber),
+                        event_name
+                    ),
+                None =>
+                    format!(
+                        "{} ({}) subscribes to an event name that could not be resolved",
+                        subscriber.name,
+                        Self::get_location(subscriber)
+                    ),
+            });
+        }
+        for event in &response_model.events {
+            if response_model.unsubscribed_events.contains(&event.event_name) {
+                diagram.warnings.push(
+                    format!(
+                        "{} ({}) has no subscribers",
+                        event.event_name,
+                        Self::get_location(&event.declaration)
+                    )
+                );
+            }
+        }
+        diagram
+    }
+}
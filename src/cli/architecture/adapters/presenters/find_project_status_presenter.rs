@@ -0,0 +1,116 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::application::queries::find_project_status_use_case::{
+    ComponentCountReadModel,
+    ComponentStatusReadModel,
+    FindProjectStatusResponseModel,
+};
+
+pub struct FindProjectStatusPresenter;
+
+impl FindProjectStatusPresenter {
+    fn sum_counts(
+        &self,
+        component_counts: &[ComponentCountReadModel],
+        predicate: impl Fn(&ComponentCountReadModel) -> bool
+    ) -> usize {
+        component_counts
+            .iter()
+            .filter(|component_count| predicate(component_count))
+            .map(|component_count| component_count.count)
+            .sum()
+    }
+
+    fn print_component_counts(&self, component_counts: &[ComponentCountReadModel]) {
+        println!("Components: {}", self.sum_counts(component_counts, |_| true));
+        let mut previous: Option<&ComponentCountReadModel> = None;
+        component_counts.iter().for_each(|current| {
+            let is_same_bounded_context = previous.is_some_and(|previous| {
+                previous.bounded_context_name == current.bounded_context_name
+            });
+            let is_same_aggregate =
+                is_same_bounded_context &&
+                previous.is_some_and(|previous| previous.aggregate_name == current.aggregate_name);
+            let is_same_layer =
+                is_same_aggregate &&
+                previous.is_some_and(|previous| previous.layer_name == current.layer_name);
+            if !is_same_bounded_context {
+                println!(
+                    "  {}: {}",
+                    current.bounded_context_name,
+                    self.sum_counts(component_counts, |c| {
+                        c.bounded_context_name == current.bounded_context_name
+                    })
+                );
+            }
+            if !is_same_aggregate {
+                println!(
+                    "    {}: {}",
+                    current.aggregate_name,
+                    self.sum_counts(component_counts, |c| {
+                        c.bounded_context_name == current.bounded_context_name &&
+                            c.aggregate_name == current.aggregate_name
+                    })
+                );
+            }
+            if !is_same_layer {
+                println!(
+                    "      {}: {}",
+                    current.layer_name,
+                    self.sum_counts(component_counts, |c| {
+                        c.bounded_context_name == current.bounded_context_name &&
+                            c.aggregate_name == current.aggregate_name &&
+                            c.layer_name == current.layer_name
+                    })
+                );
+            }
+            println!("        {}: {}", current.component_type, current.count);
+            previous = Some(current);
+        });
+    }
+
+    fn format_component(&self, component: &ComponentStatusReadModel) -> String {
+        format!(
+            "{} {} <{}> ({})",
+            component.module_name,
+            component.component_type,
+            component.component_name,
+            component.path
+        )
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<FindProjectStatusResponseModel> for FindProjectStatusPresenter {
+    async fn success(&self, response_model: FindProjectStatusResponseModel) {
+        self.print_component_counts(&response_model.component_counts);
+        if response_model.stubs.is_empty() {
+            println!("No unfinished components found.");
+        } else {
+            println!("Found {} unfinished component(s):", response_model.stubs.len());
+            response_model.stubs.iter().for_each(|stub| {
+                let mut reasons = stub.markers.clone();
+                if stub.is_empty {
+                    reasons.insert(0, "empty file".to_string());
+                }
+                println!("  {}: {}", self.format_component(&stub.component), reasons.join(", "));
+            });
+        }
+        if response_model.untested_components.is_empty() {
+            println!("Every component has tests.");
+        } else {
+            println!(
+                "Found {} component(s) without tests:",
+                response_model.untested_components.len()
+            );
+            response_model.untested_components.iter().for_each(|component| {
+                println!("  {}", self.format_component(component));
+            });
+        }
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to find project status due to: {}", error)
+    }
+}
@@ -0,0 +1,25 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::application::commands::generate_docs_use_case::GenerateDocsResponseModel;
+
+pub struct GenerateDocsPresenter;
+
+#[async_trait]
+impl UseCaseOutputPort<GenerateDocsResponseModel> for GenerateDocsPresenter {
+    async fn success(&self, response_model: GenerateDocsResponseModel) {
+        response_model.created_bounded_contexts.iter().for_each(|bounded_context_name| {
+            println!("Created README for bounded context <{}>", bounded_context_name);
+        });
+        response_model.updated_bounded_contexts.iter().for_each(|bounded_context_name| {
+            println!("Updated README for bounded context <{}>", bounded_context_name);
+        });
+        response_model.unchanged_bounded_contexts.iter().for_each(|bounded_context_name| {
+            println!("README for bounded context <{}> is up to date", bounded_context_name);
+        });
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to generate docs due to: {}", error)
+    }
+}
@@ -0,0 +1,340 @@
+use std::{ error::Error, sync::{ Mutex, atomic::{ AtomicBool, Ordering } } };
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::{
+    architecture::{
+        adapters::presenters::{
+            context_map_diagram::ContextMapDiagram,
+            event_flow_diagram::EventFlowDiagram,
+            svg_diagram::{ SvgDiagram, escape_markup },
+        },
+        application::queries::{
+            find_context_map_use_case::FindContextMapResponseModel,
+            find_event_flow_use_case::FindEventFlowResponseModel,
+        },
+    },
+    bounded_context::application::queries::export_model_use_case::{
+        ExportModelResponseModel,
+        ModelAggregateReadModel,
+        ModelBoundedContextReadModel,
+    },
+};
+
+const STYLESHEET: &str = concat!(
+    "body { font-family: sans-serif; margin: 2rem auto; max-width: 72rem; color: #222; }\n",
+    "nav a { margin-right: 0.5rem; }\n",
+    "table { border-collapse: collapse; }\n",
+    "th, td { border: 1px solid #ccc; padding: 0.3rem 0.8rem; text-align: left; }\n",
+    ".diagram { overflow-x: auto; border: 1px solid #ddd; padding: 0.5rem; }\n",
+    ".warning { color: #a35c00; }"
+);
+
+pub struct HtmlReportPage {
+    pub path: String,
+    pub content: String,
+}
+
+pub struct HtmlReportPresenter {
+    source_url: String,
+    bounded_contexts: Mutex<Vec<ModelBoundedContextReadModel>>,
+    context_map: Mutex<Option<SvgDiagram>>,
+    event_flow: Mutex<Option<SvgDiagram>>,
+    has_failed: AtomicBool,
+}
+
+impl HtmlReportPresenter {
+    pub fn new(source_url: String) -> Self {
+        Self {
+            source_url: source_url.trim_end_matches('/').to_string(),
+            bounded_contexts: Mutex::new(vec![]),
+            context_map: Mutex::new(None),
+            event_flow: Mutex::new(None),
+            has_failed: AtomicBool::new(false),
+        }
+    }
+
+    pub fn has_failed(&self) -> bool {
+        self.has_failed.load(Ordering::SeqCst)
+    }
+
+    fn fail(&self, topic: &str, error: Box<dyn Error + Send>) {
+        self.has_failed.store(true, Ordering::SeqCst);
+        eprintln!("Failed to build report {} due to: {}", topic, error)
+    }
+
+    fn render_page(
+        title: &str,
+        root_path: &str,
+        breadcrumbs: &[(String, String)],
+        body: &str
+    ) -> String {
+        let navigation = std::iter
+            ::once(format!("<a href=\"{}index.html\">Architecture</a>", root_path))
+            .chain(
+                breadcrumbs
+                    .iter()
+                    .map(|(href, label)| {
+                        format!(
+                            "&rsaquo; <a href=\"{}\">{}</a>",
+                            escape_markup(href),
+                            escape_markup(label)
+                        )
+                    })
+            )
+            .collect::<Vec<_>>()
+            .join(" ");
+        [
+            "<!DOCTYPE html>",
+            "<html lang=\"en\">",
+            "<head>",
+            "<meta charset=\"utf-8\">",
+            &format!("<title>{}</title>", escape_markup(title)),
+            &format!("<style>\n{}\n</style>", STYLESHEET),
+            "</head>",
+            "<body>",
+            &format!("<nav>{}</nav>", navigation),
+            &format!("<h1>{}</h1>", escape_markup(title)),
+            body,
+            "</body>",
+            "</html>\n",
+        ].join("\n")
+    }
+
+    fn render_diagram(title: &str, diagram: &Option<SvgDiagram>) -> String {
+        let Some(diagram) = diagram else {
+            return String::new();
+        };
+        let warnings = diagram.warnings
+            .iter()
+            .map(|warning| format!("<li class=\"warning\">{}</li>", escape_markup(warning)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            "<h2>{}</h2>\n<div class=\"diagram\">\n{}\n</div>\n<ul>{}</ul>",
+            title,
+            diagram.render(),
+            warnings
+        )
+    }
+
+    fn count_components(aggregate: &ModelAggregateReadModel) -> usize {
+        aggregate.layers
+            .iter()
+            .map(|layer| layer.components.len())
+            .sum()
+    }
+
+    fn render_index(&self, bounded_contexts: &[ModelBoundedContextReadModel]) -> HtmlReportPage {
+        let rows = bounded_contexts
+            .iter()
+            .map(|bounded_context| {
+                format!(
+                    "<tr><td><a href=\"{}/index.html\">{}</a></td><td>{}</td><td>{}</td></tr>",
+                    escape_markup(&bounded_context.name),
+                    escape_markup(&bounded_context.name),
+                    bounded_context.aggregates.len(),
+                    bounded_context.aggregates
+                        .iter()
+                        .map(Self::count_components)
+                        .sum::<usize>()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let body = format!(
+            "<h2>Bounded contexts</h2>\n<table>\n{}\n{}\n</table>\n{}\n{}",
+            "<tr><th>Bounded context</th><th>Aggregates</th><th>Components</th></tr>",
+            rows,
+            Self::render_diagram("Context map", &self.context_map.lock().unwrap()),
+            Self::render_diagram("Event flow", &self.event_flow.lock().unwrap())
+        );
+        HtmlReportPage {
+            path: "index.html".to_string(),
+            content: Self::render_page("Architecture", "", &[], &body),
+        }
+    }
+
+    fn render_bounded_context(bounded_context: &ModelBoundedContextReadModel) -> HtmlReportPage {
+        let rows = bounded_context.aggregates
+            .iter()
+            .map(|aggregate| {
+                let layers = aggregate.layers
+                    .iter()
+                    .map(|layer| format!("{} ({})", layer.layer_name, layer.components.len()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "<tr><td><a href=\"{}/index.html\">{}</a></td><td>{}</td></tr>",
+                    escape_markup(&aggregate.name),
+                    escape_markup(&aggregate.name),
+                    layers
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let body = format!(
+            "<h2>Aggregates</h2>\n<table>\n{}\n{}\n</table>",
+            "<tr><th>Aggregate</th><th>Layers</th></tr>",
+            rows
+        );
+        HtmlReportPage {
+            path: format!("{}/index.html", bounded_context.name),
+            content: Self::render_page(
+                &bounded_context.name,
+                "../",
+                &[("index.html".to_string(), bounded_context.name.clone())],
+                &body
+            ),
+        }
+    }
+
+    // Every aggregate gets its own directory, so that one named `index`
+    // doesn't replace the page of its bounded context.
+    fn render_aggregate(
+        &self,
+        bounded_context: &ModelBoundedContextReadModel,
+        aggregate: &ModelAggregateReadModel
+    ) -> HtmlReportPage {
+        let body = aggregate.layers
+            .iter()
+            .map(|layer| {
+                let components = layer.components
+                    .iter()
+                    .map(|component| {
+                        let path = format!(
+                            "src/{}/{}/{}/{}/{}.rs",
+                            bounded_context.name,
+                            aggregate.name,
+                            layer.layer_name,
+                            component.component_type,
+                            component.component_name
+                        );
+                        format!(
+                            "<tr><td>{}</td><td><a href=\"{}\">{}</a></td></tr>",
+                            component.component_type.get_singular_name().replace('_', " "),
+                            escape_markup(&format!("{}/{}", self.source_url, path)),
+                            escape_markup(&component.component_name)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                match components.is_empty() {
+                    true => format!("<h2>{}</h2>\n<p>No components.</p>", layer.layer_name),
+                    false =>
+                        format!(
+                            "<h2>{}</h2>\n<table>\n{}\n{}\n</table>",
+                            layer.layer_name,
+                            "<tr><th>Type</th><th>Component</th></tr>",
+                            components.join("\n")
+                        ),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        HtmlReportPage {
+            path: format!("{}/{}/index.html", bounded_context.name, aggregate.name),
+            content: Self::render_page(
+                &format!("{}/{}", bounded_context.name, aggregate.name),
+                "../../",
+                &[
+                    ("../index.html".to_string(), bounded_context.name.clone()),
+                    ("index.html".to_string(), aggregate.name.clone()),
+                ],
+                &body
+            ),
+        }
+    }
+
+    pub fn get_pages(&self) -> Vec<HtmlReportPage> {
+        let bounded_contexts = self.bounded_contexts.lock().unwrap();
+        let mut pages = vec![self.render_index(&bounded_contexts)];
+        for bounded_context in bounded_contexts.iter() {
+            pages.push(Self::render_bounded_context(bounded_context));
+            for aggregate in &bounded_context.aggregates {
+                pages.push(self.render_aggregate(bounded_context, aggregate));
+            }
+        }
+        pages
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<ExportModelResponseModel> for HtmlReportPresenter {
+    async fn success(&self, response_model: ExportModelResponseModel) {
+        *self.bounded_contexts.lock().unwrap() = response_model.bounded_contexts;
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        self.fail("model", error)
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<FindContextMapResponseModel> for HtmlReportPresenter {
+    async fn success(&self, response_model: FindContextMapResponseModel) {
+        let diagram = ContextMapDiagram::from(&response_model);
+        *self.context_map.lock().unwrap() = Some(SvgDiagram::from(&diagram));
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        self.fail("context map", error)
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<FindEventFlowResponseModel> for HtmlReportPresenter {
+    async fn success(&self, response_model: FindEventFlowResponseModel) {
+        let diagram = EventFlowDiagram::from(&response_model);
+        *self.event_flow.lock().unwrap() = Some(SvgDiagram::from(&diagram));
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        self.fail("event flow", error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{
+        architecture::adapters::presenters::html_report_presenter::HtmlReportPresenter,
+        bounded_context::{
+            application::queries::export_model_use_case::{
+                ModelAggregateReadModel,
+                ModelBoundedContextReadModel,
+                ModelComponentReadModel,
+                ModelLayerReadModel,
+            },
+            domain::enums::{ component_type::ComponentType, layer_name::LayerName },
+        },
+    };
+
+    #[test]
+    fn should_write_aggregate_pages_apart_with_escaped_links() {
+        let html_report_presenter = HtmlReportPresenter::new("https://host/a?b=1&c=2".to_string());
+        *html_report_presenter.bounded_contexts.lock().unwrap() = vec![
+            ModelBoundedContextReadModel {
+                name: "sales".to_string(),
+                aggregates: vec![ModelAggregateReadModel {
+                    name: "index".to_string(),
+                    layers: vec![ModelLayerReadModel {
+                        layer_name: LayerName::Domain,
+                        components: vec![ModelComponentReadModel {
+                            component_type: ComponentType::Entities,
+                            component_name: "order".to_string(),
+                        }],
+                    }],
+                }],
+            }
+        ];
+        let pages = html_report_presenter.get_pages();
+        let paths = pages
+            .iter()
+            .map(|page| page.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["index.html", "sales/index.html", "sales/index/index.html"]);
+        assert!(
+            pages[2].content.contains(
+                "<a href=\"https://host/a?b=1&amp;c=2/src/sales/index/domain/entities/order.rs\">"
+            )
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use std::{ error::Error, sync::atomic::{ AtomicBool, Ordering } };
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::{
+    adapters::presenters::architecture_report::ArchitectureReport,
+    application::{
+        commands::diagnose_project_use_case::DiagnoseProjectResponseModel,
+        queries::check_architecture_use_case::CheckArchitectureResponseModel,
+    },
+};
+
+#[derive(Default)]
+pub struct JunitReportPresenter {
+    has_failed: AtomicBool,
+}
+
+impl JunitReportPresenter {
+    pub fn has_failed(&self) -> bool {
+        self.has_failed.load(Ordering::SeqCst)
+    }
+
+    fn escape(&self, text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&apos;")
+    }
+
+    fn print_report(&self, report: ArchitectureReport) {
+        if report.has_errors() {
+            self.has_failed.store(true, Ordering::SeqCst);
+        }
+        let mut test_cases = vec![];
+        report.rules
+            .iter()
+            .filter(|rule| !report.findings.iter().any(|finding| &finding.rule == *rule))
+            .for_each(|rule| {
+                test_cases.push(
+                    format!(
+                        "    <testcase classname=\"{}\" name=\"{}\"/>",
+                        self.escape(&report.name),
+                        self.escape(rule)
+                    )
+                );
+            });
+        report.findings.iter().for_each(|finding| {
+            let location = match (&finding.path, finding.line) {
+                (Some(path), Some(line)) => format!("{}:{}", path, line),
+                (Some(path), None) => path.clone(),
+                _ => String::new(),
+            };
+            let summary = finding.message.lines().next().unwrap_or_default();
+            test_cases.push(
+                format!(
+                    "    <testcase classname=\"{}\" name=\"{}\" file=\"{}\">\n      <failure type=\"{}\" message=\"{}\">{}</failure>\n    </testcase>",
+                    self.escape(&report.name),
+                    self.escape(&format!("{}: {}", finding.rule, location)),
+                    self.escape(finding.path.as_deref().unwrap_or_default()),
+                    self.escape(&finding.rule),
+                    self.escape(summary),
+                    self.escape(&finding.message)
+                )
+            );
+        });
+        println!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
+        println!(
+            "<testsuites name=\"arpeggio\" tests=\"{}\" failures=\"{}\">",
+            test_cases.len(),
+            report.findings.len()
+        );
+        println!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">",
+            self.escape(&report.name),
+            test_cases.len(),
+            report.findings.len()
+        );
+        test_cases.iter().for_each(|test_case| println!("{}", test_case));
+        println!("  </testsuite>");
+        println!("</testsuites>");
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<CheckArchitectureResponseModel> for JunitReportPresenter {
+    async fn success(&self, response_model: CheckArchitectureResponseModel) {
+        self.print_report(ArchitectureReport::from(&response_model))
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        self.has_failed.store(true, Ordering::SeqCst);
+        eprintln!("Failed to check architecture due to: {}", error)
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<DiagnoseProjectResponseModel> for JunitReportPresenter {
+    async fn success(&self, response_model: DiagnoseProjectResponseModel) {
+        self.print_report(ArchitectureReport::from(&response_model))
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        self.has_failed.store(true, Ordering::SeqCst);
+        eprintln!("Failed to diagnose project due to: {}", error)
+    }
+}
@@ -0,0 +1,43 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::{
+    adapters::presenters::context_map_diagram::ContextMapDiagram,
+    application::queries::find_context_map_use_case::FindContextMapResponseModel,
+};
+
+pub struct MermaidContextMapPresenter;
+
+#[async_trait]
+impl UseCaseOutputPort<FindContextMapResponseModel> for MermaidContextMapPresenter {
+    async fn success(&self, response_model: FindContextMapResponseModel) {
+        let diagram = ContextMapDiagram::from(&response_model);
+        diagram.warnings.iter().for_each(|warning| eprintln!("Warning: {}", warning));
+        println!("flowchart LR");
+        diagram.nodes.iter().for_each(|node| {
+            let label = std::iter
+                ::once(format!("<b>{}</b>", node.title))
+                .chain(node.items.iter().cloned())
+                .collect::<Vec<_>>()
+                .join("<br/>");
+            println!("    {}[\"{}\"]", node.id, label);
+        });
+        diagram.edges.iter().for_each(|edge| {
+            let arrow = match (edge.is_directed, edge.is_observed) {
+                (true, true) => "-->",
+                (true, false) => "-.->",
+                (false, true) => "<-->",
+                (false, false) => "<-.->",
+            };
+            let label = match edge.is_directed {
+                true => format!("D → U: {}", edge.label),
+                false => edge.label.clone(),
+            };
+            println!("    {} {}|\"{}\"| {}", edge.source, arrow, label, edge.target);
+        });
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to build context map due to: {}", error)
+    }
+}
@@ -0,0 +1,35 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::{
+    adapters::presenters::event_flow_diagram::EventFlowDiagram,
+    application::queries::find_event_flow_use_case::FindEventFlowResponseModel,
+};
+
+pub struct MermaidEventFlowPresenter;
+
+#[async_trait]
+impl UseCaseOutputPort<FindEventFlowResponseModel> for MermaidEventFlowPresenter {
+    async fn success(&self, response_model: FindEventFlowResponseModel) {
+        let diagram = EventFlowDiagram::from(&response_model);
+        diagram.warnings.iter().for_each(|warning| eprintln!("Warning: {}", warning));
+        println!("flowchart LR");
+        diagram.groups.iter().for_each(|group| {
+            println!("    subgraph {}[\"{}\"]", group.id, group.title);
+            group.nodes.iter().for_each(|node| {
+                match node.is_event {
+                    true => println!("        {}([\"{}\"])", node.id, node.title),
+                    false => println!("        {}[\"{}\"]", node.id, node.title),
+                }
+            });
+            println!("    end");
+        });
+        diagram.edges.iter().for_each(|edge| {
+            println!("    {} -->|\"{}\"| {}", edge.source, edge.label, edge.target);
+        });
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to build event flow due to: {}", error)
+    }
+}
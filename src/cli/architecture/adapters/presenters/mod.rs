@@ -0,0 +1,18 @@
+pub mod architecture_report;
+pub mod check_architecture_presenter;
+pub mod context_map_diagram;
+pub mod diagnose_project_presenter;
+pub mod dot_context_map_presenter;
+pub mod dot_event_flow_presenter;
+pub mod event_flow_diagram;
+pub mod find_project_status_presenter;
+pub mod generate_docs_presenter;
+pub mod html_report_presenter;
+pub mod junit_report_presenter;
+pub mod mermaid_context_map_presenter;
+pub mod mermaid_event_flow_presenter;
+pub mod plantuml_aggregate_diagram_presenter;
+pub mod sarif_report_presenter;
+pub mod show_aggregate_presenter;
+pub mod structurizr_c4_presenter;
+pub mod svg_diagram;
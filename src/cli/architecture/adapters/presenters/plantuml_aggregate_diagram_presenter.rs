@@ -0,0 +1,98 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::{
+    application::queries::find_aggregate_diagram_use_case::{
+        ClassRelationKind,
+        DiagramClassReadModel,
+        DiagramRelationReadModel,
+        FindAggregateDiagramResponseModel,
+    },
+    domain::enums::source_item_kind::SourceItemKind,
+};
+
+pub struct PlantumlAggregateDiagramPresenter;
+
+impl PlantumlAggregateDiagramPresenter {
+    fn print_class(&self, class: &DiagramClassReadModel) {
+        let keyword = match class.kind {
+            SourceItemKind::Enum => "enum",
+            SourceItemKind::Trait => "interface",
+            _ => "class",
+        };
+        let stereotypes = std::iter
+            ::once(class.component_type.get_singular_name().replace('_', " "))
+            .chain(class.implemented_traits.iter().cloned())
+            .map(|stereotype| format!("<<{}>>", stereotype))
+            .collect::<Vec<_>>()
+            .join(" ");
+        println!("    {} {} {} {{", keyword, class.name, stereotypes);
+        class.members.iter().for_each(|member| {
+            match (&member.parameters, &member.type_name) {
+                (Some(parameters), Some(type_name)) => {
+                    println!("        {}({}): {}", member.name, parameters.join(", "), type_name);
+                }
+                (Some(parameters), None) => {
+                    println!("        {}({})", member.name, parameters.join(", "));
+                }
+                (None, Some(type_name)) => {
+                    println!("        {}: {}", member.name, type_name);
+                }
+                (None, None) => {
+                    println!("        {}", member.name);
+                }
+            }
+        });
+        println!("    }}");
+    }
+
+    fn print_relation(&self, relation: &DiagramRelationReadModel) {
+        let (arrow, label) = match relation.kind {
+            ClassRelationKind::Association => ("-->", relation.label.clone()),
+            ClassRelationKind::Dependency => ("..>", relation.label.clone()),
+            ClassRelationKind::Realization => ("..|>", None),
+            ClassRelationKind::Raises => ("..>", Some("<<raises>>".to_string())),
+        };
+        match label {
+            Some(label) => {
+                println!("{} {} {} : {}", relation.source, arrow, relation.target, label);
+            }
+            None => {
+                println!("{} {} {}", relation.source, arrow, relation.target);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<FindAggregateDiagramResponseModel> for PlantumlAggregateDiagramPresenter {
+    async fn success(&self, response_model: FindAggregateDiagramResponseModel) {
+        println!("@startuml");
+        println!(
+            "title {} / {} domain model",
+            response_model.bounded_context_name,
+            response_model.aggregate_name
+        );
+        println!("hide empty members");
+        let mut component_types = vec![];
+        response_model.classes.iter().for_each(|class| {
+            if !component_types.contains(&class.component_type) {
+                component_types.push(class.component_type.clone());
+            }
+        });
+        component_types.iter().for_each(|component_type| {
+            println!("package {} {{", component_type);
+            response_model.classes
+                .iter()
+                .filter(|class| &class.component_type == component_type)
+                .for_each(|class| self.print_class(class));
+            println!("}}");
+        });
+        response_model.relations.iter().for_each(|relation| self.print_relation(relation));
+        println!("@enduml");
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to build aggregate diagram due to: {}", error)
+    }
+}
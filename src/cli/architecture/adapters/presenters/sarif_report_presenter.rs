@@ -0,0 +1,90 @@
+use std::{ error::Error, sync::atomic::{ AtomicBool, Ordering } };
+use async_trait::async_trait;
+use serde_json::{ json, Value };
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::{
+    adapters::presenters::architecture_report::ArchitectureReport,
+    application::{
+        commands::diagnose_project_use_case::DiagnoseProjectResponseModel,
+        queries::check_architecture_use_case::CheckArchitectureResponseModel,
+    },
+};
+
+#[derive(Default)]
+pub struct SarifReportPresenter {
+    has_failed: AtomicBool,
+}
+
+impl SarifReportPresenter {
+    const SARIF_SCHEMA: &'static str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+    pub fn has_failed(&self) -> bool {
+        self.has_failed.load(Ordering::SeqCst)
+    }
+
+    fn print_report(&self, report: ArchitectureReport) {
+        if report.has_errors() {
+            self.has_failed.store(true, Ordering::SeqCst);
+        }
+        let results = report.findings
+            .iter()
+            .map(|finding| {
+                let mut result =
+                    json!({
+                    "ruleId": finding.rule,
+                    "level": finding.level,
+                    "message": { "text": finding.message },
+                });
+                if let Some(path) = &finding.path {
+                    let mut physical_location = json!({ "artifactLocation": { "uri": path } });
+                    if let Some(line) = finding.line {
+                        physical_location["region"] = json!({ "startLine": line });
+                    }
+                    result["locations"] = json!([{ "physicalLocation": physical_location }]);
+                }
+                result
+            })
+            .collect::<Vec<Value>>();
+        let sarif_log =
+            json!({
+            "$schema": Self::SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": report.name,
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "rules": report.rules.iter().map(|rule| json!({ "id": rule })).collect::<Vec<_>>(),
+                    }
+                },
+                "results": results,
+            }],
+        });
+        println!("{}", serde_json::to_string_pretty(&sarif_log).unwrap())
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<CheckArchitectureResponseModel> for SarifReportPresenter {
+    async fn success(&self, response_model: CheckArchitectureResponseModel) {
+        self.print_report(ArchitectureReport::from(&response_model))
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        self.has_failed.store(true, Ordering::SeqCst);
+        eprintln!("Failed to check architecture due to: {}", error)
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<DiagnoseProjectResponseModel> for SarifReportPresenter {
+    async fn success(&self, response_model: DiagnoseProjectResponseModel) {
+        self.print_report(ArchitectureReport::from(&response_model))
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        self.has_failed.store(true, Ordering::SeqCst);
+        eprintln!("Failed to diagnose project due to: {}", error)
+    }
+}
@@ -0,0 +1,75 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::architecture::application::queries::show_aggregate_use_case::{
+    ComponentReferenceReadModel,
+    ShowAggregateResponseModel,
+};
+
+pub struct ShowAggregatePresenter;
+
+impl ShowAggregatePresenter {
+    fn print_references(&self, title: &str, references: &[ComponentReferenceReadModel]) {
+        if references.is_empty() {
+            println!("{}: none", title);
+            return;
+        }
+        println!("{}:", title);
+        references.iter().for_each(|reference| {
+            println!(
+                "  {} {} <{}> ({})",
+                reference.module_name,
+                reference.component_type,
+                reference.component_name,
+                reference.path
+            );
+        });
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<ShowAggregateResponseModel> for ShowAggregatePresenter {
+    async fn success(&self, response_model: ShowAggregateResponseModel) {
+        println!(
+            "Aggregate <{}> of bounded context <{}>",
+            response_model.aggregate_name,
+            response_model.bounded_context_name
+        );
+        response_model.layers.iter().for_each(|layer| {
+            println!("{}", layer.layer_name);
+            layer.components.iter().for_each(|component| {
+                println!(
+                    "  {} <{}>{}",
+                    component.component_type,
+                    component.component_name,
+                    component.path
+                        .as_ref()
+                        .map(|path| format!(" ({})", path))
+                        .unwrap_or_default()
+                );
+                component.items.iter().for_each(|item| {
+                    println!("    {} {} (line {})", item.kind, item.name, item.line);
+                });
+            });
+        });
+        if response_model.raised_events.is_empty() {
+            println!("Raised events: none");
+        } else {
+            println!("Raised events:");
+            response_model.raised_events.iter().for_each(|raised_event| {
+                println!(
+                    "  {} ({}:{})",
+                    raised_event.event_name,
+                    raised_event.path,
+                    raised_event.line
+                );
+            });
+        }
+        self.print_references("Repositories", &response_model.repositories);
+        self.print_references("Use cases", &response_model.use_cases);
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to show aggregate due to: {}", error)
+    }
+}
@@ -0,0 +1,142 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::{
+    architecture::application::queries::find_c4_model_use_case::{
+        C4ComponentReadModel,
+        C4RelationshipReadModel,
+        FindC4ModelResponseModel,
+    },
+    bounded_context::domain::enums::component_type::ComponentType,
+};
+
+pub struct StructurizrC4Presenter;
+
+impl StructurizrC4Presenter {
+    const SYSTEM_IDENTIFIER: &'static str = "system";
+    const CONTAINER_TAG: &'static str = "Bounded Context";
+
+    // Identifiers can't hold `/`: `_` is doubled first so that the `_0`
+    // standing for `/` keeps `a_b/c` and `a/b_c` apart.
+    fn get_identifier(id: &str) -> String {
+        id.replace('_', "__").replace('/', "_0")
+    }
+
+    fn escape(value: &str) -> String {
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    fn print_component(&self, component: &C4ComponentReadModel) {
+        let aggregate_name = component.aggregate_id.rsplit('/').next().unwrap_or_default();
+        let (description, tag) = match component.component_type {
+            Some(ComponentType::Controllers) =>
+                (format!("Controller of the {} aggregate", aggregate_name), "Controller"),
+            Some(ComponentType::Services) =>
+                (format!("Service of the {} aggregate", aggregate_name), "Service"),
+            _ => ("Aggregate".to_string(), "Aggregate"),
+        };
+        println!(
+            "                {} = component \"{}\" \"{}\" \"Rust\" \"{}\"",
+            Self::get_identifier(&component.id),
+            Self::escape(&component.name),
+            Self::escape(&description),
+            tag
+        );
+    }
+
+    fn print_relationship(&self, relationship: &C4RelationshipReadModel) {
+        let description = match relationship.import_count {
+            0 => relationship.description.clone(),
+            1 => format!("{} (1 import)", relationship.description),
+            import_count => format!("{} ({} imports)", relationship.description, import_count),
+        };
+        println!(
+            "        {} -> {} \"{}\"",
+            Self::get_identifier(&relationship.source),
+            Self::get_identifier(&relationship.target),
+            Self::escape(&description)
+        );
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<FindC4ModelResponseModel> for StructurizrC4Presenter {
+    async fn success(&self, response_model: FindC4ModelResponseModel) {
+        println!("workspace \"{}\" {{", Self::escape(&response_model.project_name));
+        println!("    model {{");
+        println!(
+            "        {} = softwareSystem \"{}\" {{",
+            Self::SYSTEM_IDENTIFIER,
+            Self::escape(&response_model.project_name)
+        );
+        response_model.containers.iter().for_each(|container| {
+            println!(
+                "            {} = container \"{}\" \"Bounded context\" \"Rust\" \"{}\" {{",
+                Self::get_identifier(&container.name),
+                Self::escape(&container.name),
+                Self::CONTAINER_TAG
+            );
+            container.components.iter().for_each(|component| self.print_component(component));
+            println!("            }}");
+        });
+        println!("        }}");
+        response_model.relationships
+            .iter()
+            .for_each(|relationship| self.print_relationship(relationship));
+        println!("    }}");
+        println!("    views {{");
+        println!("        container {} \"Containers\" {{", Self::SYSTEM_IDENTIFIER);
+        println!("            include *");
+        println!("            autoLayout lr");
+        println!("        }}");
+        response_model.containers.iter().for_each(|container| {
+            let identifier = Self::get_identifier(&container.name);
+            println!("        component {} \"{}_components\" {{", identifier, identifier);
+            println!("            include *");
+            println!("            autoLayout lr");
+            println!("        }}");
+        });
+        println!("        styles {{");
+        println!("            element \"Aggregate\" {{");
+        println!("                background #1168bd");
+        println!("                color #ffffff");
+        println!("            }}");
+        println!("            element \"Controller\" {{");
+        println!("                background #85bbf0");
+        println!("            }}");
+        println!("            element \"Service\" {{");
+        println!("                background #dddddd");
+        println!("            }}");
+        println!("        }}");
+        println!("    }}");
+        println!("}}");
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to build C4 model due to: {}", error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::architecture::adapters::presenters::{
+        structurizr_c4_presenter::StructurizrC4Presenter,
+    };
+
+    #[test]
+    fn should_keep_identifiers_of_different_paths_apart() {
+        assert_ne!(
+            StructurizrC4Presenter::get_identifier("a_b/c"),
+            StructurizrC4Presenter::get_identifier("a/b_c")
+        );
+        assert_eq!(StructurizrC4Presenter::get_identifier("sales/order"), "sales_0order");
+    }
+
+    #[test]
+    fn should_escape_quotes_in_names() {
+        assert_eq!(
+            StructurizrC4Presenter::escape("the \"core\" shop"),
+            "the \\\"core\\\" shop"
+        );
+    }
+}
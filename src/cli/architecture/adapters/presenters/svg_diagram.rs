@@ -0,0 +1,343 @@
+use crate::cli::architecture::adapters::presenters::{
+    context_map_diagram::ContextMapDiagram,
+    event_flow_diagram::EventFlowDiagram,
+};
+
+const CHARACTER_WIDTH: f64 = 7.5;
+const LINE_HEIGHT: f64 = 18.0;
+const NODE_PADDING: f64 = 12.0;
+const MINIMUM_COLUMN_GAP: f64 = 120.0;
+const ROW_GAP: f64 = 40.0;
+const MARGIN: f64 = 20.0;
+const EDGE_OFFSET: f64 = 10.0;
+
+pub struct SvgNode {
+    pub id: String,
+    pub lines: Vec<String>,
+    pub is_rounded: bool,
+}
+
+pub struct SvgEdge {
+    pub source: String,
+    pub target: String,
+    pub label: String,
+    pub is_directed: bool,
+    pub is_dashed: bool,
+}
+
+pub struct SvgDiagram {
+    pub nodes: Vec<SvgNode>,
+    pub edges: Vec<SvgEdge>,
+    pub warnings: Vec<String>,
+}
+
+struct NodeBox {
+    center_x: f64,
+    center_y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl NodeBox {
+    // Point where the segment towards (x, y) leaves the box, so edges end at
+    // the border instead of the node center.
+    fn get_border_point(&self, x: f64, y: f64) -> (f64, f64) {
+        let (delta_x, delta_y) = (x - self.center_x, y - self.center_y);
+        if delta_x == 0.0 && delta_y == 0.0 {
+            return (self.center_x, self.center_y);
+        }
+        let scale = f64::min(
+            (self.width / 2.0) / delta_x.abs().max(f64::EPSILON),
+            (self.height / 2.0) / delta_y.abs().max(f64::EPSILON)
+        );
+        (self.center_x + delta_x * scale, self.center_y + delta_y * scale)
+    }
+}
+
+pub fn escape_markup(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl SvgDiagram {
+    fn get_node_index(&self, id: &str) -> Option<usize> {
+        self.nodes.iter().position(|node| node.id == id)
+    }
+
+    fn get_forward_edges(&self) -> Vec<(usize, usize)> {
+        self.edges
+            .iter()
+            .filter_map(|edge| {
+                let source = self.get_node_index(&edge.source)?;
+                let target = self.get_node_index(&edge.target)?;
+                (source != target).then_some((source, target))
+            })
+            .collect()
+    }
+
+    fn visit(
+        node: usize,
+        edges: &[(usize, usize)],
+        states: &mut [u8],
+        forward_edges: &mut Vec<(usize, usize)>
+    ) {
+        states[node] = 1;
+        for (source, target) in edges.iter().filter(|(source, _)| *source == node) {
+            match states[*target] {
+                0 => {
+                    forward_edges.push((*source, *target));
+                    Self::visit(*target, edges, states, forward_edges);
+                }
+                2 => forward_edges.push((*source, *target)),
+                _ => {}
+            }
+        }
+        states[node] = 2;
+    }
+
+    // Nodes are ranked left to right along the edges. Edges closing a cycle
+    // are left out of the ranking so the layout stays finite.
+    fn get_ranks(&self) -> Vec<usize> {
+        let edges = self.get_forward_edges();
+        let mut states = vec![0; self.nodes.len()];
+        let mut acyclic_edges = vec![];
+        for node in 0..self.nodes.len() {
+            if states[node] == 0 {
+                Self::visit(node, &edges, &mut states, &mut acyclic_edges);
+            }
+        }
+        let mut ranks = vec![0; self.nodes.len()];
+        let mut has_changed = true;
+        while has_changed {
+            has_changed = false;
+            for (source, target) in &acyclic_edges {
+                if ranks[*target] <= ranks[*source] {
+                    ranks[*target] = ranks[*source] + 1;
+                    has_changed = true;
+                }
+            }
+        }
+        ranks
+    }
+
+    fn get_node_boxes(&self) -> Vec<NodeBox> {
+        let ranks = self.get_ranks();
+        let sizes = self.nodes
+            .iter()
+            .map(|node| {
+                let longest_line = node.lines
+                    .iter()
+                    .map(|line| line.chars().count())
+                    .max()
+                    .unwrap_or_default();
+                (
+                    (longest_line as f64) * CHARACTER_WIDTH + NODE_PADDING * 2.0,
+                    (node.lines.len() as f64) * LINE_HEIGHT + NODE_PADDING,
+                )
+            })
+            .collect::<Vec<_>>();
+        let column_count = ranks.iter().max().map(|rank| rank + 1).unwrap_or_default();
+        let column_gap = self.edges
+            .iter()
+            .map(|edge| (edge.label.chars().count() as f64) * CHARACTER_WIDTH + NODE_PADDING * 2.0)
+            .fold(MINIMUM_COLUMN_GAP, f64::max);
+        let mut column_x = MARGIN;
+        let mut node_boxes = sizes
+            .iter()
+            .map(|(width, height)| NodeBox {
+                center_x: 0.0,
+                center_y: 0.0,
+                width: *width,
+                height: *height,
+            })
+            .collect::<Vec<_>>();
+        for column in 0..column_count {
+            let members = (0..self.nodes.len())
+                .filter(|index| ranks[*index] == column)
+                .collect::<Vec<_>>();
+            let column_width = members
+                .iter()
+                .map(|index| sizes[*index].0)
+                .fold(0.0, f64::max);
+            let mut row_y = MARGIN;
+            for index in members {
+                let node_box = &mut node_boxes[index];
+                node_box.center_x = column_x + column_width / 2.0;
+                node_box.center_y = row_y + node_box.height / 2.0;
+                row_y += node_box.height + ROW_GAP;
+            }
+            column_x += column_width + column_gap;
+        }
+        node_boxes
+    }
+
+    pub fn render(&self) -> String {
+        let node_boxes = self.get_node_boxes();
+        let width = node_boxes
+            .iter()
+            .map(|node_box| node_box.center_x + node_box.width / 2.0)
+            .fold(0.0, f64::max) + MARGIN;
+        let height = node_boxes
+            .iter()
+            .map(|node_box| node_box.center_y + node_box.height / 2.0)
+            .fold(0.0, f64::max) + MARGIN;
+        let mut lines = vec![
+            format!(
+                "<svg xmlns=\"{}\" width=\"{:.0}\" height=\"{:.0}\" {}>",
+                "http://www.w3.org/2000/svg",
+                width,
+                height,
+                "font-family=\"monospace\" font-size=\"12\""
+            ),
+            [
+                "<defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\"",
+                " markerWidth=\"8\" markerHeight=\"8\" orient=\"auto-start-reverse\">",
+                "<path d=\"M 0 0 L 10 5 L 0 10 z\" fill=\"#555\"/></marker></defs>",
+            ].concat()
+        ];
+        for edge in &self.edges {
+            let source = self.get_node_index(&edge.source);
+            let target = self.get_node_index(&edge.target);
+            let (Some(source), Some(target)) = (source, target) else {
+                continue;
+            };
+            let (source_box, target_box) = (&node_boxes[source], &node_boxes[target]);
+            let (start_x, start_y) = source_box.get_border_point(
+                target_box.center_x,
+                target_box.center_y
+            );
+            let (end_x, end_y) = target_box.get_border_point(
+                source_box.center_x,
+                source_box.center_y
+            );
+            // Edges running both ways between two nodes are shifted apart so
+            // neither line nor label hides the other.
+            let has_reverse_edge = self.edges
+                .iter()
+                .any(|other| other.source == edge.target && other.target == edge.source);
+            let length = f64::hypot(end_x - start_x, end_y - start_y).max(f64::EPSILON);
+            let (offset_x, offset_y) = match has_reverse_edge {
+                true => (
+                    ((start_y - end_y) / length) * EDGE_OFFSET,
+                    ((end_x - start_x) / length) * EDGE_OFFSET,
+                ),
+                false => (0.0, 0.0),
+            };
+            let (start_x, start_y) = (start_x + offset_x, start_y + offset_y);
+            let (end_x, end_y) = (end_x + offset_x, end_y + offset_y);
+            let mut attributes = vec![
+                format!("x1=\"{:.1}\" y1=\"{:.1}\"", start_x, start_y),
+                format!("x2=\"{:.1}\" y2=\"{:.1}\"", end_x, end_y),
+                "stroke=\"#555\"".to_string()
+            ];
+            if edge.is_directed {
+                attributes.push("marker-end=\"url(#arrow)\"".to_string());
+            }
+            if edge.is_dashed {
+                attributes.push("stroke-dasharray=\"6 4\"".to_string());
+            }
+            lines.push(format!("<line {}/>", attributes.join(" ")));
+            lines.push(
+                format!(
+                    "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\" fill=\"#333\">{}</text>",
+                    (start_x + end_x) / 2.0,
+                    (start_y + end_y) / 2.0 - 4.0,
+                    escape_markup(&edge.label)
+                )
+            );
+        }
+        for (node, node_box) in self.nodes.iter().zip(&node_boxes) {
+            let radius = match node.is_rounded {
+                true => node_box.height / 2.0,
+                false => 6.0,
+            };
+            let style = format!("rx=\"{:.1}\" fill=\"#f4f6fb\" stroke=\"#3b4a6b\"", radius);
+            lines.push(
+                format!(
+                    "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" {}/>",
+                    node_box.center_x - node_box.width / 2.0,
+                    node_box.center_y - node_box.height / 2.0,
+                    node_box.width,
+                    node_box.height,
+                    style
+                )
+            );
+            let top = node_box.center_y - node_box.height / 2.0 + NODE_PADDING / 2.0;
+            for (index, line) in node.lines.iter().enumerate() {
+                let weight = match index {
+                    0 => " font-weight=\"bold\"",
+                    _ => "",
+                };
+                lines.push(
+                    format!(
+                        "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\"{}>{}</text>",
+                        node_box.center_x,
+                        top + LINE_HEIGHT * ((index as f64) + 0.75),
+                        weight,
+                        escape_markup(line)
+                    )
+                );
+            }
+        }
+        lines.push("</svg>".to_string());
+        lines.join("\n")
+    }
+}
+
+impl From<&ContextMapDiagram> for SvgDiagram {
+    fn from(diagram: &ContextMapDiagram) -> Self {
+        Self {
+            nodes: diagram.nodes
+                .iter()
+                .map(|node| SvgNode {
+                    id: node.id.clone(),
+                    lines: std::iter
+                        ::once(node.title.clone())
+                        .chain(node.items.iter().cloned())
+                        .collect(),
+                    is_rounded: false,
+                })
+                .collect(),
+            edges: diagram.edges
+                .iter()
+                .map(|edge| SvgEdge {
+                    source: edge.source.clone(),
+                    target: edge.target.clone(),
+                    label: edge.label.clone(),
+                    is_directed: edge.is_directed,
+                    is_dashed: !edge.is_observed,
+                })
+                .collect(),
+            warnings: diagram.warnings.clone(),
+        }
+    }
+}
+
+impl From<&EventFlowDiagram> for SvgDiagram {
+    fn from(diagram: &EventFlowDiagram) -> Self {
+        Self {
+            nodes: diagram.groups
+                .iter()
+                .flat_map(|group| group.nodes.iter())
+                .map(|node| SvgNode {
+                    id: node.id.clone(),
+                    lines: vec![node.title.clone()],
+                    is_rounded: node.is_event,
+                })
+                .collect(),
+            edges: diagram.edges
+                .iter()
+                .map(|edge| SvgEdge {
+                    source: edge.source.clone(),
+                    target: edge.target.clone(),
+                    label: edge.label.clone(),
+                    is_directed: true,
+                    is_dashed: false,
+                })
+                .collect(),
+            warnings: diagram.warnings.clone(),
+        }
+    }
+}
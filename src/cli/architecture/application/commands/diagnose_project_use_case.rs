@@ -0,0 +1,108 @@
+use std::error::Error;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use crate::{
+    cli::architecture::domain::{
+        repositories::project_structure_repository::ProjectStructureRepository,
+        value_objects::structural_issue::StructuralIssue,
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::value_object::ValueObject,
+    },
+};
+
+pub struct DiagnoseProjectRequestModel {
+    pub fix: bool,
+}
+
+#[derive(Clone)]
+pub struct StructuralIssueReadModel {
+    pub rule: String,
+    pub path: String,
+    pub description: String,
+    pub is_fixable: bool,
+}
+
+pub struct DiagnoseProjectResponseModel {
+    pub issues: Vec<StructuralIssueReadModel>,
+    pub fixed_issues: Vec<StructuralIssueReadModel>,
+}
+
+pub struct DiagnoseProjectUseCase<'a> {
+    repository: &'a dyn ProjectStructureRepository,
+    output_port: &'a dyn UseCaseOutputPort<DiagnoseProjectResponseModel>,
+}
+
+impl<'a> DiagnoseProjectUseCase<'a> {
+    pub fn new(
+        repository: &'a dyn ProjectStructureRepository,
+        output_port: &'a dyn UseCaseOutputPort<DiagnoseProjectResponseModel>
+    ) -> Self {
+        Self {
+            repository,
+            output_port,
+        }
+    }
+
+    fn to_read_models(&self, issues: &[StructuralIssue]) -> Vec<StructuralIssueReadModel> {
+        issues
+            .iter()
+            .map(|issue| {
+                let value = issue.get_value();
+                StructuralIssueReadModel {
+                    rule: value.kind.to_string(),
+                    path: value.path.clone(),
+                    description: value.description.clone(),
+                    is_fixable: value.kind.is_fixable(),
+                }
+            })
+            .collect()
+    }
+
+    async fn try_interact(
+        &self,
+        request_model: DiagnoseProjectRequestModel
+    ) -> Result<DiagnoseProjectResponseModel, Box<dyn Error + Send + Sync>> {
+        let mut project_structure = self.repository.read_project_structure().await?;
+        let issues = project_structure.diagnose();
+        if !request_model.fix {
+            return Ok(DiagnoseProjectResponseModel {
+                issues: self.to_read_models(&issues),
+                fixed_issues: vec![],
+            });
+        }
+        for module_directory in project_structure.repair_module_declarations() {
+            self.repository.write_module_declarations(&module_directory).await?;
+        }
+        let remaining_issues = project_structure.diagnose();
+        let fixed_issues = issues
+            .iter()
+            .filter(|issue| !remaining_issues.iter().any(|remaining| remaining.is_equal(issue)))
+            .cloned()
+            .collect::<Vec<_>>();
+        Ok(DiagnoseProjectResponseModel {
+            issues: self.to_read_models(&remaining_issues),
+            fixed_issues: self.to_read_models(&fixed_issues),
+        })
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<DiagnoseProjectRequestModel> for DiagnoseProjectUseCase<'a> {
+    async fn interact(&self, request_model: DiagnoseProjectRequestModel) {
+        let result = self.try_interact(request_model).await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
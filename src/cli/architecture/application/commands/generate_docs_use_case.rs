@@ -0,0 +1,313 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use crate::{
+    cli::{
+        architecture::domain::{
+            entities::event_catalog::{ EventCatalog, EventParticipant },
+            repositories::{
+                context_readme_repository::ContextReadmeRepository,
+                source_file_repository::SourceFileRepository,
+            },
+            services::source_analyzer::SourceAnalyzer,
+            value_objects::context_readme::ContextReadme,
+        },
+        bounded_context::{
+            application::queries::find_bounded_contexts_use_case::BoundedContextReadModel,
+            domain::{
+                entities::bounded_context::BoundedContext,
+                enums::component_type::ComponentType,
+                repositories::{
+                    bounded_context_repository::BoundedContextRepository,
+                    find_bounded_contexts_repository::FindBoundedContextsRepository,
+                },
+            },
+        },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::{
+            entity::Entity,
+            identity_object::IdentityObject,
+            value_object::ValueObject,
+        },
+    },
+};
+
+pub struct GenerateDocsRequestModel;
+
+pub struct GenerateDocsResponseModel {
+    pub created_bounded_contexts: Vec<String>,
+    pub updated_bounded_contexts: Vec<String>,
+    pub unchanged_bounded_contexts: Vec<String>,
+}
+
+pub struct GenerateDocsUseCase<'a> {
+    find_repository: &'a dyn FindBoundedContextsRepository<BoundedContextReadModel>,
+    bounded_context_repository: &'a dyn BoundedContextRepository,
+    source_file_repository: &'a dyn SourceFileRepository,
+    source_analyzer: &'a dyn SourceAnalyzer,
+    readme_repository: &'a dyn ContextReadmeRepository,
+    output_port: &'a dyn UseCaseOutputPort<GenerateDocsResponseModel>,
+}
+
+impl<'a> GenerateDocsUseCase<'a> {
+    pub fn new(
+        find_repository: &'a dyn FindBoundedContextsRepository<BoundedContextReadModel>,
+        bounded_context_repository: &'a dyn BoundedContextRepository,
+        source_file_repository: &'a dyn SourceFileRepository,
+        source_analyzer: &'a dyn SourceAnalyzer,
+        readme_repository: &'a dyn ContextReadmeRepository,
+        output_port: &'a dyn UseCaseOutputPort<GenerateDocsResponseModel>
+    ) -> Self {
+        Self {
+            find_repository,
+            bounded_context_repository,
+            source_file_repository,
+            source_analyzer,
+            readme_repository,
+            output_port,
+        }
+    }
+
+    async fn read_event_catalog(&self) -> Result<EventCatalog, Box<dyn Error + Send + Sync>> {
+        let source_files = self.source_file_repository.read_source_files().await?;
+        let mut event_catalog = EventCatalog::new(IdentityObject::new("docs".to_string()));
+        event_catalog.add_source_files(&source_files, self.source_analyzer);
+        Ok(event_catalog)
+    }
+
+    fn is_in_bounded_context(participant: &EventParticipant, bounded_context_name: &str) -> bool {
+        participant.module_name == bounded_context_name ||
+            participant.module_name.starts_with(&format!("{}/", bounded_context_name))
+    }
+
+    fn render_names(names: &[String]) -> String {
+        match names.is_empty() {
+            true => "none".to_string(),
+            false =>
+                names
+                    .iter()
+                    .map(|name| format!("`{}`", name))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+        }
+    }
+
+    fn render_participant(participant: &EventParticipant) -> String {
+        format!("`{}` ({})", participant.name, participant.module_name)
+    }
+
+    fn render_participants(participants: &[EventParticipant]) -> String {
+        match participants.is_empty() {
+            true => "none".to_string(),
+            false =>
+                participants
+                    .iter()
+                    .map(Self::render_participant)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+        }
+    }
+
+    fn render_aggregates(bounded_context: &BoundedContext) -> Vec<String> {
+        let mut lines = vec!["## Aggregates".to_string(), String::new()];
+        if bounded_context.aggregates.is_empty() {
+            lines.push("No aggregates yet.".to_string());
+            lines.push(String::new());
+        }
+        for aggregate in &bounded_context.aggregates {
+            lines.push(format!("### {}", aggregate.get_id()));
+            lines.push(String::new());
+            for (label, component_type) in [
+                ("Commands", ComponentType::Commands),
+                ("Queries", ComponentType::Queries),
+                ("Events", ComponentType::Events),
+                ("Subscribers", ComponentType::Subscribers),
+            ] {
+                let names = aggregate
+                    .get_layers()
+                    .iter()
+                    .flat_map(|layer| layer.get_value().components.iter())
+                    .filter(|component| component.get_value().component_type == component_type)
+                    .map(|component| component.get_value().component_name.get_value().to_string())
+                    .collect::<Vec<_>>();
+                lines.push(format!("- {}: {}", label, Self::render_names(&names)));
+            }
+            lines.push(String::new());
+        }
+        lines
+    }
+
+    fn render_event_catalog(
+        bounded_context_name: &str,
+        event_catalog: &EventCatalog
+    ) -> Vec<String> {
+        let mut lines = vec!["## Event catalog".to_string(), String::new()];
+        let events = event_catalog
+            .get_events()
+            .iter()
+            .filter(|event| Self::is_in_bounded_context(&event.declaration, bounded_context_name))
+            .collect::<Vec<_>>();
+        match events.is_empty() {
+            true => {
+                lines.push("No domain events are declared in this bounded context.".to_string());
+            }
+            false => {
+                lines.push("| Event | Declared by | Raised by | Subscribers |".to_string());
+                lines.push("| --- | --- | --- | --- |".to_string());
+                for event in events {
+                    lines.push(
+                        format!(
+                            "| `{}` | {} | {} | {} |",
+                            event.event_name,
+                            Self::render_participant(&event.declaration),
+                            Self::render_participants(&event.publishers),
+                            Self::render_participants(&event.subscribers)
+                        )
+                    );
+                }
+            }
+        }
+        lines.push(String::new());
+        let mut subscriptions = event_catalog
+            .get_events()
+            .iter()
+            .flat_map(|event| {
+                event.subscribers
+                    .iter()
+                    .map(|subscriber| (subscriber, format!("`{}`", event.event_name)))
+            })
+            .chain(
+                event_catalog
+                    .get_unmatched_subscriptions()
+                    .iter()
+                    .map(|unmatched_subscription| {
+                        let event_name = match &unmatched_subscription.event_name {
+                            Some(event_name) => format!("`{}` (unknown event)", event_name),
+                            None => "unresolved".to_string(),
+                        };
+                        (&unmatched_subscription.subscriber, event_name)
+                    })
+            )
+            .filter(|(subscriber, _)| Self::is_in_bounded_context(subscriber, bounded_context_name))
+            .collect::<Vec<_>>();
+        if !subscriptions.is_empty() {
+            subscriptions.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+            lines.push("### Subscriptions".to_string());
+            lines.push(String::new());
+            lines.push("| Subscriber | Subscribed to |".to_string());
+            lines.push("| --- | --- |".to_string());
+            for (subscriber, event_name) in subscriptions {
+                lines.push(
+                    format!(
+                        "| {} | {} |",
+                        Self::render_participant(subscriber),
+                        event_name
+                    )
+                );
+            }
+            lines.push(String::new());
+        }
+        lines
+    }
+
+    fn render_glossary(bounded_context: &BoundedContext) -> Vec<String> {
+        let mut lines = vec!["## Glossary".to_string(), String::new()];
+        let terms = bounded_context.glossary
+            .as_ref()
+            .map(|glossary| glossary.get_value().terms.clone())
+            .unwrap_or_default();
+        if terms.is_empty() {
+            lines.push("No glossary terms are defined in `glossary.toml` yet.".to_string());
+            return lines;
+        }
+        lines.push("| Term | Definition | Avoid |".to_string());
+        lines.push("| --- | --- | --- |".to_string());
+        for term in terms {
+            lines.push(
+                format!(
+                    "| {} | {} | {} |",
+                    term.name,
+                    term.definition.unwrap_or_default(),
+                    term.forbidden_synonyms.join(", ")
+                )
+            );
+        }
+        lines
+    }
+
+    fn render_generated_section(
+        bounded_context: &BoundedContext,
+        event_catalog: &EventCatalog
+    ) -> String {
+        let bounded_context_name = bounded_context.get_id().to_string();
+        let mut lines = vec![
+            "_This section is generated by `arpeggio docs`; edit outside the markers._".to_string(),
+            String::new()
+        ];
+        lines.extend(Self::render_aggregates(bounded_context));
+        lines.extend(Self::render_event_catalog(&bounded_context_name, event_catalog));
+        lines.extend(Self::render_glossary(bounded_context));
+        lines.join("\n")
+    }
+
+    async fn try_interact(
+        &self
+    ) -> Result<GenerateDocsResponseModel, Box<dyn Error + Send + Sync>> {
+        let event_catalog = self.read_event_catalog().await?;
+        let mut response_model = GenerateDocsResponseModel {
+            created_bounded_contexts: vec![],
+            updated_bounded_contexts: vec![],
+            unchanged_bounded_contexts: vec![],
+        };
+        for bounded_context_read_model in self.find_repository.list_bounded_contexts().await? {
+            let bounded_context = self.bounded_context_repository.read_bounded_context(
+                &IdentityObject::new(bounded_context_read_model.name.clone())
+            ).await?;
+            let Some(bounded_context) = bounded_context else {
+                continue;
+            };
+            let generated_section = Self::render_generated_section(
+                &bounded_context,
+                &event_catalog
+            );
+            let bounded_context_name = bounded_context_read_model.name;
+            match self.readme_repository.read_context_readme(&bounded_context_name).await? {
+                Some(readme) => {
+                    let updated_readme = readme.with_generated_section(&generated_section);
+                    if updated_readme.is_equal(&readme) {
+                        response_model.unchanged_bounded_contexts.push(bounded_context_name);
+                        continue;
+                    }
+                    self.readme_repository.write_context_readme(&updated_readme).await?;
+                    response_model.updated_bounded_contexts.push(bounded_context_name);
+                }
+                None => {
+                    let readme = ContextReadme::create(&bounded_context_name, &generated_section);
+                    self.readme_repository.write_context_readme(&readme).await?;
+                    response_model.created_bounded_contexts.push(bounded_context_name);
+                }
+            }
+        }
+        Ok(response_model)
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<GenerateDocsRequestModel> for GenerateDocsUseCase<'a> {
+    async fn interact(&self, _request_model: GenerateDocsRequestModel) {
+        let result = self.try_interact().await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod diagnose_project_use_case;
+pub mod generate_docs_use_case;
@@ -0,0 +1,267 @@
+use std::{ collections::BTreeSet, error::Error };
+
+use async_trait::async_trait;
+use crate::{
+    cli::{
+        architecture::domain::{
+            entities::dependency_graph::DependencyGraph,
+            repositories::source_file_repository::SourceFileRepository,
+            services::source_analyzer::SourceAnalyzer,
+            value_objects::{
+                dependency_cycle::DependencyCycle,
+                module_dependency::{ ModuleDependency, ModuleDependencyValue },
+                source_file::SourceFile,
+            },
+        },
+        bounded_context::domain::{
+            repositories::glossary_repository::GlossaryRepository,
+            value_objects::glossary::Glossary,
+        },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::{ identity_object::IdentityObject, value_object::ValueObject },
+    },
+};
+
+pub struct CheckArchitectureRequestModel;
+
+#[derive(Clone)]
+pub struct ModuleImportReadModel {
+    pub source_module: String,
+    pub target_module: String,
+    pub file_path: String,
+    pub line: usize,
+    pub statement: String,
+}
+
+#[derive(Clone)]
+pub struct DependencyCycleReadModel {
+    pub modules: Vec<String>,
+    pub imports: Vec<ModuleImportReadModel>,
+}
+
+#[derive(Clone)]
+pub struct GlossaryViolationReadModel {
+    pub bounded_context_name: String,
+    pub name: String,
+    pub path: String,
+    pub forbidden_term: String,
+    pub preferred_term: String,
+    pub is_strict: bool,
+}
+
+pub struct CheckArchitectureResponseModel {
+    pub aggregate_cycles: Vec<DependencyCycleReadModel>,
+    pub bounded_context_cycles: Vec<DependencyCycleReadModel>,
+    pub glossary_violations: Vec<GlossaryViolationReadModel>,
+}
+
+pub struct CheckArchitectureUseCase<'a> {
+    repository: &'a dyn SourceFileRepository,
+    glossary_repository: &'a dyn GlossaryRepository,
+    source_analyzer: &'a dyn SourceAnalyzer,
+    output_port: &'a dyn UseCaseOutputPort<CheckArchitectureResponseModel>,
+}
+
+impl<'a> CheckArchitectureUseCase<'a> {
+    pub fn new(
+        repository: &'a dyn SourceFileRepository,
+        glossary_repository: &'a dyn GlossaryRepository,
+        source_analyzer: &'a dyn SourceAnalyzer,
+        output_port: &'a dyn UseCaseOutputPort<CheckArchitectureResponseModel>
+    ) -> Self {
+        Self {
+            repository,
+            glossary_repository,
+            source_analyzer,
+            output_port,
+        }
+    }
+
+    fn find_glossary_violations(
+        &self,
+        bounded_context_name: &str,
+        glossary: &Glossary,
+        source_files: &[SourceFile]
+    ) -> Vec<GlossaryViolationReadModel> {
+        let mut named_paths = BTreeSet::new();
+        source_files.iter().for_each(|source_file| {
+            let value = source_file.get_value();
+            if let Some(aggregate_name) = &value.aggregate_name {
+                let aggregate_directory = format!("/{}/{}/", value.bounded_context_name, aggregate_name);
+                if let Some(position) = value.path.find(&aggregate_directory) {
+                    named_paths.insert((
+                        aggregate_name.clone(),
+                        value.path[..position + aggregate_directory.len() - 1].to_string(),
+                    ));
+                }
+            }
+            let file_name = source_file.get_component_name();
+            if file_name != "mod" {
+                named_paths.insert((file_name, value.path.clone()));
+            }
+        });
+        named_paths
+            .iter()
+            .flat_map(|(name, path)| {
+                glossary
+                    .find_forbidden_terms(name)
+                    .into_iter()
+                    .map(|usage| GlossaryViolationReadModel {
+                        bounded_context_name: bounded_context_name.to_string(),
+                        name: name.clone(),
+                        path: path.clone(),
+                        forbidden_term: usage.forbidden_term,
+                        preferred_term: usage.preferred_term,
+                        is_strict: glossary.get_value().is_strict,
+                    })
+            })
+            .collect()
+    }
+
+    fn build_dependency_graphs(
+        &self,
+        source_files: &[SourceFile]
+    ) -> (DependencyGraph, DependencyGraph) {
+        let bounded_contexts = source_files
+            .iter()
+            .map(|source_file| source_file.get_value().bounded_context_name.clone())
+            .collect::<BTreeSet<_>>();
+        let aggregates = source_files
+            .iter()
+            .filter(|source_file| source_file.get_value().aggregate_name.is_some())
+            .map(|source_file| source_file.get_module_name())
+            .collect::<BTreeSet<_>>();
+        let mut aggregate_graph = DependencyGraph::new(IdentityObject::new("aggregates".to_string()));
+        let mut bounded_context_graph = DependencyGraph::new(
+            IdentityObject::new("bounded_contexts".to_string())
+        );
+        source_files.iter().for_each(|source_file| {
+            let source_value = source_file.get_value();
+            self.source_analyzer
+                .find_imports(source_file)
+                .iter()
+                .for_each(|import| {
+                    let import_value = import.get_value();
+                    let Some(target_bounded_context) = import_value.path
+                        .first()
+                        .filter(|name| bounded_contexts.contains(*name)) else {
+                        return;
+                    };
+                    let dependency = |source_module: String, target_module: String| {
+                        ModuleDependency::new(ModuleDependencyValue {
+                            source_module,
+                            target_module,
+                            file_path: source_value.path.clone(),
+                            line: import_value.line,
+                            statement: import_value.statement.clone(),
+                        })
+                    };
+                    bounded_context_graph.add_dependency(
+                        dependency(
+                            source_value.bounded_context_name.clone(),
+                            target_bounded_context.clone()
+                        )
+                    );
+                    let target_aggregate = import_value.path
+                        .get(1)
+                        .map(|name| format!("{}/{}", target_bounded_context, name))
+                        .filter(|name| {
+                            source_value.aggregate_name.is_some() && aggregates.contains(name)
+                        });
+                    if let Some(target_aggregate) = target_aggregate {
+                        aggregate_graph.add_dependency(
+                            dependency(source_file.get_module_name(), target_aggregate)
+                        );
+                    }
+                });
+        });
+        (aggregate_graph, bounded_context_graph)
+    }
+
+    fn to_read_model(&self, cycle: &DependencyCycle) -> DependencyCycleReadModel {
+        DependencyCycleReadModel {
+            modules: cycle.get_modules(),
+            imports: cycle
+                .get_value()
+                .iter()
+                .map(|dependency| {
+                    let value = dependency.get_value();
+                    ModuleImportReadModel {
+                        source_module: value.source_module.clone(),
+                        target_module: value.target_module.clone(),
+                        file_path: value.file_path.clone(),
+                        line: value.line,
+                        statement: value.statement.clone(),
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    async fn try_interact(
+        &self,
+        _request_model: CheckArchitectureRequestModel
+    ) -> Result<CheckArchitectureResponseModel, Box<dyn Error + Send + Sync>> {
+        let source_files = self.repository.read_source_files().await?;
+        let (aggregate_graph, bounded_context_graph) = self.build_dependency_graphs(&source_files);
+        let bounded_context_names = source_files
+            .iter()
+            .map(|source_file| source_file.get_value().bounded_context_name.clone())
+            .collect::<BTreeSet<_>>();
+        let mut glossary_violations = vec![];
+        for bounded_context_name in bounded_context_names {
+            let glossary = self.glossary_repository.read_glossary(
+                &IdentityObject::new(bounded_context_name.clone())
+            ).await?;
+            if let Some(glossary) = glossary {
+                let bounded_context_files = source_files
+                    .iter()
+                    .filter(|source_file| {
+                        source_file.get_value().bounded_context_name == bounded_context_name
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                glossary_violations.extend(
+                    self.find_glossary_violations(
+                        &bounded_context_name,
+                        &glossary,
+                        &bounded_context_files
+                    )
+                );
+            }
+        }
+        Ok(CheckArchitectureResponseModel {
+            glossary_violations,
+            aggregate_cycles: aggregate_graph
+                .find_cycles()
+                .iter()
+                .map(|cycle| self.to_read_model(cycle))
+                .collect(),
+            bounded_context_cycles: bounded_context_graph
+                .find_cycles()
+                .iter()
+                .map(|cycle| self.to_read_model(cycle))
+                .collect(),
+        })
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<CheckArchitectureRequestModel> for CheckArchitectureUseCase<'a> {
+    async fn interact(&self, request_model: CheckArchitectureRequestModel) {
+        let result = self.try_interact(request_model).await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use crate::{
+    cli::{
+        architecture::domain::{
+            enums::source_item_kind::SourceItemKind,
+            repositories::source_file_repository::SourceFileRepository,
+            services::source_analyzer::SourceAnalyzer,
+            value_objects::type_definition::TypeDefinition,
+        },
+        bounded_context::domain::{
+            enums::{ component_type::ComponentType, layer_name::LayerName },
+            repositories::bounded_context_repository::BoundedContextRepository,
+        },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::{
+            entity::Entity,
+            identity_object::IdentityObject,
+            value_object::ValueObject,
+        },
+    },
+};
+
+pub struct FindAggregateDiagramRequestModel {
+    pub bounded_context_name: String,
+    pub aggregate_name: String,
+}
+
+#[derive(PartialEq)]
+pub enum ClassRelationKind {
+    Association,
+    Dependency,
+    Realization,
+    Raises,
+}
+
+pub struct DiagramMemberReadModel {
+    pub name: String,
+    pub type_name: Option<String>,
+    pub parameters: Option<Vec<String>>,
+}
+
+pub struct DiagramClassReadModel {
+    pub component_type: ComponentType,
+    pub kind: SourceItemKind,
+    pub name: String,
+    pub implemented_traits: Vec<String>,
+    pub members: Vec<DiagramMemberReadModel>,
+}
+
+pub struct DiagramRelationReadModel {
+    pub source: String,
+    pub target: String,
+    pub kind: ClassRelationKind,
+    pub label: Option<String>,
+}
+
+pub struct FindAggregateDiagramResponseModel {
+    pub bounded_context_name: String,
+    pub aggregate_name: String,
+    pub classes: Vec<DiagramClassReadModel>,
+    pub relations: Vec<DiagramRelationReadModel>,
+}
+
+pub struct FindAggregateDiagramUseCase<'a> {
+    bounded_context_repository: &'a dyn BoundedContextRepository,
+    source_file_repository: &'a dyn SourceFileRepository,
+    source_analyzer: &'a dyn SourceAnalyzer,
+    output_port: &'a dyn UseCaseOutputPort<FindAggregateDiagramResponseModel>,
+}
+
+impl<'a> FindAggregateDiagramUseCase<'a> {
+    pub fn new(
+        bounded_context_repository: &'a dyn BoundedContextRepository,
+        source_file_repository: &'a dyn SourceFileRepository,
+        source_analyzer: &'a dyn SourceAnalyzer,
+        output_port: &'a dyn UseCaseOutputPort<FindAggregateDiagramResponseModel>
+    ) -> Self {
+        Self {
+            bounded_context_repository,
+            source_file_repository,
+            source_analyzer,
+            output_port,
+        }
+    }
+
+    fn get_component_types() -> Vec<ComponentType> {
+        vec![
+            ComponentType::Entities,
+            ComponentType::ValueObjects,
+            ComponentType::Events,
+            ComponentType::Repositories
+        ]
+    }
+
+    fn find_relations(
+        &self,
+        definitions: &[(ComponentType, TypeDefinition)]
+    ) -> Vec<DiagramRelationReadModel> {
+        let is_known = |name: &String| {
+            definitions.iter().any(|(_, definition)| &definition.get_value().name == name)
+        };
+        let mut relations = vec![];
+        for (_, definition) in definitions {
+            let value = definition.get_value();
+            for (member_name, type_name) in definition.get_referenced_types() {
+                if type_name == value.name || !is_known(&type_name) {
+                    continue;
+                }
+                let relation = match value.kind {
+                    SourceItemKind::Trait =>
+                        DiagramRelationReadModel {
+                            source: value.name.clone(),
+                            target: type_name,
+                            kind: ClassRelationKind::Dependency,
+                            label: None,
+                        },
+                    _ =>
+                        DiagramRelationReadModel {
+                            source: value.name.clone(),
+                            target: type_name,
+                            kind: ClassRelationKind::Association,
+                            label: Some(member_name),
+                        },
+                };
+                let is_duplicate = relations.iter().any(|existing: &DiagramRelationReadModel| {
+                    existing.source == relation.source &&
+                        existing.target == relation.target &&
+                        existing.kind == relation.kind &&
+                        existing.label == relation.label
+                });
+                if !is_duplicate {
+                    relations.push(relation);
+                }
+            }
+            value.implemented_traits
+                .iter()
+                .filter(|trait_name| is_known(trait_name))
+                .for_each(|trait_name| {
+                    relations.push(DiagramRelationReadModel {
+                        source: value.name.clone(),
+                        target: trait_name.clone(),
+                        kind: ClassRelationKind::Realization,
+                        label: None,
+                    });
+                });
+        }
+        relations
+    }
+
+    async fn try_interact(
+        &self,
+        request_model: &FindAggregateDiagramRequestModel
+    ) -> Result<FindAggregateDiagramResponseModel, Box<dyn Error + Send + Sync>> {
+        let bounded_context_id = IdentityObject::new(request_model.bounded_context_name.clone());
+        let bounded_context = self.bounded_context_repository
+            .read_bounded_context(&bounded_context_id).await?
+            .ok_or_else(|| format!("Bounded context <{}> not found", bounded_context_id))?;
+        let aggregate = bounded_context.aggregates
+            .iter()
+            .find(|aggregate| aggregate.get_id().get_value() == &request_model.aggregate_name)
+            .ok_or_else(|| format!("Aggregate <{}> not found", request_model.aggregate_name))?;
+        let Some(domain_layer) = aggregate
+            .get_layers()
+            .iter()
+            .find(|layer| layer.get_value().name == LayerName::Domain) else {
+            return Err(
+                format!("Aggregate <{}> has no domain layer", request_model.aggregate_name).into()
+            );
+        };
+        let source_files = self.source_file_repository.read_source_files().await?;
+        let mut definitions = vec![];
+        let mut raised_events = vec![];
+        for component_type in Self::get_component_types() {
+            let mut component_names = domain_layer
+                .get_value()
+                .components.iter()
+                .filter(|component| component.get_value().component_type == component_type)
+                .map(|component| component.get_value().component_name.get_value().clone())
+                .collect::<Vec<_>>();
+            component_names.sort();
+            for component_name in component_names {
+                let source_file = source_files.iter().find(|source_file| {
+                    let value = source_file.get_value();
+                    value.bounded_context_name == request_model.bounded_context_name &&
+                        value.aggregate_name.as_ref() == Some(&request_model.aggregate_name) &&
+                        value.layer_name == Some(LayerName::Domain) &&
+                        value.component_type.as_ref() == Some(&component_type) &&
+                        source_file.get_component_name() == component_name
+                });
+                let Some(source_file) = source_file else {
+                    continue;
+                };
+                let file_definitions = self.source_analyzer.find_type_definitions(source_file);
+                // Events are attributed to the first struct of the file raising
+                // them, which is where aggregate roots are declared.
+                let raiser = file_definitions
+                    .iter()
+                    .find(|definition| definition.get_value().kind == SourceItemKind::Struct)
+                    .map(|definition| definition.get_value().name.clone());
+                if let Some(raiser) = raiser {
+                    self.source_analyzer
+                        .find_raised_events(source_file)
+                        .iter()
+                        .for_each(|raised_event| {
+                            let event_name = raised_event.get_value().event_name.clone();
+                            if !raised_events.contains(&(raiser.clone(), event_name.clone())) {
+                                raised_events.push((raiser.clone(), event_name));
+                            }
+                        });
+                }
+                definitions.extend(
+                    file_definitions
+                        .into_iter()
+                        .map(|definition| (component_type.clone(), definition))
+                );
+            }
+        }
+        let mut relations = self.find_relations(&definitions);
+        raised_events
+            .into_iter()
+            .filter(|(_, event_name)| {
+                definitions
+                    .iter()
+                    .any(|(_, definition)| &definition.get_value().name == event_name)
+            })
+            .for_each(|(raiser, event_name)| {
+                relations.push(DiagramRelationReadModel {
+                    source: raiser,
+                    target: event_name,
+                    kind: ClassRelationKind::Raises,
+                    label: None,
+                });
+            });
+        Ok(FindAggregateDiagramResponseModel {
+            bounded_context_name: request_model.bounded_context_name.clone(),
+            aggregate_name: request_model.aggregate_name.clone(),
+            classes: definitions
+                .into_iter()
+                .map(|(component_type, definition)| {
+                    let value = definition.get_value();
+                    DiagramClassReadModel {
+                        component_type,
+                        kind: value.kind.clone(),
+                        name: value.name.clone(),
+                        implemented_traits: value.implemented_traits.clone(),
+                        members: value.members
+                            .iter()
+                            .map(|member| DiagramMemberReadModel {
+                                name: member.name.clone(),
+                                type_name: member.type_name.clone(),
+                                parameters: member.parameters.clone(),
+                            })
+                            .collect(),
+                    }
+                })
+                .collect(),
+            relations,
+        })
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<FindAggregateDiagramRequestModel> for FindAggregateDiagramUseCase<'a> {
+    async fn interact(&self, request_model: FindAggregateDiagramRequestModel) {
+        let result = self.try_interact(&request_model).await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
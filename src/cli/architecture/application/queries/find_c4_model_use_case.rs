@@ -0,0 +1,229 @@
+use std::{ collections::BTreeMap, error::Error };
+
+use async_trait::async_trait;
+use crate::{
+    cli::{
+        architecture::domain::{
+            repositories::{
+                project_config_repository::ProjectConfigRepository,
+                source_file_repository::SourceFileRepository,
+            },
+            services::source_analyzer::SourceAnalyzer,
+            value_objects::source_file::SourceFile,
+        },
+        bounded_context::domain::enums::{ component_type::ComponentType, layer_name::LayerName },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::value_object::ValueObject,
+    },
+};
+
+pub struct FindC4ModelRequestModel;
+
+#[derive(Clone)]
+pub struct C4ComponentReadModel {
+    pub id: String,
+    pub name: String,
+    pub aggregate_id: String,
+    pub component_type: Option<ComponentType>,
+}
+
+pub struct C4ContainerReadModel {
+    pub name: String,
+    pub components: Vec<C4ComponentReadModel>,
+}
+
+pub struct C4RelationshipReadModel {
+    pub source: String,
+    pub target: String,
+    pub description: String,
+    pub import_count: usize,
+}
+
+pub struct FindC4ModelResponseModel {
+    pub project_name: String,
+    pub containers: Vec<C4ContainerReadModel>,
+    pub relationships: Vec<C4RelationshipReadModel>,
+}
+
+pub struct FindC4ModelUseCase<'a> {
+    repository: &'a dyn SourceFileRepository,
+    config_repository: &'a dyn ProjectConfigRepository,
+    source_analyzer: &'a dyn SourceAnalyzer,
+    output_port: &'a dyn UseCaseOutputPort<FindC4ModelResponseModel>,
+}
+
+impl<'a> FindC4ModelUseCase<'a> {
+    pub fn new(
+        repository: &'a dyn SourceFileRepository,
+        config_repository: &'a dyn ProjectConfigRepository,
+        source_analyzer: &'a dyn SourceAnalyzer,
+        output_port: &'a dyn UseCaseOutputPort<FindC4ModelResponseModel>
+    ) -> Self {
+        Self {
+            repository,
+            config_repository,
+            source_analyzer,
+            output_port,
+        }
+    }
+
+    // Adapter controllers and infrastructure services are the components that
+    // face the outside world, so they are drawn apart from their aggregate.
+    fn is_standalone_component(layer_name: &LayerName, component_type: &ComponentType) -> bool {
+        matches!(
+            (layer_name, component_type),
+            (LayerName::Adapters, ComponentType::Controllers) |
+                (LayerName::Infrastructure, ComponentType::Services)
+        )
+    }
+
+    fn get_aggregate_component(source_file: &SourceFile) -> Option<C4ComponentReadModel> {
+        let value = source_file.get_value();
+        let aggregate_name = value.aggregate_name.clone()?;
+        let aggregate_id = format!("{}/{}", value.bounded_context_name, aggregate_name);
+        Some(C4ComponentReadModel {
+            id: aggregate_id.clone(),
+            name: aggregate_name,
+            aggregate_id,
+            component_type: None,
+        })
+    }
+
+    fn get_component(source_file: &SourceFile) -> Option<C4ComponentReadModel> {
+        let aggregate_component = Self::get_aggregate_component(source_file)?;
+        let value = source_file.get_value();
+        let component_name = source_file.get_component_name();
+        match (&value.layer_name, &value.component_type) {
+            (Some(layer_name), Some(component_type)) if
+                component_name != "mod" &&
+                Self::is_standalone_component(layer_name, component_type)
+            =>
+                Some(C4ComponentReadModel {
+                    id: format!(
+                        "{}/{}/{}/{}",
+                        aggregate_component.id,
+                        layer_name,
+                        component_type,
+                        component_name
+                    ),
+                    name: component_name,
+                    aggregate_id: aggregate_component.id,
+                    component_type: Some(component_type.clone()),
+                }),
+            _ => Some(aggregate_component),
+        }
+    }
+
+    fn find_target_component(
+        path: &[String],
+        components: &BTreeMap<String, C4ComponentReadModel>
+    ) -> Option<String> {
+        let aggregate_id = format!("{}/{}", path.first()?, path.get(1)?);
+        if path.len() >= 5 {
+            let component_id = format!("{}/{}", aggregate_id, path[2..5].join("/"));
+            if components.contains_key(&component_id) {
+                return Some(component_id);
+            }
+        }
+        components.contains_key(&aggregate_id).then_some(aggregate_id)
+    }
+
+    async fn try_interact(
+        &self
+    ) -> Result<FindC4ModelResponseModel, Box<dyn Error + Send + Sync>> {
+        let source_files = self.repository.read_source_files().await?;
+        let mut containers: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let mut components = BTreeMap::new();
+        source_files.iter().for_each(|source_file| {
+            let container = containers
+                .entry(source_file.get_value().bounded_context_name.clone())
+                .or_default();
+            let found_components = [
+                Self::get_aggregate_component(source_file),
+                Self::get_component(source_file),
+            ];
+            for component in found_components.into_iter().flatten() {
+                if !container.contains(&component.id) {
+                    container.push(component.id.clone());
+                }
+                components.insert(component.id.clone(), component);
+            }
+        });
+        let mut relationships: BTreeMap<(String, String), (String, usize)> = BTreeMap::new();
+        source_files.iter().for_each(|source_file| {
+            let Some(source_component) = Self::get_component(source_file) else {
+                return;
+            };
+            self.source_analyzer
+                .find_imports(source_file)
+                .iter()
+                .filter_map(|import| {
+                    Self::find_target_component(&import.get_value().path, &components)
+                })
+                .filter(|target_id| target_id != &source_component.id)
+                .for_each(|target_id| {
+                    relationships
+                        .entry((source_component.id.clone(), target_id))
+                        .or_insert_with(|| ("Uses".to_string(), 0))
+                        .1 += 1;
+                });
+        });
+        components
+            .values()
+            .filter_map(|component| {
+                let aggregate_id = component.aggregate_id.clone();
+                match component.component_type {
+                    Some(ComponentType::Controllers) =>
+                        Some((component.id.clone(), aggregate_id, "Delivers requests to")),
+                    Some(ComponentType::Services) =>
+                        Some((aggregate_id, component.id.clone(), "Uses")),
+                    _ => None,
+                }
+            })
+            .for_each(|(source, target, description)| {
+                relationships.entry((source, target)).or_insert((description.to_string(), 0));
+            });
+        Ok(FindC4ModelResponseModel {
+            project_name: self.config_repository.read_project_name().await?,
+            containers: containers
+                .into_iter()
+                .map(|(name, component_ids)| C4ContainerReadModel {
+                    name,
+                    components: component_ids
+                        .iter()
+                        .filter_map(|component_id| components.get(component_id).cloned())
+                        .collect(),
+                })
+                .collect(),
+            relationships: relationships
+                .into_iter()
+                .map(|((source, target), (description, import_count))| C4RelationshipReadModel {
+                    source,
+                    target,
+                    description,
+                    import_count,
+                })
+                .collect(),
+        })
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<FindC4ModelRequestModel> for FindC4ModelUseCase<'a> {
+    async fn interact(&self, _request_model: FindC4ModelRequestModel) {
+        let result = self.try_interact().await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::{ collections::BTreeSet, error::Error };
+
+use async_trait::async_trait;
+use crate::{
+    cli::architecture::domain::{
+        entities::context_map::ContextMap,
+        enums::relationship_type::RelationshipType,
+        repositories::{
+            project_config_repository::ProjectConfigRepository,
+            source_file_repository::SourceFileRepository,
+        },
+        services::source_analyzer::SourceAnalyzer,
+        value_objects::module_dependency::{ ModuleDependency, ModuleDependencyValue },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::{ identity_object::IdentityObject, value_object::ValueObject },
+    },
+};
+
+pub struct FindContextMapRequestModel;
+
+pub struct ContextMapBoundedContextReadModel {
+    pub name: String,
+    pub aggregates: Vec<String>,
+}
+
+pub struct ContextLinkReadModel {
+    pub upstream: String,
+    pub downstream: String,
+    pub relationship_type: Option<RelationshipType>,
+    pub import_count: usize,
+}
+
+pub struct FindContextMapResponseModel {
+    pub bounded_contexts: Vec<ContextMapBoundedContextReadModel>,
+    pub links: Vec<ContextLinkReadModel>,
+    pub warnings: Vec<String>,
+}
+
+pub struct FindContextMapUseCase<'a> {
+    repository: &'a dyn SourceFileRepository,
+    config_repository: &'a dyn ProjectConfigRepository,
+    source_analyzer: &'a dyn SourceAnalyzer,
+    output_port: &'a dyn UseCaseOutputPort<FindContextMapResponseModel>,
+}
+
+impl<'a> FindContextMapUseCase<'a> {
+    pub fn new(
+        repository: &'a dyn SourceFileRepository,
+        config_repository: &'a dyn ProjectConfigRepository,
+        source_analyzer: &'a dyn SourceAnalyzer,
+        output_port: &'a dyn UseCaseOutputPort<FindContextMapResponseModel>
+    ) -> Self {
+        Self {
+            repository,
+            config_repository,
+            source_analyzer,
+            output_port,
+        }
+    }
+
+    async fn try_interact(
+        &self
+    ) -> Result<FindContextMapResponseModel, Box<dyn Error + Send + Sync>> {
+        let source_files = self.repository.read_source_files().await?;
+        let mut context_map = ContextMap::new(IdentityObject::new("context_map".to_string()));
+        source_files.iter().for_each(|source_file| {
+            let value = source_file.get_value();
+            context_map.add_module(&value.bounded_context_name, value.aggregate_name.as_deref());
+        });
+        let bounded_context_names = context_map
+            .get_bounded_contexts()
+            .keys()
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        source_files.iter().for_each(|source_file| {
+            let source_value = source_file.get_value();
+            self.source_analyzer
+                .find_imports(source_file)
+                .iter()
+                .for_each(|import| {
+                    let import_value = import.get_value();
+                    let Some(target_bounded_context) = import_value.path
+                        .first()
+                        .filter(|name| bounded_context_names.contains(*name)) else {
+                        return;
+                    };
+                    context_map.add_dependency(
+                        ModuleDependency::new(ModuleDependencyValue {
+                            source_module: source_value.bounded_context_name.clone(),
+                            target_module: target_bounded_context.clone(),
+                            file_path: source_value.path.clone(),
+                            line: import_value.line,
+                            statement: import_value.statement.clone(),
+                        })
+                    );
+                });
+        });
+        let mut warnings = vec![];
+        for relationship in self.config_repository.read_context_relationships().await? {
+            if let Err(error) = context_map.add_relationship(relationship) {
+                warnings.push(error.to_string());
+            }
+        }
+        Ok(FindContextMapResponseModel {
+            bounded_contexts: context_map
+                .get_bounded_contexts()
+                .iter()
+                .map(|(name, aggregates)| ContextMapBoundedContextReadModel {
+                    name: name.clone(),
+                    aggregates: aggregates.iter().cloned().collect(),
+                })
+                .collect(),
+            links: context_map
+                .get_links()
+                .iter()
+                .map(|link| {
+                    let value = link.get_value();
+                    ContextLinkReadModel {
+                        upstream: value.upstream.clone(),
+                        downstream: value.downstream.clone(),
+                        relationship_type: value.relationship_type.clone(),
+                        import_count: value.dependencies.len(),
+                    }
+                })
+                .collect(),
+            warnings,
+        })
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<FindContextMapRequestModel> for FindContextMapUseCase<'a> {
+    async fn interact(&self, _request_model: FindContextMapRequestModel) {
+        let result = self.try_interact().await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use crate::{
+    cli::architecture::domain::{
+        entities::event_catalog::{ EventCatalog, EventParticipant },
+        repositories::source_file_repository::SourceFileRepository,
+        services::source_analyzer::SourceAnalyzer,
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::{ identity_object::IdentityObject, value_object::ValueObject },
+    },
+};
+
+pub struct FindEventFlowRequestModel;
+
+#[derive(Clone)]
+pub struct EventEndpointReadModel {
+    pub name: String,
+    pub module_name: String,
+    pub path: String,
+    pub line: usize,
+}
+
+pub struct EventFlowReadModel {
+    pub event_name: String,
+    pub declaration: EventEndpointReadModel,
+    pub publishers: Vec<EventEndpointReadModel>,
+    pub subscribers: Vec<EventEndpointReadModel>,
+}
+
+pub struct UnmatchedSubscriberReadModel {
+    pub subscriber: EventEndpointReadModel,
+    pub subscribed_to: Option<String>,
+}
+
+pub struct FindEventFlowResponseModel {
+    pub events: Vec<EventFlowReadModel>,
+    pub unmatched_subscribers: Vec<UnmatchedSubscriberReadModel>,
+    pub unsubscribed_events: Vec<String>,
+}
+
+pub struct FindEventFlowUseCase<'a> {
+    repository: &'a dyn SourceFileRepository,
+    source_analyzer: &'a dyn SourceAnalyzer,
+    output_port: &'a dyn UseCaseOutputPort<FindEventFlowResponseModel>,
+}
+
+impl<'a> FindEventFlowUseCase<'a> {
+    pub fn new(
+        repository: &'a dyn SourceFileRepository,
+        source_analyzer: &'a dyn SourceAnalyzer,
+        output_port: &'a dyn UseCaseOutputPort<FindEventFlowResponseModel>
+    ) -> Self {
+        Self {
+            repository,
+            source_analyzer,
+            output_port,
+        }
+    }
+
+    fn to_read_model(participant: &EventParticipant) -> EventEndpointReadModel {
+        EventEndpointReadModel {
+            name: participant.name.clone(),
+            module_name: participant.module_name.clone(),
+            path: participant.path.clone(),
+            line: participant.line,
+        }
+    }
+
+    async fn try_interact(
+        &self
+    ) -> Result<FindEventFlowResponseModel, Box<dyn Error + Send + Sync>> {
+        let source_files = self.repository.read_source_files().await?;
+        let mut event_catalog = EventCatalog::new(IdentityObject::new("event_flow".to_string()));
+        event_catalog.add_source_files(&source_files, self.source_analyzer);
+        Ok(FindEventFlowResponseModel {
+            events: event_catalog
+                .get_events()
+                .iter()
+                .map(|event| EventFlowReadModel {
+                    event_name: event.event_name.clone(),
+                    declaration: Self::to_read_model(&event.declaration),
+                    publishers: event.publishers.iter().map(Self::to_read_model).collect(),
+                    subscribers: event.subscribers.iter().map(Self::to_read_model).collect(),
+                })
+                .collect(),
+            unmatched_subscribers: event_catalog
+                .get_unmatched_subscriptions()
+                .iter()
+                .map(|unmatched_subscription| UnmatchedSubscriberReadModel {
+                    subscriber: Self::to_read_model(&unmatched_subscription.subscriber),
+                    subscribed_to: unmatched_subscription.event_name.clone(),
+                })
+                .collect(),
+            unsubscribed_events: event_catalog
+                .get_unsubscribed_events()
+                .iter()
+                .map(|event| event.event_name.clone())
+                .collect(),
+        })
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<FindEventFlowRequestModel> for FindEventFlowUseCase<'a> {
+    async fn interact(&self, _request_model: FindEventFlowRequestModel) {
+        let result = self.try_interact().await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use crate::{
+    cli::{
+        architecture::domain::{
+            repositories::source_file_repository::SourceFileRepository,
+            services::source_analyzer::SourceAnalyzer,
+        },
+        bounded_context::domain::enums::{ component_type::ComponentType, layer_name::LayerName },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::value_object::ValueObject,
+    },
+};
+
+pub struct FindProjectStatusRequestModel;
+
+pub struct ComponentCountReadModel {
+    pub bounded_context_name: String,
+    pub aggregate_name: String,
+    pub layer_name: LayerName,
+    pub component_type: ComponentType,
+    pub count: usize,
+}
+
+pub struct ComponentStatusReadModel {
+    pub module_name: String,
+    pub component_type: ComponentType,
+    pub component_name: String,
+    pub path: String,
+}
+
+pub struct ComponentStubReadModel {
+    pub component: ComponentStatusReadModel,
+    pub is_empty: bool,
+    pub markers: Vec<String>,
+}
+
+pub struct FindProjectStatusResponseModel {
+    pub component_counts: Vec<ComponentCountReadModel>,
+    pub stubs: Vec<ComponentStubReadModel>,
+    pub untested_components: Vec<ComponentStatusReadModel>,
+}
+
+pub struct FindProjectStatusUseCase<'a> {
+    repository: &'a dyn SourceFileRepository,
+    source_analyzer: &'a dyn SourceAnalyzer,
+    output_port: &'a dyn UseCaseOutputPort<FindProjectStatusResponseModel>,
+}
+
+impl<'a> FindProjectStatusUseCase<'a> {
+    pub fn new(
+        repository: &'a dyn SourceFileRepository,
+        source_analyzer: &'a dyn SourceAnalyzer,
+        output_port: &'a dyn UseCaseOutputPort<FindProjectStatusResponseModel>
+    ) -> Self {
+        Self {
+            repository,
+            source_analyzer,
+            output_port,
+        }
+    }
+
+    async fn try_interact(
+        &self
+    ) -> Result<FindProjectStatusResponseModel, Box<dyn Error + Send + Sync>> {
+        let source_files = self.repository.read_source_files().await?;
+        let mut component_counts: Vec<ComponentCountReadModel> = vec![];
+        let mut stubs = vec![];
+        let mut untested_components = vec![];
+        source_files
+            .iter()
+            .filter(|source_file| source_file.get_component_name() != "mod")
+            .for_each(|source_file| {
+                let value = source_file.get_value();
+                let (Some(aggregate_name), Some(layer_name), Some(component_type)) = (
+                    &value.aggregate_name,
+                    &value.layer_name,
+                    &value.component_type,
+                ) else {
+                    return;
+                };
+                let component_count = component_counts.iter_mut().find(|component_count| {
+                    component_count.bounded_context_name == value.bounded_context_name &&
+                        &component_count.aggregate_name == aggregate_name &&
+                        &component_count.layer_name == layer_name &&
+                        &component_count.component_type == component_type
+                });
+                match component_count {
+                    Some(component_count) => {
+                        component_count.count += 1;
+                    }
+                    None => {
+                        component_counts.push(ComponentCountReadModel {
+                            bounded_context_name: value.bounded_context_name.clone(),
+                            aggregate_name: aggregate_name.clone(),
+                            layer_name: layer_name.clone(),
+                            component_type: component_type.clone(),
+                            count: 1,
+                        });
+                    }
+                }
+                let component = || ComponentStatusReadModel {
+                    module_name: source_file.get_module_name(),
+                    component_type: component_type.clone(),
+                    component_name: source_file.get_component_name(),
+                    path: value.path.clone(),
+                };
+                let is_empty = self.source_analyzer.is_blank(source_file);
+                let markers = self.source_analyzer
+                    .find_stub_markers(source_file)
+                    .iter()
+                    .map(|marker| {
+                        let value = marker.get_value();
+                        format!("{} (line {})", value.macro_name, value.line)
+                    })
+                    .collect::<Vec<_>>();
+                if is_empty || !markers.is_empty() {
+                    stubs.push(ComponentStubReadModel {
+                        component: component(),
+                        is_empty,
+                        markers,
+                    });
+                }
+                if !self.source_analyzer.has_tests(source_file) {
+                    untested_components.push(component());
+                }
+            });
+        component_counts.sort_by_key(|component_count| {
+            (
+                component_count.bounded_context_name.clone(),
+                component_count.aggregate_name.clone(),
+                LayerName::get_layer_names()
+                    .iter()
+                    .position(|layer_name| layer_name == &component_count.layer_name),
+                component_count.layer_name
+                    .get_component_types()
+                    .iter()
+                    .position(|component_type| component_type == &component_count.component_type),
+            )
+        });
+        Ok(FindProjectStatusResponseModel {
+            component_counts,
+            stubs,
+            untested_components,
+        })
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<FindProjectStatusRequestModel> for FindProjectStatusUseCase<'a> {
+    async fn interact(&self, _request_model: FindProjectStatusRequestModel) {
+        let result = self.try_interact().await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod check_architecture_use_case;
+pub mod find_aggregate_diagram_use_case;
+pub mod find_c4_model_use_case;
+pub mod find_context_map_use_case;
+pub mod find_event_flow_use_case;
+pub mod find_project_status_use_case;
+pub mod show_aggregate_use_case;
@@ -0,0 +1,272 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use crate::{
+    cli::{
+        architecture::domain::{
+            repositories::source_file_repository::SourceFileRepository,
+            services::source_analyzer::SourceAnalyzer,
+            value_objects::source_file::SourceFile,
+        },
+        bounded_context::domain::{
+            enums::{ component_type::ComponentType, layer_name::LayerName },
+            repositories::bounded_context_repository::BoundedContextRepository,
+        },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::{
+            entity::Entity,
+            identity_object::IdentityObject,
+            value_object::ValueObject,
+        },
+    },
+};
+
+pub struct ShowAggregateRequestModel {
+    pub bounded_context_name: String,
+    pub aggregate_name: String,
+}
+
+pub struct SourceItemReadModel {
+    pub kind: String,
+    pub name: String,
+    pub line: usize,
+}
+
+pub struct ComponentDetailsReadModel {
+    pub component_type: ComponentType,
+    pub component_name: String,
+    pub path: Option<String>,
+    pub items: Vec<SourceItemReadModel>,
+}
+
+pub struct AggregateLayerReadModel {
+    pub layer_name: LayerName,
+    pub components: Vec<ComponentDetailsReadModel>,
+}
+
+pub struct RaisedEventReadModel {
+    pub event_name: String,
+    pub path: String,
+    pub line: usize,
+}
+
+pub struct ComponentReferenceReadModel {
+    pub module_name: String,
+    pub component_type: ComponentType,
+    pub component_name: String,
+    pub path: String,
+}
+
+pub struct ShowAggregateResponseModel {
+    pub bounded_context_name: String,
+    pub aggregate_name: String,
+    pub layers: Vec<AggregateLayerReadModel>,
+    pub raised_events: Vec<RaisedEventReadModel>,
+    pub repositories: Vec<ComponentReferenceReadModel>,
+    pub use_cases: Vec<ComponentReferenceReadModel>,
+}
+
+pub struct ShowAggregateUseCase<'a> {
+    bounded_context_repository: &'a dyn BoundedContextRepository,
+    source_file_repository: &'a dyn SourceFileRepository,
+    source_analyzer: &'a dyn SourceAnalyzer,
+    output_port: &'a dyn UseCaseOutputPort<ShowAggregateResponseModel>,
+}
+
+impl<'a> ShowAggregateUseCase<'a> {
+    pub fn new(
+        bounded_context_repository: &'a dyn BoundedContextRepository,
+        source_file_repository: &'a dyn SourceFileRepository,
+        source_analyzer: &'a dyn SourceAnalyzer,
+        output_port: &'a dyn UseCaseOutputPort<ShowAggregateResponseModel>
+    ) -> Self {
+        Self {
+            bounded_context_repository,
+            source_file_repository,
+            source_analyzer,
+            output_port,
+        }
+    }
+
+    fn is_aggregate_file(
+        &self,
+        source_file: &SourceFile,
+        request_model: &ShowAggregateRequestModel
+    ) -> bool {
+        let value = source_file.get_value();
+        value.bounded_context_name == request_model.bounded_context_name &&
+            value.aggregate_name.as_ref() == Some(&request_model.aggregate_name)
+    }
+
+    fn is_touching_aggregate(
+        &self,
+        source_file: &SourceFile,
+        request_model: &ShowAggregateRequestModel
+    ) -> bool {
+        self.is_aggregate_file(source_file, request_model) ||
+            self.source_analyzer
+                .find_imports(source_file)
+                .iter()
+                .any(|import| {
+                    let path = &import.get_value().path;
+                    path.first() == Some(&request_model.bounded_context_name) &&
+                        path.get(1) == Some(&request_model.aggregate_name)
+                })
+    }
+
+    fn find_references(
+        &self,
+        source_files: &[SourceFile],
+        component_types: &[ComponentType],
+        request_model: &ShowAggregateRequestModel
+    ) -> Vec<ComponentReferenceReadModel> {
+        source_files
+            .iter()
+            .filter(|source_file| source_file.get_component_name() != "mod")
+            .filter_map(|source_file| {
+                let component_type = source_file
+                    .get_value()
+                    .component_type.clone()
+                    .filter(|component_type| component_types.contains(component_type))?;
+                if !self.is_touching_aggregate(source_file, request_model) {
+                    return None;
+                }
+                Some(ComponentReferenceReadModel {
+                    module_name: source_file.get_module_name(),
+                    component_type,
+                    component_name: source_file.get_component_name(),
+                    path: source_file.get_value().path.clone(),
+                })
+            })
+            .collect()
+    }
+
+    async fn try_interact(
+        &self,
+        request_model: &ShowAggregateRequestModel
+    ) -> Result<ShowAggregateResponseModel, Box<dyn Error + Send + Sync>> {
+        let bounded_context_id = IdentityObject::new(request_model.bounded_context_name.clone());
+        let bounded_context = self.bounded_context_repository
+            .read_bounded_context(&bounded_context_id).await?
+            .ok_or_else(|| format!("Bounded context <{}> not found", bounded_context_id))?;
+        let aggregate = bounded_context.aggregates
+            .iter()
+            .find(|aggregate| aggregate.get_id().get_value() == &request_model.aggregate_name)
+            .ok_or_else(|| format!("Aggregate <{}> not found", request_model.aggregate_name))?;
+        let source_files = self.source_file_repository.read_source_files().await?;
+        let aggregate_files = source_files
+            .iter()
+            .filter(|source_file| self.is_aggregate_file(source_file, request_model))
+            .collect::<Vec<_>>();
+        let layers = LayerName::get_layer_names()
+            .into_iter()
+            .filter_map(|layer_name| {
+                aggregate
+                    .get_layers()
+                    .iter()
+                    .find(|layer| layer.get_value().name == layer_name)
+            })
+            .map(|layer| AggregateLayerReadModel {
+                layer_name: layer.get_value().name.clone(),
+                components: layer
+                    .get_value()
+                    .components.iter()
+                    .map(|component| {
+                        let component_value = component.get_value();
+                        let source_file = aggregate_files.iter().find(|source_file| {
+                            let value = source_file.get_value();
+                            value.layer_name.as_ref() == Some(&layer.get_value().name) &&
+                                value.component_type.as_ref() ==
+                                    Some(&component_value.component_type) &&
+                                &source_file.get_component_name() ==
+                                    component_value.component_name.get_value()
+                        });
+                        ComponentDetailsReadModel {
+                            component_type: component_value.component_type.clone(),
+                            component_name: component_value.component_name.get_value().clone(),
+                            path: source_file.map(|source_file| {
+                                source_file.get_value().path.clone()
+                            }),
+                            items: source_file
+                                .map(|source_file| {
+                                    self.source_analyzer
+                                        .find_public_items(source_file)
+                                        .iter()
+                                        .map(|item| SourceItemReadModel {
+                                            kind: item.get_value().kind.to_string(),
+                                            name: item.get_value().name.clone(),
+                                            line: item.get_value().line,
+                                        })
+                                        .collect()
+                                })
+                                .unwrap_or_default(),
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+            })
+            .map(|mut layer| {
+                let component_types = layer.layer_name.get_component_types();
+                layer.components.sort_by_key(|component| {
+                    (
+                        component_types
+                            .iter()
+                            .position(|component_type| {
+                                component_type == &component.component_type
+                            }),
+                        component.component_name.clone(),
+                    )
+                });
+                layer
+            })
+            .collect();
+        let raised_events = aggregate_files
+            .iter()
+            .flat_map(|source_file| {
+                self.source_analyzer
+                    .find_raised_events(source_file)
+                    .into_iter()
+                    .map(|raised_event| RaisedEventReadModel {
+                        event_name: raised_event.get_value().event_name.clone(),
+                        path: source_file.get_value().path.clone(),
+                        line: raised_event.get_value().line,
+                    })
+            })
+            .collect();
+        Ok(ShowAggregateResponseModel {
+            bounded_context_name: request_model.bounded_context_name.clone(),
+            aggregate_name: request_model.aggregate_name.clone(),
+            layers,
+            raised_events,
+            repositories: self.find_references(
+                &source_files,
+                &[ComponentType::Repositories],
+                request_model
+            ),
+            use_cases: self.find_references(
+                &source_files,
+                &[ComponentType::Commands, ComponentType::Queries, ComponentType::Subscribers],
+                request_model
+            ),
+        })
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<ShowAggregateRequestModel> for ShowAggregateUseCase<'a> {
+    async fn interact(&self, request_model: ShowAggregateRequestModel) {
+        let result = self.try_interact(&request_model).await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::{ BTreeMap, BTreeSet };
+use thiserror::Error;
+use crate::{
+    cli::architecture::domain::value_objects::{
+        context_link::{ ContextLink, ContextLinkValue },
+        context_relationship::ContextRelationship,
+        module_dependency::ModuleDependency,
+    },
+    core::domain::models::{
+        entity::Entity,
+        identity_object::IdentityObject,
+        value_object::ValueObject,
+    },
+};
+
+#[derive(Error, Debug)]
+pub enum ContextMapError {
+    #[error(
+        "{relationship_type} relationship names unknown bounded context <{bounded_context_name}>"
+    )] UnknownBoundedContext {
+        relationship_type: String,
+        bounded_context_name: String,
+    },
+}
+
+pub struct ContextMap {
+    id: IdentityObject,
+    bounded_contexts: BTreeMap<String, BTreeSet<String>>,
+    relationships: Vec<ContextRelationship>,
+    dependencies: BTreeMap<(String, String), Vec<ModuleDependency>>,
+}
+
+impl ContextMap {
+    pub fn new(id: IdentityObject) -> Self {
+        Self {
+            id,
+            bounded_contexts: BTreeMap::new(),
+            relationships: vec![],
+            dependencies: BTreeMap::new(),
+        }
+    }
+
+    pub fn get_bounded_contexts(&self) -> &BTreeMap<String, BTreeSet<String>> {
+        &self.bounded_contexts
+    }
+
+    pub fn add_module(&mut self, bounded_context_name: &str, aggregate_name: Option<&str>) {
+        let aggregates = self.bounded_contexts.entry(bounded_context_name.to_string()).or_default();
+        if let Some(aggregate_name) = aggregate_name {
+            aggregates.insert(aggregate_name.to_string());
+        }
+    }
+
+    // Dependencies are read as "source depends on target", which makes the
+    // target the upstream bounded context of the link.
+    pub fn add_dependency(&mut self, dependency: ModuleDependency) {
+        let value = dependency.get_value();
+        if value.source_module == value.target_module {
+            return;
+        }
+        self.dependencies
+            .entry((value.target_module.clone(), value.source_module.clone()))
+            .or_default()
+            .push(dependency);
+    }
+
+    pub fn add_relationship(
+        &mut self,
+        relationship: ContextRelationship
+    ) -> Result<(), ContextMapError> {
+        let value = relationship.get_value();
+        for bounded_context_name in [&value.upstream, &value.downstream] {
+            if !self.bounded_contexts.contains_key(bounded_context_name) {
+                return Err(ContextMapError::UnknownBoundedContext {
+                    relationship_type: value.relationship_type.get_label().to_string(),
+                    bounded_context_name: bounded_context_name.clone(),
+                });
+            }
+        }
+        if !self.relationships.iter().any(|existing| existing.is_equal(&relationship)) {
+            self.relationships.push(relationship);
+        }
+        Ok(())
+    }
+
+    // Declared relationships come first and absorb the dependencies observed
+    // in either direction when symmetric; undeclared dependencies follow.
+    pub fn get_links(&self) -> Vec<ContextLink> {
+        let mut links = self.relationships
+            .iter()
+            .map(|relationship| {
+                let value = relationship.get_value();
+                ContextLink::new(ContextLinkValue {
+                    upstream: value.upstream.clone(),
+                    downstream: value.downstream.clone(),
+                    relationship_type: Some(value.relationship_type.clone()),
+                    dependencies: self.dependencies
+                        .iter()
+                        .filter(|((upstream, downstream), _)| {
+                            relationship.connects(upstream, downstream)
+                        })
+                        .flat_map(|(_, dependencies)| dependencies.clone())
+                        .collect(),
+                })
+            })
+            .collect::<Vec<_>>();
+        self.dependencies
+            .iter()
+            .filter(|((upstream, downstream), _)| {
+                !self.relationships
+                    .iter()
+                    .any(|relationship| relationship.connects(upstream, downstream))
+            })
+            .for_each(|((upstream, downstream), dependencies)| {
+                links.push(
+                    ContextLink::new(ContextLinkValue {
+                        upstream: upstream.clone(),
+                        downstream: downstream.clone(),
+                        relationship_type: None,
+                        dependencies: dependencies.clone(),
+                    })
+                );
+            });
+        links
+    }
+}
+
+impl Entity<IdentityObject> for ContextMap {
+    fn get_id(&self) -> &IdentityObject {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::architecture::domain::{
+            entities::context_map::ContextMap,
+            enums::relationship_type::RelationshipType,
+            value_objects::{
+                context_relationship::{ ContextRelationship, ContextRelationshipValue },
+                module_dependency::{ ModuleDependency, ModuleDependencyValue },
+            },
+        },
+        core::domain::models::{ identity_object::IdentityObject, value_object::ValueObject },
+    };
+
+    fn dependency(source_module: &str, target_module: &str) -> ModuleDependency {
+        ModuleDependency::new(ModuleDependencyValue {
+            source_module: source_module.to_string(),
+            target_module: target_module.to_string(),
+            file_path: format!("src/{}/mod.rs", source_module),
+            line: 1,
+            statement: format!("use crate::{};", target_module),
+        })
+    }
+
+    fn relationship(
+        upstream: &str,
+        downstream: &str,
+        relationship_type: RelationshipType
+    ) -> ContextRelationship {
+        ContextRelationship::new(ContextRelationshipValue {
+            upstream: upstream.to_string(),
+            downstream: downstream.to_string(),
+            relationship_type,
+        })
+    }
+
+    fn context_map() -> ContextMap {
+        let mut context_map = ContextMap::new(IdentityObject::new("context_map".to_string()));
+        context_map.add_module("billing", Some("invoice"));
+        context_map.add_module("sales", Some("order"));
+        context_map.add_module("shipping", None);
+        context_map
+    }
+
+    #[test]
+    fn should_label_observed_dependencies_with_declared_relationships() {
+        let mut context_map = context_map();
+        context_map.add_dependency(dependency("billing", "sales"));
+        context_map.add_dependency(dependency("billing", "sales"));
+        context_map.add_dependency(dependency("shipping", "sales"));
+        context_map.add_dependency(dependency("sales", "shipping"));
+        context_map
+            .add_relationship(
+                relationship("sales", "billing", RelationshipType::AnticorruptionLayer)
+            )
+            .unwrap();
+        context_map
+            .add_relationship(relationship("sales", "shipping", RelationshipType::SharedKernel))
+            .unwrap();
+        let links = context_map
+            .get_links()
+            .iter()
+            .map(|link| {
+                let value = link.get_value();
+                (
+                    format!("{} -> {}", value.downstream, value.upstream),
+                    value.relationship_type.clone(),
+                    value.dependencies.len(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(links, vec![
+            ("billing -> sales".to_string(), Some(RelationshipType::AnticorruptionLayer), 2),
+            ("shipping -> sales".to_string(), Some(RelationshipType::SharedKernel), 2)
+        ]);
+    }
+
+    #[test]
+    fn should_reject_relationships_with_unknown_bounded_contexts() {
+        let mut context_map = context_map();
+        let result = context_map.add_relationship(
+            relationship("sales", "marketing", RelationshipType::Conformist)
+        );
+        assert!(result.is_err());
+        assert!(context_map.get_links().is_empty());
+    }
+}
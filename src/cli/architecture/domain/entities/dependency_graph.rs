@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use crate::{
+    cli::architecture::domain::value_objects::{
+        dependency_cycle::DependencyCycle,
+        module_dependency::ModuleDependency,
+    },
+    core::domain::models::{
+        entity::Entity,
+        identity_object::IdentityObject,
+        value_object::ValueObject,
+    },
+};
+
+pub struct DependencyGraph {
+    id: IdentityObject,
+    dependencies: BTreeMap<String, BTreeMap<String, ModuleDependency>>,
+}
+
+impl DependencyGraph {
+    pub fn new(id: IdentityObject) -> Self {
+        Self {
+            id,
+            dependencies: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_dependency(&mut self, dependency: ModuleDependency) {
+        let value = dependency.get_value();
+        if value.source_module == value.target_module {
+            return;
+        }
+        self.dependencies
+            .entry(value.source_module.clone())
+            .or_default()
+            .entry(value.target_module.clone())
+            .or_insert(dependency);
+    }
+
+    pub fn find_cycles(&self) -> Vec<DependencyCycle> {
+        let mut cycles = vec![];
+        for start_module in self.dependencies.keys() {
+            let mut chain = vec![];
+            self.collect_cycles(start_module, start_module, &mut chain, &mut cycles);
+        }
+        cycles
+    }
+
+    // Every elementary cycle is reported once, rooted at its lowest module name.
+    fn collect_cycles(
+        &self,
+        start_module: &String,
+        current_module: &String,
+        chain: &mut Vec<ModuleDependency>,
+        cycles: &mut Vec<DependencyCycle>
+    ) {
+        let Some(targets) = self.dependencies.get(current_module) else {
+            return;
+        };
+        for (target_module, dependency) in targets {
+            if target_module == start_module {
+                let mut cycle = chain.clone();
+                cycle.push(dependency.clone());
+                cycles.push(DependencyCycle::new(cycle));
+            } else if
+                target_module > start_module &&
+                !chain.iter().any(|link| &link.get_value().source_module == target_module)
+            {
+                chain.push(dependency.clone());
+                self.collect_cycles(start_module, target_module, chain, cycles);
+                chain.pop();
+            }
+        }
+    }
+}
+
+impl Entity<IdentityObject> for DependencyGraph {
+    fn get_id(&self) -> &IdentityObject {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::architecture::domain::{
+            entities::dependency_graph::DependencyGraph,
+            value_objects::module_dependency::{ ModuleDependency, ModuleDependencyValue },
+        },
+        core::domain::models::{ identity_object::IdentityObject, value_object::ValueObject },
+    };
+
+    fn dependency(source_module: &str, target_module: &str) -> ModuleDependency {
+        ModuleDependency::new(ModuleDependencyValue {
+            source_module: source_module.to_string(),
+            target_module: target_module.to_string(),
+            file_path: format!("src/{}/mod.rs", source_module),
+            line: 1,
+            statement: format!("use crate::{};", target_module.replace('/', "::")),
+        })
+    }
+
+    #[test]
+    fn should_find_every_elementary_cycle_once() {
+        let mut graph = DependencyGraph::new(IdentityObject::new("aggregates".to_string()));
+        graph.add_dependency(dependency("sales/order", "sales/customer"));
+        graph.add_dependency(dependency("sales/customer", "sales/order"));
+        graph.add_dependency(dependency("sales/customer", "billing/invoice"));
+        graph.add_dependency(dependency("billing/invoice", "sales/order"));
+        graph.add_dependency(dependency("billing/invoice", "billing/invoice"));
+        let cycles = graph
+            .find_cycles()
+            .iter()
+            .map(|cycle| cycle.get_modules().join(" -> "))
+            .collect::<Vec<_>>();
+        assert_eq!(cycles, vec![
+            "billing/invoice -> sales/order -> sales/customer -> billing/invoice".to_string(),
+            "sales/customer -> sales/order -> sales/customer".to_string()
+        ]);
+    }
+
+    #[test]
+    fn should_find_no_cycles_in_layered_dependencies() {
+        let mut graph = DependencyGraph::new(IdentityObject::new("aggregates".to_string()));
+        graph.add_dependency(dependency("sales/order", "sales/customer"));
+        graph.add_dependency(dependency("sales/customer", "billing/invoice"));
+        assert!(graph.find_cycles().is_empty());
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{
+    cli::architecture::domain::{
+        services::source_analyzer::SourceAnalyzer,
+        value_objects::{
+            domain_event_declaration::DomainEventDeclaration,
+            event_subscription::EventSubscription,
+            raised_event::RaisedEvent,
+            source_file::SourceFile,
+        },
+    },
+    core::domain::models::{
+        entity::Entity,
+        identity_object::IdentityObject,
+        value_object::ValueObject,
+    },
+};
+
+#[derive(Clone)]
+pub struct EventParticipant {
+    pub name: String,
+    pub module_name: String,
+    pub path: String,
+    pub line: usize,
+}
+
+impl EventParticipant {
+    fn new(source_file: &SourceFile, name: String, line: usize) -> Self {
+        Self {
+            name,
+            module_name: source_file.get_module_name(),
+            path: source_file.get_value().path.clone(),
+            line,
+        }
+    }
+}
+
+pub struct CatalogedEvent {
+    pub event_name: String,
+    pub declaration: EventParticipant,
+    pub publishers: Vec<EventParticipant>,
+    pub subscribers: Vec<EventParticipant>,
+}
+
+pub struct UnmatchedSubscription {
+    pub subscriber: EventParticipant,
+    pub event_name: Option<String>,
+}
+
+// Declarations have to be added before the raised events and subscriptions
+// referring to them, which are matched on the event type and name.
+pub struct EventCatalog {
+    id: IdentityObject,
+    events: Vec<CatalogedEvent>,
+    unmatched_subscriptions: Vec<UnmatchedSubscription>,
+}
+
+impl EventCatalog {
+    pub fn new(id: IdentityObject) -> Self {
+        Self {
+            id,
+            events: vec![],
+            unmatched_subscriptions: vec![],
+        }
+    }
+
+    pub fn get_events(&self) -> &Vec<CatalogedEvent> {
+        &self.events
+    }
+
+    pub fn get_unmatched_subscriptions(&self) -> &Vec<UnmatchedSubscription> {
+        &self.unmatched_subscriptions
+    }
+
+    pub fn get_unsubscribed_events(&self) -> Vec<&CatalogedEvent> {
+        self.events
+            .iter()
+            .filter(|event| event.subscribers.is_empty())
+            .collect()
+    }
+
+    // Events are looked up by their declared type or their name, optionally
+    // narrowed to the `<bounded_context>/<aggregate>` declaring them.
+    pub fn find_events(&self, event: &str, module_name: Option<&str>) -> Vec<&CatalogedEvent> {
+        self.events
+            .iter()
+            .filter(|cataloged_event| {
+                cataloged_event.declaration.name == event || cataloged_event.event_name == event
+            })
+            .filter(|cataloged_event| {
+                match module_name {
+                    Some(module_name) => cataloged_event.declaration.module_name == module_name,
+                    None => true,
+                }
+            })
+            .collect()
+    }
+
+    pub fn add_source_files(
+        &mut self,
+        source_files: &[SourceFile],
+        source_analyzer: &dyn SourceAnalyzer
+    ) {
+        for source_file in source_files {
+            for declaration in source_analyzer.find_domain_events(source_file) {
+                self.add_declaration(source_file, &declaration);
+            }
+        }
+        for source_file in source_files {
+            for raised_event in source_analyzer.find_raised_events(source_file) {
+                self.add_raised_event(source_file, &raised_event);
+            }
+            for subscription in source_analyzer.find_event_subscriptions(source_file) {
+                self.add_subscription(source_file, &subscription);
+            }
+        }
+    }
+
+    pub fn add_declaration(
+        &mut self,
+        source_file: &SourceFile,
+        declaration: &DomainEventDeclaration
+    ) {
+        let value = declaration.get_value();
+        let position = self.events
+            .iter()
+            .position(|event| event.event_name > value.event_name)
+            .unwrap_or(self.events.len());
+        self.events.insert(position, CatalogedEvent {
+            event_name: value.event_name.clone(),
+            declaration: EventParticipant::new(source_file, value.type_name.clone(), value.line),
+            publishers: vec![],
+            subscribers: vec![],
+        });
+    }
+
+    pub fn add_raised_event(&mut self, source_file: &SourceFile, raised_event: &RaisedEvent) {
+        let value = raised_event.get_value();
+        let event = self.events
+            .iter_mut()
+            .find(|event| event.declaration.name == value.event_name);
+        if let Some(event) = event {
+            event.publishers.push(
+                EventParticipant::new(source_file, source_file.get_component_name(), value.line)
+            );
+        }
+    }
+
+    pub fn add_subscription(&mut self, source_file: &SourceFile, subscription: &EventSubscription) {
+        let value = subscription.get_value();
+        let subscriber = EventParticipant::new(
+            source_file,
+            value.subscriber_name.clone(),
+            value.line
+        );
+        let event_name = subscription.resolve_event_name(|type_name| {
+            self.events
+                .iter()
+                .find(|event| event.declaration.name == type_name)
+                .map(|event| event.event_name.clone())
+        });
+        let event = self.events
+            .iter_mut()
+            .find(|event| Some(&event.event_name) == event_name.as_ref());
+        match event {
+            Some(event) => event.subscribers.push(subscriber),
+            None => {
+                self.unmatched_subscriptions.push(UnmatchedSubscription {
+                    subscriber,
+                    event_name,
+                });
+            }
+        }
+    }
+}
+
+impl Entity<IdentityObject> for EventCatalog {
+    fn get_id(&self) -> &IdentityObject {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::architecture::domain::{
+            entities::event_catalog::EventCatalog,
+            value_objects::{
+                domain_event_declaration::{
+                    DomainEventDeclaration,
+                    DomainEventDeclarationValue,
+                },
+                event_subscription::{ EventSubscription, EventSubscriptionValue },
+                raised_event::{ RaisedEvent, RaisedEventValue },
+                source_file::{ SourceFile, SourceFileValue },
+            },
+        },
+        core::domain::models::{ identity_object::IdentityObject, value_object::ValueObject },
+    };
+
+    fn source_file(bounded_context_name: &str, aggregate_name: &str) -> SourceFile {
+        SourceFile::new(SourceFileValue {
+            path: format!("src/{}/{}/mod.rs", bounded_context_name, aggregate_name),
+            bounded_context_name: bounded_context_name.to_string(),
+            aggregate_name: Some(aggregate_name.to_string()),
+            layer_name: None,
+            component_type: None,
+            content: String::new(),
+        })
+    }
+
+    fn subscription(subscriber_name: &str, event_name: Option<&str>) -> EventSubscription {
+        EventSubscription::new(EventSubscriptionValue {
+            subscriber_name: subscriber_name.to_string(),
+            event_name: event_name.map(|event_name| event_name.to_string()),
+            referenced_types: vec!["OrderPlaced".to_string()],
+            line: 1,
+        })
+    }
+
+    #[test]
+    fn should_match_publishers_and_subscribers_to_declared_events() {
+        let order_file = source_file("sales", "order");
+        let invoice_file = source_file("billing", "invoice");
+        let mut event_catalog = EventCatalog::new(IdentityObject::new("events".to_string()));
+        for (type_name, event_name) in [
+            ("OrderShipped", "sales.order_shipped"),
+            ("OrderPlaced", "sales.order_placed"),
+        ] {
+            event_catalog.add_declaration(
+                &order_file,
+                &DomainEventDeclaration::new(DomainEventDeclarationValue {
+                    type_name: type_name.to_string(),
+                    event_name: event_name.to_string(),
+                    line: 1,
+                })
+            );
+        }
+        event_catalog.add_raised_event(
+            &order_file,
+            &RaisedEvent::new(RaisedEventValue { event_name: "OrderPlaced".to_string(), line: 9 })
+        );
+        event_catalog.add_subscription(&invoice_file, &subscription("CreateInvoice", None));
+        event_catalog.add_subscription(
+            &invoice_file,
+            &subscription("Refund", Some("billing.refunded"))
+        );
+        let events = event_catalog
+            .get_events()
+            .iter()
+            .map(|event| {
+                let publishers = event.publishers
+                    .iter()
+                    .map(|publisher| publisher.module_name.as_str())
+                    .collect::<Vec<_>>();
+                let subscribers = event.subscribers
+                    .iter()
+                    .map(|subscriber| subscriber.name.as_str())
+                    .collect::<Vec<_>>();
+                format!("{} {:?} {:?}", event.event_name, publishers, subscribers)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(events, vec![
+            "sales.order_placed [\"sales/order\"] [\"CreateInvoice\"]",
+            "sales.order_shipped [] []"
+        ]);
+        assert_eq!(event_catalog.find_events("OrderPlaced", Some("sales/order")).len(), 1);
+        assert_eq!(event_catalog.find_events("sales.order_shipped", None).len(), 1);
+        assert!(event_catalog.find_events("OrderPlaced", Some("billing/invoice")).is_empty());
+        let unmatched_subscriptions = event_catalog
+            .get_unmatched_subscriptions()
+            .iter()
+            .map(|unmatched| {
+                (unmatched.subscriber.name.as_str(), unmatched.event_name.as_deref())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(unmatched_subscriptions, vec![("Refund", Some("billing.refunded"))]);
+        let unsubscribed_events = event_catalog
+            .get_unsubscribed_events()
+            .iter()
+            .map(|event| event.event_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(unsubscribed_events, vec!["sales.order_shipped"]);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod context_map;
+pub mod dependency_graph;
+pub mod event_catalog;
+pub mod project_structure;
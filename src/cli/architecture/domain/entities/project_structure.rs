@@ -0,0 +1,400 @@
+use std::str::FromStr;
+use crate::{
+    cli::{
+        architecture::domain::{
+            enums::structural_issue_kind::StructuralIssueKind,
+            value_objects::{
+                module_directory::{ ModuleDirectory, ModuleSubdirectory },
+                structural_issue::{ StructuralIssue, StructuralIssueValue },
+            },
+        },
+        bounded_context::domain::enums::{ component_type::ComponentType, layer_name::LayerName },
+    },
+    core::domain::models::{
+        entity::Entity,
+        identity_object::IdentityObject,
+        value_object::ValueObject,
+    },
+};
+
+pub struct ProjectStructure {
+    id: IdentityObject,
+    directories: Vec<ModuleDirectory>,
+}
+
+impl ProjectStructure {
+    pub fn new(id: IdentityObject, directories: Vec<ModuleDirectory>) -> Self {
+        Self { id, directories }
+    }
+
+    pub fn diagnose(&self) -> Vec<StructuralIssue> {
+        let mut issues = vec![];
+        self.directories
+            .iter()
+            .filter(|directory| self.is_diagnosable(&directory.get_value().path))
+            .for_each(|directory| {
+                issues.extend(self.diagnose_declarations(directory));
+                let path = &directory.get_value().path;
+                match path.len() {
+                    2 => {
+                        directory.get_value().child_directories
+                            .iter()
+                            .map(|child_directory| &child_directory.name)
+                            .filter(|name| LayerName::from_str(name).is_err())
+                            .for_each(|name| {
+                                issues.push(
+                                    self.create_issue(
+                                        StructuralIssueKind::UnknownLayerDirectory,
+                                        &self.get_path(directory, Some(name)),
+                                        format!(
+                                            "<{}> is not an aggregate layer (expected domain, application, adapters or infrastructure)",
+                                            name
+                                        )
+                                    )
+                                )
+                            });
+                    }
+                    3 => {
+                        let layer_name = LayerName::from_str(&path[2]).unwrap();
+                        let component_types = layer_name.get_component_types();
+                        directory.get_value().child_directories.iter().for_each(|child_directory| {
+                            let name = &child_directory.name;
+                            match ComponentType::from_str(name) {
+                                Err(_) => {
+                                    issues.push(
+                                        self.create_issue(
+                                            StructuralIssueKind::UnknownComponentDirectory,
+                                            &self.get_path(directory, Some(name)),
+                                            format!("<{}> is not a component type", name)
+                                        )
+                                    );
+                                }
+                                Ok(component_type) if !component_types.contains(&component_type) => {
+                                    issues.push(
+                                        self.create_issue(
+                                            StructuralIssueKind::MisplacedComponentDirectory,
+                                            &self.get_path(directory, Some(name)),
+                                            format!(
+                                                "<{}> do not belong to the {} layer (allowed: {})",
+                                                name,
+                                                layer_name,
+                                                component_types
+                                                    .iter()
+                                                    .map(|c| c.to_string())
+                                                    .collect::<Vec<_>>()
+                                                    .join(", ")
+                                            )
+                                        )
+                                    );
+                                }
+                                Ok(_) => {}
+                            }
+                        });
+                    }
+                    4 => {
+                        directory.get_value().child_files
+                            .iter()
+                            .filter(|file| file.is_empty)
+                            .for_each(|file| {
+                                issues.push(
+                                    self.create_issue(
+                                        StructuralIssueKind::EmptyComponentFile,
+                                        &format!("{}.rs", self.get_path(directory, Some(&file.name))),
+                                        format!("Component <{}> has an empty file", file.name)
+                                    )
+                                );
+                            });
+                    }
+                    _ => {}
+                }
+            });
+        issues
+    }
+
+    pub fn repair_module_declarations(&mut self) -> Vec<ModuleDirectory> {
+        let repairs = self.directories
+            .iter()
+            .filter(|directory| {
+                self.is_diagnosable(&directory.get_value().path) &&
+                    !self.diagnose_declarations(directory).is_empty()
+            })
+            .map(|directory| {
+                (directory.get_value().path.clone(), self.get_declarable_modules(directory))
+            })
+            .collect::<Vec<_>>();
+        self.directories
+            .iter_mut()
+            .filter_map(|directory| {
+                let (_, declarable_modules) = repairs
+                    .iter()
+                    .find(|(path, _)| *path == directory.get_value().path)?;
+                directory.declare_modules(declarable_modules);
+                Some(directory.clone())
+            })
+            .collect()
+    }
+
+    fn diagnose_declarations(&self, directory: &ModuleDirectory) -> Vec<StructuralIssue> {
+        let index_path = format!("{}/mod.rs", self.get_path(directory, None));
+        let declarable_modules = self.get_declarable_modules(directory);
+        if directory.get_value().declared_modules.is_none() {
+            if declarable_modules.is_empty() {
+                return vec![];
+            }
+            return vec![
+                self.create_issue(
+                    StructuralIssueKind::MissingModuleIndex,
+                    &index_path,
+                    format!("Directory <{}> has no mod.rs", self.get_path(directory, None))
+                )
+            ];
+        }
+        let mut issues = directory
+            .get_undeclared_modules(&declarable_modules)
+            .iter()
+            .map(|module| {
+                self.create_issue(
+                    StructuralIssueKind::UndeclaredModule,
+                    &index_path,
+                    format!("Module <{}> is not declared", module)
+                )
+            })
+            .collect::<Vec<_>>();
+        issues.extend(
+            directory
+                .get_dangling_modules()
+                .iter()
+                .map(|module| {
+                    self.create_issue(
+                        StructuralIssueKind::DanglingModuleDeclaration,
+                        &index_path,
+                        format!("Module <{}> is declared but has no file or directory", module)
+                    )
+                })
+        );
+        issues
+    }
+
+    fn get_declarable_modules(&self, directory: &ModuleDirectory) -> Vec<String> {
+        let mut modules = directory.get_value().child_directories
+            .iter()
+            .filter(|child_directory| self.is_declarable_directory(directory, child_directory))
+            .map(|child_directory| child_directory.name.clone())
+            .collect::<Vec<_>>();
+        modules.extend(directory.get_value().child_files.iter().map(|file| file.name.clone()));
+        modules.sort();
+        modules
+    }
+
+    /// Unknown, misplaced and empty directories are only reported, a directory is declared
+    /// once it has a mod.rs or is about to get one from the repair.
+    fn is_declarable_directory(
+        &self,
+        directory: &ModuleDirectory,
+        child_directory: &ModuleSubdirectory
+    ) -> bool {
+        let mut path = directory.get_value().path.clone();
+        path.push(child_directory.name.clone());
+        if !self.is_diagnosable(&path) {
+            return false;
+        }
+        child_directory.has_index ||
+            self.directories
+                .iter()
+                .find(|module_directory| module_directory.get_value().path == path)
+                .is_some_and(|module_directory| {
+                    !self.get_declarable_modules(module_directory).is_empty()
+                })
+    }
+
+    fn is_diagnosable(&self, path: &[String]) -> bool {
+        let Some(layer_name) = path.get(2) else {
+            return true;
+        };
+        let Ok(layer_name) = LayerName::from_str(layer_name) else {
+            return false;
+        };
+        match path.get(3).map(|name| ComponentType::from_str(name)) {
+            None => true,
+            Some(Ok(component_type)) => layer_name.get_component_types().contains(&component_type),
+            Some(Err(_)) => false,
+        }
+    }
+
+    fn get_path(&self, directory: &ModuleDirectory, child_name: Option<&String>) -> String {
+        let mut segments = vec![self.id.get_value().clone()];
+        segments.extend(directory.get_value().path.clone());
+        segments.extend(child_name.cloned());
+        segments.join("/")
+    }
+
+    fn create_issue(
+        &self,
+        kind: StructuralIssueKind,
+        path: &str,
+        description: String
+    ) -> StructuralIssue {
+        StructuralIssue::new(StructuralIssueValue {
+            kind,
+            path: path.to_string(),
+            description,
+        })
+    }
+}
+
+impl Entity<IdentityObject> for ProjectStructure {
+    fn get_id(&self) -> &IdentityObject {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::architecture::domain::{
+            entities::project_structure::ProjectStructure,
+            value_objects::module_directory::{
+                ModuleDirectory,
+                ModuleDirectoryValue,
+                ModuleFile,
+                ModuleSubdirectory,
+            },
+        },
+        core::domain::models::{ identity_object::IdentityObject, value_object::ValueObject },
+    };
+
+    fn directory(
+        path: &str,
+        declared_modules: Option<Vec<&str>>,
+        child_directories: Vec<(&str, bool)>,
+        child_files: Vec<(&str, bool)>
+    ) -> ModuleDirectory {
+        ModuleDirectory::new(ModuleDirectoryValue {
+            path: path
+                .split('/')
+                .map(|s| s.to_string())
+                .collect(),
+            declared_modules: declared_modules.map(|modules| {
+                modules
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect()
+            }),
+            child_directories: child_directories
+                .iter()
+                .map(|(name, has_index)| ModuleSubdirectory {
+                    name: name.to_string(),
+                    has_index: *has_index,
+                })
+                .collect(),
+            child_files: child_files
+                .iter()
+                .map(|(name, is_empty)| ModuleFile { name: name.to_string(), is_empty: *is_empty })
+                .collect(),
+        })
+    }
+
+    fn create_project_structure() -> ProjectStructure {
+        ProjectStructure::new(IdentityObject::new("src".to_string()), vec![
+            directory("sales", Some(vec!["order"]), vec![("order", true)], vec![]),
+            directory(
+                "sales/order",
+                Some(vec!["domain", "adapters"]),
+                vec![("domain", true), ("ui", false)],
+                vec![]
+            ),
+            directory(
+                "sales/order/domain",
+                Some(vec!["entities"]),
+                vec![
+                    ("entities", true),
+                    ("events", false),
+                    ("helpers", true),
+                    ("presenters", false),
+                    ("value_objects", false)
+                ],
+                vec![]
+            ),
+            directory(
+                "sales/order/domain/entities",
+                Some(vec!["order", "line"]),
+                vec![],
+                vec![("order", false), ("total", true)]
+            ),
+            directory("sales/order/domain/events", None, vec![], vec![]),
+            directory("sales/order/domain/helpers", Some(vec![]), vec![], vec![("format", false)]),
+            directory("sales/order/domain/presenters", None, vec![], vec![("order_presenter", false)]),
+            directory("sales/order/domain/value_objects", None, vec![], vec![("price", false)]),
+            directory("sales/order/ui", None, vec![], vec![("widget", false)])
+        ])
+    }
+
+    #[test]
+    fn should_diagnose_structural_drift() {
+        let issues = create_project_structure()
+            .diagnose()
+            .iter()
+            .map(|issue| format!("{} {}", issue.get_value().kind, issue.get_value().path))
+            .collect::<Vec<_>>();
+        assert_eq!(issues, vec![
+            "dangling-module-declaration src/sales/order/mod.rs".to_string(),
+            "unknown-layer-directory src/sales/order/ui".to_string(),
+            "undeclared-module src/sales/order/domain/mod.rs".to_string(),
+            "unknown-component-directory src/sales/order/domain/helpers".to_string(),
+            "misplaced-component-directory src/sales/order/domain/presenters".to_string(),
+            "undeclared-module src/sales/order/domain/entities/mod.rs".to_string(),
+            "dangling-module-declaration src/sales/order/domain/entities/mod.rs".to_string(),
+            "empty-component-file src/sales/order/domain/entities/total.rs".to_string(),
+            "missing-module-index src/sales/order/domain/value_objects/mod.rs".to_string()
+        ]);
+    }
+
+    #[test]
+    fn should_repair_module_declarations_keeping_declaration_order() {
+        let mut project_structure = create_project_structure();
+        let repaired_directories = project_structure.repair_module_declarations();
+        let declarations = repaired_directories
+            .iter()
+            .map(|directory| {
+                format!(
+                    "{}: {}",
+                    directory.get_value().path.join("/"),
+                    directory.get_value().declared_modules.clone().unwrap().join(",")
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(declarations, vec![
+            "sales/order: domain",
+            "sales/order/domain: entities,value_objects",
+            "sales/order/domain/entities: order,total",
+            "sales/order/domain/value_objects: price"
+        ]);
+        assert!(
+            project_structure
+                .diagnose()
+                .iter()
+                .all(|issue| !issue.get_value().kind.is_fixable())
+        );
+    }
+
+    #[test]
+    fn should_not_declare_unknown_misplaced_or_empty_directories() {
+        let mut project_structure = create_project_structure();
+        project_structure.repair_module_declarations();
+        let declared_modules = project_structure.directories
+            .iter()
+            .flat_map(|directory| {
+                directory.get_value().declared_modules.clone().unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        ["ui", "helpers", "presenters", "events"].iter().for_each(|module| {
+            assert!(!declared_modules.contains(&module.to_string()), "{} was declared", module);
+        });
+        assert!(
+            project_structure
+                .diagnose()
+                .iter()
+                .any(|issue| issue.get_value().path == "src/sales/order/ui")
+        );
+    }
+}
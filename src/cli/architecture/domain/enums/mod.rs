@@ -0,0 +1,3 @@
+pub mod relationship_type;
+pub mod source_item_kind;
+pub mod structural_issue_kind;
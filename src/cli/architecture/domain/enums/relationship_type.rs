@@ -0,0 +1,67 @@
+use std::fmt;
+use std::str::FromStr;
+use anyhow::Error;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RelationshipType {
+    UpstreamDownstream,
+    CustomerSupplier,
+    Conformist,
+    AnticorruptionLayer,
+    OpenHostService,
+    PublishedLanguage,
+    SharedKernel,
+    Partnership,
+}
+
+impl RelationshipType {
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            RelationshipType::UpstreamDownstream => "Upstream/Downstream",
+            RelationshipType::CustomerSupplier => "Customer/Supplier",
+            RelationshipType::Conformist => "Conformist",
+            RelationshipType::AnticorruptionLayer => "Anticorruption Layer",
+            RelationshipType::OpenHostService => "Open Host Service",
+            RelationshipType::PublishedLanguage => "Published Language",
+            RelationshipType::SharedKernel => "Shared Kernel",
+            RelationshipType::Partnership => "Partnership",
+        }
+    }
+
+    pub fn is_symmetric(&self) -> bool {
+        matches!(self, RelationshipType::SharedKernel | RelationshipType::Partnership)
+    }
+}
+
+impl fmt::Display for RelationshipType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RelationshipType::UpstreamDownstream => write!(f, "upstream-downstream"),
+            RelationshipType::CustomerSupplier => write!(f, "customer-supplier"),
+            RelationshipType::Conformist => write!(f, "conformist"),
+            RelationshipType::AnticorruptionLayer => write!(f, "anticorruption-layer"),
+            RelationshipType::OpenHostService => write!(f, "open-host-service"),
+            RelationshipType::PublishedLanguage => write!(f, "published-language"),
+            RelationshipType::SharedKernel => write!(f, "shared-kernel"),
+            RelationshipType::Partnership => write!(f, "partnership"),
+        }
+    }
+}
+
+impl FromStr for RelationshipType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "upstream-downstream" => Ok(RelationshipType::UpstreamDownstream),
+            "customer-supplier" => Ok(RelationshipType::CustomerSupplier),
+            "conformist" => Ok(RelationshipType::Conformist),
+            "anticorruption-layer" | "acl" => Ok(RelationshipType::AnticorruptionLayer),
+            "open-host-service" | "ohs" => Ok(RelationshipType::OpenHostService),
+            "published-language" => Ok(RelationshipType::PublishedLanguage),
+            "shared-kernel" => Ok(RelationshipType::SharedKernel),
+            "partnership" => Ok(RelationshipType::Partnership),
+            _ => Err(anyhow::anyhow!("Invalid context relationship type")),
+        }
+    }
+}
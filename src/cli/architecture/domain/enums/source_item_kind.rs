@@ -0,0 +1,38 @@
+use std::fmt;
+
+#[derive(Clone, PartialEq)]
+pub enum SourceItemKind {
+    Struct,
+    Enum,
+    Trait,
+    Function,
+    TypeAlias,
+    Constant,
+}
+
+impl SourceItemKind {
+    pub fn from_keyword(keyword: &str) -> Option<SourceItemKind> {
+        match keyword {
+            "struct" => Some(SourceItemKind::Struct),
+            "enum" => Some(SourceItemKind::Enum),
+            "trait" => Some(SourceItemKind::Trait),
+            "fn" => Some(SourceItemKind::Function),
+            "type" => Some(SourceItemKind::TypeAlias),
+            "const" | "static" => Some(SourceItemKind::Constant),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SourceItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SourceItemKind::Struct => write!(f, "struct"),
+            SourceItemKind::Enum => write!(f, "enum"),
+            SourceItemKind::Trait => write!(f, "trait"),
+            SourceItemKind::Function => write!(f, "fn"),
+            SourceItemKind::TypeAlias => write!(f, "type"),
+            SourceItemKind::Constant => write!(f, "const"),
+        }
+    }
+}
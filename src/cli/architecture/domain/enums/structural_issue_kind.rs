@@ -0,0 +1,49 @@
+use std::fmt;
+
+#[derive(Clone, PartialEq)]
+pub enum StructuralIssueKind {
+    MissingModuleIndex,
+    UndeclaredModule,
+    DanglingModuleDeclaration,
+    UnknownLayerDirectory,
+    UnknownComponentDirectory,
+    MisplacedComponentDirectory,
+    EmptyComponentFile,
+}
+
+impl StructuralIssueKind {
+    pub fn get_kinds() -> Vec<StructuralIssueKind> {
+        vec![
+            StructuralIssueKind::MissingModuleIndex,
+            StructuralIssueKind::UndeclaredModule,
+            StructuralIssueKind::DanglingModuleDeclaration,
+            StructuralIssueKind::UnknownLayerDirectory,
+            StructuralIssueKind::UnknownComponentDirectory,
+            StructuralIssueKind::MisplacedComponentDirectory,
+            StructuralIssueKind::EmptyComponentFile
+        ]
+    }
+
+    pub fn is_fixable(&self) -> bool {
+        matches!(
+            self,
+            StructuralIssueKind::MissingModuleIndex |
+                StructuralIssueKind::UndeclaredModule |
+                StructuralIssueKind::DanglingModuleDeclaration
+        )
+    }
+}
+
+impl fmt::Display for StructuralIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StructuralIssueKind::MissingModuleIndex => write!(f, "missing-module-index"),
+            StructuralIssueKind::UndeclaredModule => write!(f, "undeclared-module"),
+            StructuralIssueKind::DanglingModuleDeclaration => write!(f, "dangling-module-declaration"),
+            StructuralIssueKind::UnknownLayerDirectory => write!(f, "unknown-layer-directory"),
+            StructuralIssueKind::UnknownComponentDirectory => write!(f, "unknown-component-directory"),
+            StructuralIssueKind::MisplacedComponentDirectory => write!(f, "misplaced-component-directory"),
+            StructuralIssueKind::EmptyComponentFile => write!(f, "empty-component-file"),
+        }
+    }
+}
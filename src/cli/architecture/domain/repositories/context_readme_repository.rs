@@ -0,0 +1,21 @@
+use thiserror::Error;
+use async_trait::async_trait;
+use crate::cli::architecture::domain::value_objects::context_readme::ContextReadme;
+
+#[derive(Error, Debug)]
+pub enum ContextReadmeRepositoryError {
+    #[error("Error reading bounded context README: {0}")] ReadError(String),
+    #[error("Error writing bounded context README: {0}")] WriteError(String),
+}
+
+#[async_trait]
+pub trait ContextReadmeRepository: Send + Sync {
+    async fn read_context_readme(
+        &self,
+        bounded_context_name: &str
+    ) -> Result<Option<ContextReadme>, ContextReadmeRepositoryError>;
+    async fn write_context_readme(
+        &self,
+        context_readme: &ContextReadme
+    ) -> Result<(), ContextReadmeRepositoryError>;
+}
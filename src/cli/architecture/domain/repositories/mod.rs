@@ -0,0 +1,4 @@
+pub mod context_readme_repository;
+pub mod project_config_repository;
+pub mod project_structure_repository;
+pub mod source_file_repository;
@@ -0,0 +1,17 @@
+use thiserror::Error;
+use async_trait::async_trait;
+use crate::cli::architecture::domain::value_objects::context_relationship::ContextRelationship;
+
+#[derive(Error, Debug)]
+pub enum ProjectConfigRepositoryError {
+    #[error("Error reading project config: {0}")] ReadError(String),
+}
+
+#[async_trait]
+pub trait ProjectConfigRepository: Send + Sync {
+    async fn read_project_name(&self) -> Result<String, ProjectConfigRepositoryError>;
+    async fn read_dependency_names(&self) -> Result<Vec<String>, ProjectConfigRepositoryError>;
+    async fn read_context_relationships(
+        &self
+    ) -> Result<Vec<ContextRelationship>, ProjectConfigRepositoryError>;
+}
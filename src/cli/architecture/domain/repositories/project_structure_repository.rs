@@ -0,0 +1,23 @@
+use thiserror::Error;
+use async_trait::async_trait;
+use crate::cli::architecture::domain::{
+    entities::project_structure::ProjectStructure,
+    value_objects::module_directory::ModuleDirectory,
+};
+
+#[derive(Error, Debug)]
+pub enum ProjectStructureRepositoryError {
+    #[error("Error reading project structure: {0}")] ReadError(String),
+    #[error("Error writing module declarations: {0}")] WriteError(String),
+}
+
+#[async_trait]
+pub trait ProjectStructureRepository: Send + Sync {
+    async fn read_project_structure(
+        &self
+    ) -> Result<ProjectStructure, ProjectStructureRepositoryError>;
+    async fn write_module_declarations(
+        &self,
+        module_directory: &ModuleDirectory
+    ) -> Result<(), ProjectStructureRepositoryError>;
+}
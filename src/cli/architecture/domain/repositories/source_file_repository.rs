@@ -0,0 +1,13 @@
+use thiserror::Error;
+use async_trait::async_trait;
+use crate::cli::architecture::domain::value_objects::source_file::SourceFile;
+
+#[derive(Error, Debug)]
+pub enum SourceFileRepositoryError {
+    #[error("Error reading source files: {0}")] ReadError(String),
+}
+
+#[async_trait]
+pub trait SourceFileRepository: Send + Sync {
+    async fn read_source_files(&self) -> Result<Vec<SourceFile>, SourceFileRepositoryError>;
+}
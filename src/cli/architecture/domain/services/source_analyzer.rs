@@ -0,0 +1,22 @@
+use crate::cli::architecture::domain::value_objects::{
+    domain_event_declaration::DomainEventDeclaration,
+    event_subscription::EventSubscription,
+    raised_event::RaisedEvent,
+    source_file::SourceFile,
+    source_import::SourceImport,
+    source_item::SourceItem,
+    stub_marker::StubMarker,
+    type_definition::TypeDefinition,
+};
+
+pub trait SourceAnalyzer: Send + Sync {
+    fn find_imports(&self, source_file: &SourceFile) -> Vec<SourceImport>;
+    fn find_public_items(&self, source_file: &SourceFile) -> Vec<SourceItem>;
+    fn find_raised_events(&self, source_file: &SourceFile) -> Vec<RaisedEvent>;
+    fn find_domain_events(&self, source_file: &SourceFile) -> Vec<DomainEventDeclaration>;
+    fn find_event_subscriptions(&self, source_file: &SourceFile) -> Vec<EventSubscription>;
+    fn find_type_definitions(&self, source_file: &SourceFile) -> Vec<TypeDefinition>;
+    fn find_stub_markers(&self, source_file: &SourceFile) -> Vec<StubMarker>;
+    fn is_blank(&self, source_file: &SourceFile) -> bool;
+    fn has_tests(&self, source_file: &SourceFile) -> bool;
+}
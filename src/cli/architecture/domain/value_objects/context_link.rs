@@ -0,0 +1,35 @@
+use crate::{
+    cli::architecture::domain::{
+        enums::relationship_type::RelationshipType,
+        value_objects::module_dependency::ModuleDependency,
+    },
+    core::domain::models::value_object::ValueObject,
+};
+
+#[derive(Clone)]
+pub struct ContextLinkValue {
+    pub upstream: String,
+    pub downstream: String,
+    pub relationship_type: Option<RelationshipType>,
+    pub dependencies: Vec<ModuleDependency>,
+}
+
+#[derive(Clone)]
+pub struct ContextLink {
+    value: ContextLinkValue,
+}
+
+impl ValueObject<ContextLinkValue> for ContextLink {
+    fn new(value: ContextLinkValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &ContextLinkValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.upstream == other.value.upstream &&
+            self.value.downstream == other.value.downstream
+    }
+}
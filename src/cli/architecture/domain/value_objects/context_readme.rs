@@ -0,0 +1,123 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+const GENERATED_SECTION_START: &str = "<!-- arpeggio:generated:start -->";
+const GENERATED_SECTION_END: &str = "<!-- arpeggio:generated:end -->";
+
+#[derive(Clone)]
+pub struct ContextReadmeValue {
+    pub bounded_context_name: String,
+    pub content: String,
+}
+
+#[derive(Clone)]
+pub struct ContextReadme {
+    value: ContextReadmeValue,
+}
+
+impl ContextReadme {
+    pub fn create(bounded_context_name: &str, generated_section: &str) -> Self {
+        Self::new(ContextReadmeValue {
+            bounded_context_name: bounded_context_name.to_string(),
+            content: format!("# {}\n\n", bounded_context_name),
+        }).with_generated_section(generated_section)
+    }
+
+    // Only the text between the markers is replaced, so anything written by
+    // hand before or after them is kept; without markers the generated
+    // section is appended.
+    pub fn with_generated_section(&self, generated_section: &str) -> Self {
+        let section = format!(
+            "{}\n{}\n{}",
+            GENERATED_SECTION_START,
+            generated_section.trim(),
+            GENERATED_SECTION_END
+        );
+        let content = &self.value.content;
+        let start = content.find(GENERATED_SECTION_START);
+        let end = start.and_then(|start| {
+            content[start..]
+                .find(GENERATED_SECTION_END)
+                .map(|end| start + end + GENERATED_SECTION_END.len())
+        });
+        let content = match (start, end) {
+            (Some(start), Some(end)) => {
+                format!("{}{}{}", &content[..start], section, &content[end..])
+            }
+            _ => {
+                let separator = match content.trim().is_empty() || content.ends_with("\n\n") {
+                    true => "",
+                    false if content.ends_with('\n') => "\n",
+                    false => "\n\n",
+                };
+                format!("{}{}{}\n", content, separator, section)
+            }
+        };
+        Self::new(ContextReadmeValue {
+            bounded_context_name: self.value.bounded_context_name.clone(),
+            content,
+        })
+    }
+}
+
+impl ValueObject<ContextReadmeValue> for ContextReadme {
+    fn new(value: ContextReadmeValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &ContextReadmeValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.bounded_context_name == other.value.bounded_context_name &&
+            self.value.content == other.value.content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::architecture::domain::value_objects::context_readme::{
+            ContextReadme,
+            ContextReadmeValue,
+        },
+        core::domain::models::value_object::ValueObject,
+    };
+
+    fn readme(content: &str) -> ContextReadme {
+        ContextReadme::new(ContextReadmeValue {
+            bounded_context_name: "sales".to_string(),
+            content: content.to_string(),
+        })
+    }
+
+    #[test]
+    fn should_replace_only_the_generated_section() {
+        let content =
+            "# Sales\n\nWritten by hand.\n\n\
+            <!-- arpeggio:generated:start -->\nold\n<!-- arpeggio:generated:end -->\n\n\
+            ## Notes\n";
+        let updated_readme = readme(content).with_generated_section("new\n");
+        assert_eq!(
+            updated_readme.get_value().content,
+            "# Sales\n\nWritten by hand.\n\n\
+            <!-- arpeggio:generated:start -->\nnew\n<!-- arpeggio:generated:end -->\n\n\
+            ## Notes\n"
+        );
+        assert!(updated_readme.with_generated_section("new").is_equal(&updated_readme));
+    }
+
+    #[test]
+    fn should_append_the_generated_section_when_markers_are_missing() {
+        let updated_readme = readme("# Sales\nWritten by hand.\n").with_generated_section("new");
+        assert_eq!(
+            updated_readme.get_value().content,
+            "# Sales\nWritten by hand.\n\n\
+            <!-- arpeggio:generated:start -->\nnew\n<!-- arpeggio:generated:end -->\n"
+        );
+        assert_eq!(
+            ContextReadme::create("sales", "new").get_value().content,
+            "# sales\n\n<!-- arpeggio:generated:start -->\nnew\n<!-- arpeggio:generated:end -->\n"
+        );
+    }
+}
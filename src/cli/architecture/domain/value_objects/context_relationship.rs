@@ -0,0 +1,40 @@
+use crate::{
+    cli::architecture::domain::enums::relationship_type::RelationshipType,
+    core::domain::models::value_object::ValueObject,
+};
+
+#[derive(Clone)]
+pub struct ContextRelationshipValue {
+    pub upstream: String,
+    pub downstream: String,
+    pub relationship_type: RelationshipType,
+}
+
+#[derive(Clone)]
+pub struct ContextRelationship {
+    value: ContextRelationshipValue,
+}
+
+impl ContextRelationship {
+    pub fn connects(&self, upstream: &str, downstream: &str) -> bool {
+        let value = &self.value;
+        (value.upstream == upstream && value.downstream == downstream) ||
+            (value.relationship_type.is_symmetric() &&
+                value.upstream == downstream &&
+                value.downstream == upstream)
+    }
+}
+
+impl ValueObject<ContextRelationshipValue> for ContextRelationship {
+    fn new(value: ContextRelationshipValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &ContextRelationshipValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.connects(&other.value.upstream, &other.value.downstream)
+    }
+}
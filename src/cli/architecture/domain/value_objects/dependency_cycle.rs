@@ -0,0 +1,36 @@
+use crate::{
+    cli::architecture::domain::value_objects::module_dependency::ModuleDependency,
+    core::domain::models::value_object::ValueObject,
+};
+
+#[derive(Clone)]
+pub struct DependencyCycle {
+    value: Vec<ModuleDependency>,
+}
+
+impl DependencyCycle {
+    pub fn get_modules(&self) -> Vec<String> {
+        let mut modules = self.value
+            .iter()
+            .map(|dependency| dependency.get_value().source_module.clone())
+            .collect::<Vec<_>>();
+        if let Some(first_module) = modules.first().cloned() {
+            modules.push(first_module);
+        }
+        modules
+    }
+}
+
+impl ValueObject<Vec<ModuleDependency>> for DependencyCycle {
+    fn new(value: Vec<ModuleDependency>) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &Vec<ModuleDependency> {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.get_modules() == other.get_modules()
+    }
+}
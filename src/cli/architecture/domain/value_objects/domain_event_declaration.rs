@@ -0,0 +1,28 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct DomainEventDeclarationValue {
+    pub type_name: String,
+    pub event_name: String,
+    pub line: usize,
+}
+
+#[derive(Clone)]
+pub struct DomainEventDeclaration {
+    value: DomainEventDeclarationValue,
+}
+
+impl ValueObject<DomainEventDeclarationValue> for DomainEventDeclaration {
+    fn new(value: DomainEventDeclarationValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &DomainEventDeclarationValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.type_name == other.value.type_name &&
+            self.value.event_name == other.value.event_name
+    }
+}
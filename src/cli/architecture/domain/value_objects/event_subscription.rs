@@ -0,0 +1,45 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct EventSubscriptionValue {
+    pub subscriber_name: String,
+    pub event_name: Option<String>,
+    pub referenced_types: Vec<String>,
+    pub line: usize,
+}
+
+#[derive(Clone)]
+pub struct EventSubscription {
+    value: EventSubscriptionValue,
+}
+
+impl EventSubscription {
+    // The literal returned by `subscribed_to()` when there is one, otherwise
+    // the name of the first referenced type found among the known events.
+    pub fn resolve_event_name(
+        &self,
+        find_event_name: impl Fn(&str) -> Option<String>
+    ) -> Option<String> {
+        self.value.event_name
+            .clone()
+            .or_else(|| {
+                self.value.referenced_types
+                    .iter()
+                    .find_map(|type_name| find_event_name(type_name))
+            })
+    }
+}
+
+impl ValueObject<EventSubscriptionValue> for EventSubscription {
+    fn new(value: EventSubscriptionValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &EventSubscriptionValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.subscriber_name == other.value.subscriber_name
+    }
+}
@@ -0,0 +1,15 @@
+pub mod context_link;
+pub mod context_readme;
+pub mod context_relationship;
+pub mod dependency_cycle;
+pub mod domain_event_declaration;
+pub mod event_subscription;
+pub mod module_dependency;
+pub mod module_directory;
+pub mod raised_event;
+pub mod source_file;
+pub mod source_import;
+pub mod source_item;
+pub mod structural_issue;
+pub mod stub_marker;
+pub mod type_definition;
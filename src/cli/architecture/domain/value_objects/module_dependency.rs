@@ -0,0 +1,30 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct ModuleDependencyValue {
+    pub source_module: String,
+    pub target_module: String,
+    pub file_path: String,
+    pub line: usize,
+    pub statement: String,
+}
+
+#[derive(Clone)]
+pub struct ModuleDependency {
+    value: ModuleDependencyValue,
+}
+
+impl ValueObject<ModuleDependencyValue> for ModuleDependency {
+    fn new(value: ModuleDependencyValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &ModuleDependencyValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.source_module == other.value.source_module &&
+            self.value.target_module == other.value.target_module
+    }
+}
@@ -0,0 +1,83 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct ModuleFile {
+    pub name: String,
+    pub is_empty: bool,
+}
+
+#[derive(Clone)]
+pub struct ModuleSubdirectory {
+    pub name: String,
+    pub has_index: bool,
+}
+
+#[derive(Clone)]
+pub struct ModuleDirectoryValue {
+    pub path: Vec<String>,
+    pub declared_modules: Option<Vec<String>>,
+    pub child_directories: Vec<ModuleSubdirectory>,
+    pub child_files: Vec<ModuleFile>,
+}
+
+#[derive(Clone)]
+pub struct ModuleDirectory {
+    value: ModuleDirectoryValue,
+}
+
+impl ModuleDirectory {
+    pub fn get_modules(&self) -> Vec<String> {
+        let mut modules = self.value.child_directories
+            .iter()
+            .map(|directory| directory.name.clone())
+            .collect::<Vec<_>>();
+        modules.extend(self.value.child_files.iter().map(|file| file.name.clone()));
+        modules.sort();
+        modules
+    }
+
+    pub fn get_undeclared_modules(&self, declarable_modules: &[String]) -> Vec<String> {
+        let declared_modules = self.value.declared_modules.clone().unwrap_or_default();
+        declarable_modules
+            .iter()
+            .filter(|module| !declared_modules.contains(module))
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_dangling_modules(&self) -> Vec<String> {
+        let modules = self.get_modules();
+        self.value.declared_modules
+            .iter()
+            .flatten()
+            .filter(|module| !modules.contains(module))
+            .cloned()
+            .collect()
+    }
+
+    pub fn declare_modules(&mut self, declarable_modules: &[String]) {
+        let modules = self.get_modules();
+        let mut declared_modules = self.value.declared_modules
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|module| modules.contains(module))
+            .collect::<Vec<_>>();
+        declared_modules.extend(self.get_undeclared_modules(declarable_modules));
+        self.value.declared_modules = Some(declared_modules);
+    }
+}
+
+impl ValueObject<ModuleDirectoryValue> for ModuleDirectory {
+    fn new(value: ModuleDirectoryValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &ModuleDirectoryValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.path == other.value.path
+    }
+}
@@ -0,0 +1,26 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct RaisedEventValue {
+    pub event_name: String,
+    pub line: usize,
+}
+
+#[derive(Clone)]
+pub struct RaisedEvent {
+    value: RaisedEventValue,
+}
+
+impl ValueObject<RaisedEventValue> for RaisedEvent {
+    fn new(value: RaisedEventValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &RaisedEventValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.event_name == other.value.event_name && self.value.line == other.value.line
+    }
+}
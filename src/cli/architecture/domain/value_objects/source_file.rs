@@ -0,0 +1,51 @@
+use crate::{
+    cli::bounded_context::domain::enums::{ component_type::ComponentType, layer_name::LayerName },
+    core::domain::models::value_object::ValueObject,
+};
+
+#[derive(Clone)]
+pub struct SourceFileValue {
+    pub path: String,
+    pub bounded_context_name: String,
+    pub aggregate_name: Option<String>,
+    pub layer_name: Option<LayerName>,
+    pub component_type: Option<ComponentType>,
+    pub content: String,
+}
+
+#[derive(Clone)]
+pub struct SourceFile {
+    value: SourceFileValue,
+}
+
+impl SourceFile {
+    pub fn get_module_name(&self) -> String {
+        match &self.value.aggregate_name {
+            Some(aggregate_name) => format!("{}/{}", self.value.bounded_context_name, aggregate_name),
+            None => self.value.bounded_context_name.to_string(),
+        }
+    }
+
+    pub fn get_component_name(&self) -> String {
+        self.value.path
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches(".rs")
+            .to_string()
+    }
+}
+
+impl ValueObject<SourceFileValue> for SourceFile {
+    fn new(value: SourceFileValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &SourceFileValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.path == other.value.path
+    }
+}
@@ -0,0 +1,27 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct SourceImportValue {
+    pub path: Vec<String>,
+    pub line: usize,
+    pub statement: String,
+}
+
+#[derive(Clone)]
+pub struct SourceImport {
+    value: SourceImportValue,
+}
+
+impl ValueObject<SourceImportValue> for SourceImport {
+    fn new(value: SourceImportValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &SourceImportValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.path == other.value.path && self.value.line == other.value.line
+    }
+}
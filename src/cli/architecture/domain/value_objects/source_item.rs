@@ -0,0 +1,32 @@
+use crate::{
+    cli::architecture::domain::enums::source_item_kind::SourceItemKind,
+    core::domain::models::value_object::ValueObject,
+};
+
+#[derive(Clone)]
+pub struct SourceItemValue {
+    pub kind: SourceItemKind,
+    pub name: String,
+    pub line: usize,
+}
+
+#[derive(Clone)]
+pub struct SourceItem {
+    value: SourceItemValue,
+}
+
+impl ValueObject<SourceItemValue> for SourceItem {
+    fn new(value: SourceItemValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &SourceItemValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.kind == other.value.kind &&
+            self.value.name == other.value.name &&
+            self.value.line == other.value.line
+    }
+}
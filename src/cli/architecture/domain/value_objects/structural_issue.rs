@@ -0,0 +1,32 @@
+use crate::{
+    cli::architecture::domain::enums::structural_issue_kind::StructuralIssueKind,
+    core::domain::models::value_object::ValueObject,
+};
+
+#[derive(Clone)]
+pub struct StructuralIssueValue {
+    pub kind: StructuralIssueKind,
+    pub path: String,
+    pub description: String,
+}
+
+#[derive(Clone)]
+pub struct StructuralIssue {
+    value: StructuralIssueValue,
+}
+
+impl ValueObject<StructuralIssueValue> for StructuralIssue {
+    fn new(value: StructuralIssueValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &StructuralIssueValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.kind == other.value.kind &&
+            self.value.path == other.value.path &&
+            self.value.description == other.value.description
+    }
+}
@@ -0,0 +1,26 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct StubMarkerValue {
+    pub macro_name: String,
+    pub line: usize,
+}
+
+#[derive(Clone)]
+pub struct StubMarker {
+    value: StubMarkerValue,
+}
+
+impl ValueObject<StubMarkerValue> for StubMarker {
+    fn new(value: StubMarkerValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &StubMarkerValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.macro_name == other.value.macro_name && self.value.line == other.value.line
+    }
+}
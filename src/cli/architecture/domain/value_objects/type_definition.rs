@@ -0,0 +1,63 @@
+use crate::{
+    cli::architecture::domain::enums::source_item_kind::SourceItemKind,
+    core::domain::models::value_object::ValueObject,
+};
+
+#[derive(Clone)]
+pub struct TypeMember {
+    pub name: String,
+    pub type_name: Option<String>,
+    pub parameters: Option<Vec<String>>,
+}
+
+#[derive(Clone)]
+pub struct TypeDefinitionValue {
+    pub kind: SourceItemKind,
+    pub name: String,
+    pub members: Vec<TypeMember>,
+    pub implemented_traits: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct TypeDefinition {
+    value: TypeDefinitionValue,
+}
+
+impl TypeDefinition {
+    // Every type name mentioned by the member signatures, so that references
+    // between definitions can be drawn as relations.
+    pub fn get_referenced_types(&self) -> Vec<(String, String)> {
+        let mut referenced_types = vec![];
+        for member in &self.value.members {
+            let signature = std::iter
+                ::once(member.type_name.clone().unwrap_or_default())
+                .chain(member.parameters.clone().unwrap_or_default())
+                .collect::<Vec<_>>()
+                .join(" ");
+            signature
+                .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .filter(|word| word.starts_with(|c: char| c.is_uppercase()))
+                .for_each(|word| {
+                    let reference = (member.name.clone(), word.to_string());
+                    if !referenced_types.contains(&reference) {
+                        referenced_types.push(reference);
+                    }
+                });
+        }
+        referenced_types
+    }
+}
+
+impl ValueObject<TypeDefinitionValue> for TypeDefinition {
+    fn new(value: TypeDefinitionValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &TypeDefinitionValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.kind == other.value.kind && self.value.name == other.value.name
+    }
+}
@@ -0,0 +1,62 @@
+use std::{ fs, path::Path };
+use async_trait::async_trait;
+use crate::{
+    cli::architecture::domain::{
+        repositories::context_readme_repository::{
+            ContextReadmeRepository,
+            ContextReadmeRepositoryError,
+        },
+        value_objects::context_readme::{ ContextReadme, ContextReadmeValue },
+    },
+    core::domain::models::value_object::ValueObject,
+};
+
+pub struct FilesystemContextReadmeRepository;
+
+impl FilesystemContextReadmeRepository {
+    const SOURCE_DIR: &'static str = "./src";
+    const README_FILE_NAME: &'static str = "README.md";
+
+    fn get_readme_path(&self, bounded_context_name: &str) -> String {
+        format!("{}/{}/{}", Self::SOURCE_DIR, bounded_context_name, Self::README_FILE_NAME)
+    }
+}
+
+#[async_trait]
+impl ContextReadmeRepository for FilesystemContextReadmeRepository {
+    async fn read_context_readme(
+        &self,
+        bounded_context_name: &str
+    ) -> Result<Option<ContextReadme>, ContextReadmeRepositoryError> {
+        let readme_path = self.get_readme_path(bounded_context_name);
+        if !Path::new(&readme_path).exists() {
+            return Ok(None);
+        }
+        let content = fs
+            ::read_to_string(&readme_path)
+            .map_err(|e| {
+                ContextReadmeRepositoryError::ReadError(format!("{}: {}", readme_path, e))
+            })?;
+        Ok(
+            Some(
+                ContextReadme::new(ContextReadmeValue {
+                    bounded_context_name: bounded_context_name.to_string(),
+                    content,
+                })
+            )
+        )
+    }
+
+    async fn write_context_readme(
+        &self,
+        context_readme: &ContextReadme
+    ) -> Result<(), ContextReadmeRepositoryError> {
+        let value = context_readme.get_value();
+        let readme_path = self.get_readme_path(&value.bounded_context_name);
+        fs
+            ::write(&readme_path, &value.content)
+            .map_err(|e| {
+                ContextReadmeRepositoryError::WriteError(format!("{}: {}", readme_path, e))
+            })
+    }
+}
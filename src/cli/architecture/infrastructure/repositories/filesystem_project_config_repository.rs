@@ -0,0 +1,135 @@
+use std::{ env, fs, path::Path, str::FromStr };
+use async_trait::async_trait;
+use serde::Deserialize;
+use crate::{
+    cli::architecture::domain::{
+        enums::relationship_type::RelationshipType,
+        repositories::project_config_repository::{
+            ProjectConfigRepository,
+            ProjectConfigRepositoryError,
+        },
+        value_objects::context_relationship::{ ContextRelationship, ContextRelationshipValue },
+    },
+    core::domain::models::value_object::ValueObject,
+};
+
+#[derive(Deserialize)]
+struct RelationshipEntry {
+    upstream: String,
+    downstream: String,
+    #[serde(rename = "type")]
+    relationship_type: String,
+}
+
+#[derive(Deserialize)]
+struct ProjectConfigFile {
+    #[serde(default)]
+    relationships: Vec<RelationshipEntry>,
+}
+
+pub struct FilesystemProjectConfigRepository;
+
+impl FilesystemProjectConfigRepository {
+    const CONFIG_PATH: &'static str = "./arpeggio.toml";
+    const MANIFEST_PATH: &'static str = "./Cargo.toml";
+
+    fn read_config_file(&self) -> Result<Option<ProjectConfigFile>, ProjectConfigRepositoryError> {
+        if !Path::new(Self::CONFIG_PATH).exists() {
+            return Ok(None);
+        }
+        let content = fs
+            ::read_to_string(Self::CONFIG_PATH)
+            .map_err(|e| ProjectConfigRepositoryError::ReadError(e.to_string()))?;
+        let config_file = toml
+            ::from_str(&content)
+            .map_err(|e| {
+                ProjectConfigRepositoryError::ReadError(format!("{}: {}", Self::CONFIG_PATH, e))
+            })?;
+        Ok(Some(config_file))
+    }
+}
+
+#[async_trait]
+impl ProjectConfigRepository for FilesystemProjectConfigRepository {
+    // The package name declared in the project manifest, falling back to the
+    // name of the current directory.
+    async fn read_project_name(&self) -> Result<String, ProjectConfigRepositoryError> {
+        let package_name = fs
+            ::read_to_string(Self::MANIFEST_PATH)
+            .ok()
+            .and_then(|content| toml::from_str::<toml::Value>(&content).ok())
+            .and_then(|manifest| {
+                manifest
+                    .get("package")
+                    .and_then(|package| package.get("name"))
+                    .and_then(|name| name.as_str())
+                    .map(|name| name.to_string())
+            });
+        if let Some(package_name) = package_name {
+            return Ok(package_name);
+        }
+        let current_dir = env
+            ::current_dir()
+            .map_err(|e| ProjectConfigRepositoryError::ReadError(e.to_string()))?;
+        Ok(
+            current_dir
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| "project".to_string())
+        )
+    }
+
+    // The crates listed under `[dependencies]` in the project manifest, none
+    // when there is no manifest.
+    async fn read_dependency_names(&self) -> Result<Vec<String>, ProjectConfigRepositoryError> {
+        if !Path::new(Self::MANIFEST_PATH).exists() {
+            return Ok(vec![]);
+        }
+        let content = fs
+            ::read_to_string(Self::MANIFEST_PATH)
+            .map_err(|e| ProjectConfigRepositoryError::ReadError(e.to_string()))?;
+        let manifest = toml
+            ::from_str::<toml::Value>(&content)
+            .map_err(|e| {
+                ProjectConfigRepositoryError::ReadError(format!("{}: {}", Self::MANIFEST_PATH, e))
+            })?;
+        Ok(
+            manifest
+                .get("dependencies")
+                .and_then(|dependencies| dependencies.as_table())
+                .map(|dependencies| dependencies.keys().cloned().collect())
+                .unwrap_or_default()
+        )
+    }
+
+    async fn read_context_relationships(
+        &self
+    ) -> Result<Vec<ContextRelationship>, ProjectConfigRepositoryError> {
+        let Some(config_file) = self.read_config_file()? else {
+            return Ok(vec![]);
+        };
+        config_file.relationships
+            .into_iter()
+            .map(|relationship| {
+                let relationship_type = RelationshipType::from_str(
+                    &relationship.relationship_type
+                ).map_err(|_| {
+                    ProjectConfigRepositoryError::ReadError(
+                        format!(
+                            "{}: invalid relationship type <{}>",
+                            Self::CONFIG_PATH,
+                            relationship.relationship_type
+                        )
+                    )
+                })?;
+                Ok(
+                    ContextRelationship::new(ContextRelationshipValue {
+                        upstream: relationship.upstream,
+                        downstream: relationship.downstream,
+                        relationship_type,
+                    })
+                )
+            })
+            .collect()
+    }
+}
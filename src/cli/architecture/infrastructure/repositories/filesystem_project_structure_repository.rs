@@ -0,0 +1,252 @@
+use std::{ fs, path::Path };
+use anyhow::Result;
+use async_trait::async_trait;
+use crate::{
+    cli::architecture::domain::{
+        entities::project_structure::ProjectStructure,
+        repositories::project_structure_repository::{
+            ProjectStructureRepository,
+            ProjectStructureRepositoryError,
+        },
+        value_objects::module_directory::{
+            ModuleDirectory,
+            ModuleDirectoryValue,
+            ModuleFile,
+            ModuleSubdirectory,
+        },
+    },
+    core::domain::models::{ identity_object::IdentityObject, value_object::ValueObject },
+};
+
+pub struct FilesystemProjectStructureRepository;
+
+impl FilesystemProjectStructureRepository {
+    const SOURCE_DIR: &'static str = "./src";
+    const MAX_DEPTH: usize = 4;
+
+    fn get_directory_path(&self, path: &[String]) -> String {
+        format!("{}/{}", Self::SOURCE_DIR, path.join("/"))
+    }
+
+    fn get_dir_names(&self, path: &str) -> Result<Vec<String>> {
+        let mut directory_names = fs
+            ::read_dir(path)?
+            .filter_map(Result::ok)
+            .filter(|e|
+                e
+                    .file_type()
+                    .map(|t| t.is_dir())
+                    .unwrap_or(false)
+            )
+            .filter_map(|e| e.file_name().into_string().ok())
+            .collect::<Vec<_>>();
+        directory_names.sort();
+        Ok(directory_names)
+    }
+
+    fn get_module_files(&self, path: &str) -> Result<Vec<ModuleFile>> {
+        let mut module_files = vec![];
+        for entry in fs::read_dir(path)?.filter_map(Result::ok) {
+            let file_path = entry.path();
+            let is_module_file =
+                file_path.is_file() &&
+                file_path.extension().is_some_and(|extension| extension == "rs");
+            let Some(name) = file_path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            if !is_module_file || name == "mod" {
+                continue;
+            }
+            module_files.push(ModuleFile {
+                name: name.to_string(),
+                is_empty: fs::read_to_string(&file_path)?.trim().is_empty(),
+            });
+        }
+        module_files.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(module_files)
+    }
+
+    fn strip_attributes<'a>(&self, line: &'a str) -> &'a str {
+        let mut line = line.trim_start();
+        while line.starts_with("#[") {
+            let mut depth = 0;
+            let Some(end) = line[1..].find(|c: char| {
+                match c {
+                    '[' => depth += 1,
+                    ']' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            }) else {
+                return line;
+            };
+            line = line[end + 2..].trim_start();
+        }
+        line
+    }
+
+    fn parse_module_declaration(&self, line: &str) -> Option<String> {
+        let tokens = self.strip_attributes(line).split_whitespace().collect::<Vec<_>>();
+        let mod_position = tokens.iter().position(|token| *token == "mod")?;
+        if !tokens[..mod_position].iter().all(|token| token.starts_with("pub")) {
+            return None;
+        }
+        let name = tokens.get(mod_position + 1)?.strip_suffix(';')?;
+        Some(name.to_string())
+    }
+
+    fn read_declared_modules(&self, path: &str) -> Result<Option<Vec<String>>> {
+        let index_file_path = format!("{}/mod.rs", path);
+        if !Path::new(&index_file_path).exists() {
+            return Ok(None);
+        }
+        Ok(
+            Some(
+                fs
+                    ::read_to_string(index_file_path)?
+                    .lines()
+                    .filter_map(|line| self.parse_module_declaration(line))
+                    .collect()
+            )
+        )
+    }
+
+    fn read_module_directories(
+        &self,
+        path: Vec<String>,
+        directories: &mut Vec<ModuleDirectory>
+    ) -> Result<()> {
+        let directory_path = self.get_directory_path(&path);
+        let child_directories = self.get_dir_names(&directory_path)?;
+        directories.push(
+            ModuleDirectory::new(ModuleDirectoryValue {
+                path: path.clone(),
+                declared_modules: self.read_declared_modules(&directory_path)?,
+                child_directories: child_directories
+                    .iter()
+                    .map(|name| ModuleSubdirectory {
+                        name: name.clone(),
+                        has_index: Path::new(
+                            &format!("{}/{}/mod.rs", directory_path, name)
+                        ).exists(),
+                    })
+                    .collect(),
+                child_files: self.get_module_files(&directory_path)?,
+            })
+        );
+        if path.len() < Self::MAX_DEPTH {
+            // Like `src/core`, a bounded context's `core` holds shared code
+            // rather than an aggregate.
+            for child_directory in child_directories {
+                if path.len() == 1 && child_directory == "core" {
+                    continue;
+                }
+                let mut child_path = path.clone();
+                child_path.push(child_directory);
+                self.read_module_directories(child_path, directories)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_declarations(&self, path: &str, declared_modules: &[String]) -> Result<()> {
+        let index_file_path = format!("{}/mod.rs", path);
+        if !Path::new(&index_file_path).exists() {
+            let mut module_exports = declared_modules
+                .iter()
+                .map(|module| format!("pub mod {};", module))
+                .collect::<Vec<_>>()
+                .join("\n");
+            module_exports.push('\n');
+            fs::write(index_file_path, module_exports)?;
+            return Ok(());
+        }
+        let mut lines = vec![];
+        let mut existing_modules = vec![];
+        for line in fs::read_to_string(&index_file_path)?.lines() {
+            match self.parse_module_declaration(line) {
+                Some(module) if !declared_modules.contains(&module) => {}
+                Some(module) => {
+                    existing_modules.push(module);
+                    lines.push(line.to_string());
+                }
+                None => lines.push(line.to_string()),
+            }
+        }
+        let insert_position = lines
+            .iter()
+            .rposition(|line| self.parse_module_declaration(line).is_some())
+            .map_or(lines.len(), |position| position + 1);
+        let missing_declarations = declared_modules
+            .iter()
+            .filter(|module| !existing_modules.contains(module))
+            .map(|module| format!("pub mod {};", module))
+            .collect::<Vec<_>>();
+        lines.splice(insert_position..insert_position, missing_declarations);
+        let mut content = lines.join("\n");
+        content.push('\n');
+        fs::write(index_file_path, content)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectStructureRepository for FilesystemProjectStructureRepository {
+    async fn read_project_structure(
+        &self
+    ) -> Result<ProjectStructure, ProjectStructureRepositoryError> {
+        let mut directories = vec![];
+        let bounded_context_directories = self
+            .get_dir_names(Self::SOURCE_DIR)
+            .map_err(|e| ProjectStructureRepositoryError::ReadError(e.to_string()))?;
+        for bounded_context_directory in bounded_context_directories {
+            if bounded_context_directory == "core" {
+                continue;
+            }
+            self
+                .read_module_directories(vec![bounded_context_directory], &mut directories)
+                .map_err(|e| ProjectStructureRepositoryError::ReadError(e.to_string()))?;
+        }
+        Ok(
+            ProjectStructure::new(
+                IdentityObject::new(Self::SOURCE_DIR.trim_start_matches("./").to_string()),
+                directories
+            )
+        )
+    }
+
+    async fn write_module_declarations(
+        &self,
+        module_directory: &ModuleDirectory
+    ) -> Result<(), ProjectStructureRepositoryError> {
+        let value = module_directory.get_value();
+        self
+            .write_declarations(
+                &self.get_directory_path(&value.path),
+                &value.declared_modules.clone().unwrap_or_default()
+            )
+            .map_err(|e| ProjectStructureRepositoryError::WriteError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::architecture::infrastructure::repositories::{
+        filesystem_project_structure_repository::FilesystemProjectStructureRepository,
+    };
+
+    #[test]
+    fn should_parse_module_declarations_behind_attributes() {
+        let repository = FilesystemProjectStructureRepository;
+        let modules = [
+            "pub mod order;",
+            "#[cfg(test)] mod tests;",
+            "#[cfg(all(test, unix))] pub(crate) mod fixtures;",
+            "use crate::core;",
+        ]
+            .iter()
+            .filter_map(|line| repository.parse_module_declaration(line))
+            .collect::<Vec<_>>();
+        assert_eq!(modules, vec!["order", "tests", "fixtures"]);
+    }
+}
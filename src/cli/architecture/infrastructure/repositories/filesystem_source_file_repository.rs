@@ -0,0 +1,82 @@
+use std::{ fs, path::Path, str::FromStr };
+use anyhow::Result;
+use async_trait::async_trait;
+use crate::{
+    cli::{
+        architecture::domain::{
+            repositories::source_file_repository::{
+                SourceFileRepository,
+                SourceFileRepositoryError,
+            },
+            value_objects::source_file::{ SourceFile, SourceFileValue },
+        },
+        bounded_context::domain::enums::{ component_type::ComponentType, layer_name::LayerName },
+    },
+    core::domain::models::value_object::ValueObject,
+};
+
+pub struct FilesystemSourceFileRepository;
+
+impl FilesystemSourceFileRepository {
+    const SOURCE_DIR: &'static str = "./src";
+
+    fn collect_file_paths(&self, path: &Path, file_paths: &mut Vec<String>) -> Result<()> {
+        for entry in fs::read_dir(path)? {
+            let entry_path = entry?.path();
+            if entry_path.is_dir() {
+                self.collect_file_paths(&entry_path, file_paths)?;
+            } else if entry_path.extension().is_some_and(|extension| extension == "rs") {
+                file_paths.push(entry_path.to_string_lossy().replace('\\', "/"));
+            }
+        }
+        Ok(())
+    }
+
+    fn read_source_file(&self, file_path: &str) -> Result<Option<SourceFile>> {
+        let relative_path = file_path.trim_start_matches("./");
+        let segments = relative_path.split('/').collect::<Vec<_>>();
+        let directories = &segments[1..segments.len() - 1];
+        let Some(bounded_context_name) = directories.first() else {
+            return Ok(None);
+        };
+        if *bounded_context_name == "core" {
+            return Ok(None);
+        }
+        Ok(
+            Some(
+                SourceFile::new(SourceFileValue {
+                    path: relative_path.to_string(),
+                    bounded_context_name: bounded_context_name.to_string(),
+                    aggregate_name: directories.get(1).map(|name| name.to_string()),
+                    layer_name: directories.get(2).and_then(|name| LayerName::from_str(name).ok()),
+                    component_type: directories
+                        .get(3)
+                        .and_then(|name| ComponentType::from_str(name).ok()),
+                    content: fs::read_to_string(file_path)?,
+                })
+            )
+        )
+    }
+}
+
+#[async_trait]
+impl SourceFileRepository for FilesystemSourceFileRepository {
+    async fn read_source_files(&self) -> Result<Vec<SourceFile>, SourceFileRepositoryError> {
+        let mut file_paths = vec![];
+        self
+            .collect_file_paths(Path::new(Self::SOURCE_DIR), &mut file_paths)
+            .map_err(|e| SourceFileRepositoryError::ReadError(e.to_string()))?;
+        file_paths.sort();
+        let mut source_files = vec![];
+        for file_path in file_paths {
+            if
+                let Some(source_file) = self
+                    .read_source_file(&file_path)
+                    .map_err(|e| SourceFileRepositoryError::ReadError(e.to_string()))?
+            {
+                source_files.push(source_file);
+            }
+        }
+        Ok(source_files)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod filesystem_context_readme_repository;
+pub mod filesystem_project_config_repository;
+pub mod filesystem_project_structure_repository;
+pub mod filesystem_source_file_repository;
@@ -0,0 +1,1091 @@
+use std::{ collections::HashMap, ops::Range };
+use crate::{
+    cli::architecture::domain::{
+        enums::source_item_kind::SourceItemKind,
+        services::source_analyzer::SourceAnalyzer,
+        value_objects::{
+            domain_event_declaration::{ DomainEventDeclaration, DomainEventDeclarationValue },
+            event_subscription::{ EventSubscription, EventSubscriptionValue },
+            raised_event::{ RaisedEvent, RaisedEventValue },
+            source_file::SourceFile,
+            source_import::{ SourceImport, SourceImportValue },
+            source_item::{ SourceItem, SourceItemValue },
+            stub_marker::{ StubMarker, StubMarkerValue },
+            type_definition::{ TypeDefinition, TypeDefinitionValue, TypeMember },
+        },
+    },
+    core::domain::models::value_object::ValueObject,
+};
+
+struct TraitImplementation {
+    trait_name: String,
+    type_name: String,
+    line: usize,
+    body: Range<usize>,
+}
+
+pub struct LexicalSourceAnalyzer;
+
+impl LexicalSourceAnalyzer {
+    // Blanks out comments and literal contents while keeping line breaks, so that
+    // positions in the returned code still match the original content.
+    fn strip_comments_and_literals(&self, content: &str) -> String {
+        let chars = content.chars().collect::<Vec<_>>();
+        let mut code = String::with_capacity(content.len());
+        let mut index = 0;
+        let blank = |c: char| if c == '\n' { '\n' } else { ' ' };
+        while index < chars.len() {
+            let c = chars[index];
+            let next = chars.get(index + 1).copied();
+            let is_word_start =
+                index == 0 || !(chars[index - 1].is_alphanumeric() || chars[index - 1] == '_');
+            if c == '/' && next == Some('/') {
+                while index < chars.len() && chars[index] != '\n' {
+                    code.push(' ');
+                    index += 1;
+                }
+            } else if c == '/' && next == Some('*') {
+                let mut depth = 0;
+                while index < chars.len() {
+                    if chars[index] == '/' && chars.get(index + 1) == Some(&'*') {
+                        depth += 1;
+                        code.push_str("  ");
+                        index += 2;
+                    } else if chars[index] == '*' && chars.get(index + 1) == Some(&'/') {
+                        depth -= 1;
+                        code.push_str("  ");
+                        index += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        code.push(blank(chars[index]));
+                        index += 1;
+                    }
+                }
+            } else if
+                is_word_start &&
+                (c == 'r' || (c == 'b' && next == Some('r'))) &&
+                self.get_raw_string_hashes(&chars, index + if c == 'b' { 2 } else { 1 }).is_some()
+            {
+                let prefix_length = if c == 'b' { 2 } else { 1 };
+                let hashes = self.get_raw_string_hashes(&chars, index + prefix_length).unwrap();
+                let opening_length = prefix_length + hashes + 1;
+                chars[index..index + opening_length].iter().for_each(|c| code.push(*c));
+                index += opening_length;
+                while index < chars.len() {
+                    if
+                        chars[index] == '"' &&
+                        (1..=hashes).all(|offset| chars.get(index + offset) == Some(&'#'))
+                    {
+                        code.push('"');
+                        (0..hashes).for_each(|_| code.push('#'));
+                        index += hashes + 1;
+                        break;
+                    }
+                    code.push(blank(chars[index]));
+                    index += 1;
+                }
+            } else if c == '"' {
+                code.push('"');
+                index += 1;
+                while index < chars.len() && chars[index] != '"' {
+                    if chars[index] == '\\' && index + 1 < chars.len() {
+                        code.push(' ');
+                        index += 1;
+                    }
+                    code.push(blank(chars[index]));
+                    index += 1;
+                }
+                if index < chars.len() {
+                    code.push('"');
+                    index += 1;
+                }
+            } else if c == '\'' && self.get_char_literal_length(&chars, index).is_some() {
+                let length = self.get_char_literal_length(&chars, index).unwrap();
+                code.push('\'');
+                (1..length - 1).for_each(|_| code.push(' '));
+                code.push('\'');
+                index += length;
+            } else {
+                code.push(c);
+                index += 1;
+            }
+        }
+        code
+    }
+
+    fn get_raw_string_hashes(&self, chars: &[char], start: usize) -> Option<usize> {
+        let hashes = chars[start.min(chars.len())..]
+            .iter()
+            .take_while(|c| **c == '#')
+            .count();
+        if chars.get(start + hashes) == Some(&'"') {
+            return Some(hashes);
+        }
+        None
+    }
+
+    fn get_char_literal_length(&self, chars: &[char], start: usize) -> Option<usize> {
+        match chars.get(start + 1) {
+            Some('\\') =>
+                chars[start + 2..]
+                    .iter()
+                    .position(|c| *c == '\'')
+                    .map(|position| position + 3),
+            Some(_) if chars.get(start + 2) == Some(&'\'') => Some(3),
+            _ => None,
+        }
+    }
+
+    // Splits stripped code into identifiers and single punctuation characters,
+    // each one paired with the line it starts on.
+    fn tokenize(&self, code: &str) -> Vec<(String, usize)> {
+        let mut tokens: Vec<(String, usize)> = vec![];
+        let mut line = 1;
+        let mut is_in_word = false;
+        for c in code.chars() {
+            if c.is_alphanumeric() || c == '_' {
+                match tokens.last_mut() {
+                    Some((word, _)) if is_in_word => word.push(c),
+                    _ => tokens.push((c.to_string(), line)),
+                }
+                is_in_word = true;
+                continue;
+            }
+            is_in_word = false;
+            if c == '\n' {
+                line += 1;
+            } else if !c.is_whitespace() {
+                tokens.push((c.to_string(), line));
+            }
+        }
+        tokens
+    }
+
+    fn is_identifier(&self, token: &str) -> bool {
+        token.starts_with(|c: char| c.is_alphabetic() || c == '_')
+    }
+
+    // Index of the token closing the group opened at `start`, or the token count
+    // when the group is never closed. The `>` of a `->` arrow closes nothing.
+    fn find_closing_token(&self, tokens: &[(String, usize)], start: usize) -> usize {
+        let (open, close) = match tokens[start].0.as_str() {
+            "(" => ("(", ")"),
+            "[" => ("[", "]"),
+            "<" => ("<", ">"),
+            _ => ("{", "}"),
+        };
+        let mut depth = 0;
+        for (index, (token, _)) in tokens.iter().enumerate().skip(start) {
+            if token == open {
+                depth += 1;
+            } else if token == close && !(close == ">" && tokens[index - 1].0 == "-") {
+                depth -= 1;
+                if depth == 0 {
+                    return index;
+                }
+            }
+        }
+        tokens.len()
+    }
+
+    fn split_top_level<'t>(&self, tokens: &'t [(String, usize)]) -> Vec<&'t [(String, usize)]> {
+        let mut segments = vec![];
+        let mut depth = 0;
+        let mut start = 0;
+        for (index, (token, _)) in tokens.iter().enumerate() {
+            match token.as_str() {
+                "(" | "[" | "{" | "<" => {
+                    depth += 1;
+                }
+                ")" | "]" | "}" => {
+                    depth -= 1;
+                }
+                ">" if index == 0 || tokens[index - 1].0 != "-" => {
+                    depth -= 1;
+                }
+                "," if depth == 0 => {
+                    segments.push(&tokens[start..index]);
+                    start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        if start < tokens.len() {
+            segments.push(&tokens[start..]);
+        }
+        segments
+    }
+
+    // Drops the attributes and visibility modifier in front of a field or variant.
+    fn strip_member_prefix<'t>(&self, tokens: &'t [(String, usize)]) -> &'t [(String, usize)] {
+        let mut index = 0;
+        loop {
+            match tokens.get(index).map(|(token, _)| token.as_str()) {
+                Some("#") if tokens.get(index + 1).is_some_and(|(token, _)| token == "[") => {
+                    index = self.find_closing_token(tokens, index + 1) + 1;
+                }
+                Some("pub") => {
+                    index += 1;
+                    if tokens.get(index).is_some_and(|(token, _)| token == "(") {
+                        index = self.find_closing_token(tokens, index) + 1;
+                    }
+                }
+                _ => {
+                    break;
+                }
+            }
+        }
+        &tokens[index.min(tokens.len())..]
+    }
+
+    fn join_tokens(&self, tokens: &[(String, usize)]) -> String {
+        let mut text = String::new();
+        let mut previous = "";
+        for (token, _) in tokens {
+            let is_after_colon = previous == ":" && token != ":" && !text.ends_with("::");
+            if
+                (self.is_identifier(token) && self.is_identifier(previous)) ||
+                previous == "," ||
+                is_after_colon
+            {
+                text.push(' ');
+            }
+            text.push_str(token);
+            previous = token;
+        }
+        text.replace("->", " -> ")
+    }
+
+    // The last path segment before any generic arguments, e.g. `Repository`
+    // for `crate::core::Repository<Order>`.
+    fn get_path_name(&self, tokens: &[(String, usize)]) -> Option<String> {
+        tokens
+            .iter()
+            .take_while(|(token, _)| token != "<")
+            .filter(|(token, _)| self.is_identifier(token) && token != "dyn")
+            .last()
+            .map(|(token, _)| token.clone())
+    }
+
+    fn find_fields(&self, tokens: &[(String, usize)], is_tuple: bool) -> Vec<TypeMember> {
+        self.split_top_level(tokens)
+            .into_iter()
+            .map(|segment| self.strip_member_prefix(segment))
+            .enumerate()
+            .filter_map(|(index, segment)| {
+                if is_tuple {
+                    return Some(TypeMember {
+                        name: index.to_string(),
+                        type_name: Some(self.join_tokens(segment)),
+                        parameters: None,
+                    });
+                }
+                match segment {
+                    [(name, _), (colon, _), type_tokens @ ..] if colon == ":" =>
+                        Some(TypeMember {
+                            name: name.clone(),
+                            type_name: Some(self.join_tokens(type_tokens)),
+                            parameters: None,
+                        }),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    fn find_variants(&self, tokens: &[(String, usize)]) -> Vec<TypeMember> {
+        self.split_top_level(tokens)
+            .into_iter()
+            .filter_map(|segment| {
+                let (name, _) = self.strip_member_prefix(segment).first()?;
+                Some(TypeMember {
+                    name: name.clone(),
+                    type_name: None,
+                    parameters: None,
+                })
+            })
+            .collect()
+    }
+
+    fn find_methods(&self, tokens: &[(String, usize)]) -> Vec<TypeMember> {
+        let mut methods = vec![];
+        let mut index = 0;
+        while index < tokens.len() {
+            match tokens[index].0.as_str() {
+                "{" => {
+                    index = self.find_closing_token(tokens, index) + 1;
+                }
+                "fn" if index + 2 < tokens.len() => {
+                    let name = tokens[index + 1].0.clone();
+                    let mut position = index + 2;
+                    if tokens[position].0 == "<" {
+                        position = self.find_closing_token(tokens, position) + 1;
+                    }
+                    if tokens.get(position).map(|(token, _)| token.as_str()) != Some("(") {
+                        index = position;
+                        continue;
+                    }
+                    let parameters_end = self.find_closing_token(tokens, position);
+                    let parameters = self
+                        .split_top_level(&tokens[position + 1..parameters_end.min(tokens.len())])
+                        .into_iter()
+                        .filter(|parameter| !parameter.iter().any(|(token, _)| token == "self"))
+                        .map(|parameter| self.join_tokens(parameter))
+                        .collect::<Vec<_>>();
+                    position = parameters_end + 1;
+                    let mut type_name = None;
+                    if
+                        tokens.get(position).is_some_and(|(token, _)| token == "-") &&
+                        tokens.get(position + 1).is_some_and(|(token, _)| token == ">")
+                    {
+                        let start = position + 2;
+                        position = start;
+                        while
+                            tokens.get(position).is_some_and(|(token, _)| {
+                                !matches!(token.as_str(), ";" | "{" | "where")
+                            })
+                        {
+                            position += 1;
+                        }
+                        type_name = Some(self.join_tokens(&tokens[start..position]));
+                    }
+                    methods.push(TypeMember {
+                        name,
+                        type_name,
+                        parameters: Some(parameters),
+                    });
+                    index = position;
+                }
+                _ => {
+                    index += 1;
+                }
+            }
+        }
+        methods
+    }
+
+    fn find_trait_implementations(&self, tokens: &[(String, usize)]) -> Vec<TraitImplementation> {
+        let mut implementations = vec![];
+        for (index, (token, line)) in tokens.iter().enumerate() {
+            if token != "impl" {
+                continue;
+            }
+            let mut position = index + 1;
+            if tokens.get(position).is_some_and(|(token, _)| token == "<") {
+                position = self.find_closing_token(tokens, position) + 1;
+            }
+            let start = position.min(tokens.len());
+            while
+                tokens.get(position).is_some_and(|(token, _)| {
+                    !matches!(token.as_str(), "{" | ";" | "where")
+                })
+            {
+                position += 1;
+            }
+            let header = &tokens[start..position];
+            let Some(for_position) = header.iter().position(|(token, _)| token == "for") else {
+                continue;
+            };
+            while
+                tokens
+                    .get(position)
+                    .is_some_and(|(token, _)| !matches!(token.as_str(), "{" | ";"))
+            {
+                position += 1;
+            }
+            let body = match tokens.get(position) {
+                Some((token, _)) if token == "{" => {
+                    position + 1..self.find_closing_token(tokens, position)
+                }
+                _ => position..position,
+            };
+            let trait_name = self.get_path_name(&header[..for_position]);
+            let type_name = self.get_path_name(&header[for_position + 1..]);
+            if let (Some(trait_name), Some(type_name)) = (trait_name, type_name) {
+                implementations.push(TraitImplementation {
+                    trait_name,
+                    type_name,
+                    line: *line,
+                    body,
+                });
+            }
+        }
+        implementations
+    }
+
+    // Body of the method named `method_name` within the given token range.
+    fn find_method_body(
+        &self,
+        tokens: &[(String, usize)],
+        range: &Range<usize>,
+        method_name: &str
+    ) -> Option<Range<usize>> {
+        let position = (range.start..range.end.saturating_sub(1)).find(|index| {
+            tokens[*index].0 == "fn" && tokens[*index + 1].0 == method_name
+        })?;
+        let opening = (position..range.end).find(|index| tokens[*index].0 == "{")?;
+        Some(opening + 1..self.find_closing_token(tokens, opening))
+    }
+
+    // The contents of every string literal in the file, in order of appearance.
+    // Literal contents are blanked in the stripped code, so they are read back
+    // from the original content at the same positions.
+    fn find_string_literals(&self, content: &str, code: &str) -> Vec<String> {
+        let content_chars = content.chars().collect::<Vec<_>>();
+        let quote_positions = code
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| *c == '"')
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        quote_positions
+            .chunks(2)
+            .filter(|quotes| quotes.len() == 2)
+            .map(|quotes| content_chars[quotes[0] + 1..quotes[1]].iter().collect())
+            .collect()
+    }
+
+    // Resolves the name returned by a `get_name()` or `subscribed_to()` body:
+    // either a string literal or a constant initialized with one.
+    fn find_returned_name(
+        &self,
+        tokens: &[(String, usize)],
+        body: &Range<usize>,
+        literals: &[String]
+    ) -> Option<String> {
+        let get_literal = |index: usize| {
+            let quote_count = tokens[..index]
+                .iter()
+                .filter(|(token, _)| token == "\"")
+                .count();
+            literals.get(quote_count / 2).cloned()
+        };
+        if let Some(index) = body.clone().find(|index| tokens[*index].0 == "\"") {
+            return get_literal(index);
+        }
+        let constants = tokens
+            .iter()
+            .enumerate()
+            .filter(|(index, (token, _))| token == "const" && index + 1 < tokens.len())
+            .filter_map(|(index, _)| {
+                let value_index = (index..tokens.len())
+                    .take_while(|position| tokens[*position].0 != ";")
+                    .find(|position| tokens[*position].0 == "\"")?;
+                Some((tokens[index + 1].0.clone(), get_literal(value_index)?))
+            })
+            .collect::<HashMap<_, _>>();
+        tokens[body.clone()].iter().find_map(|(token, _)| constants.get(token).cloned())
+    }
+
+    fn get_module_path(&self, file_path: &str) -> Vec<String> {
+        let mut module_path = file_path
+            .trim_end_matches(".rs")
+            .split('/')
+            .skip(1)
+            .map(|segment| segment.to_string())
+            .collect::<Vec<_>>();
+        if module_path.last().is_some_and(|segment| segment == "mod") {
+            module_path.pop();
+        }
+        module_path
+    }
+
+    fn expand_use_tree(&self, tree: &[char], index: &mut usize) -> Vec<Vec<String>> {
+        let mut prefix: Vec<String> = vec![];
+        loop {
+            while *index < tree.len() && tree[*index].is_whitespace() {
+                *index += 1;
+            }
+            match tree.get(*index) {
+                Some('{') => {
+                    *index += 1;
+                    let mut paths = vec![];
+                    loop {
+                        for path in self.expand_use_tree(tree, index) {
+                            let mut full_path = prefix.clone();
+                            full_path.extend(path.into_iter().skip_while(|s| s == "self"));
+                            paths.push(full_path);
+                        }
+                        while *index < tree.len() && tree[*index].is_whitespace() {
+                            *index += 1;
+                        }
+                        match tree.get(*index) {
+                            Some(',') => {
+                                *index += 1;
+                            }
+                            Some('}') => {
+                                *index += 1;
+                                break;
+                            }
+                            _ => {
+                                break;
+                            }
+                        }
+                        while *index < tree.len() && tree[*index].is_whitespace() {
+                            *index += 1;
+                        }
+                        if tree.get(*index) == Some(&'}') {
+                            *index += 1;
+                            break;
+                        }
+                    }
+                    return paths;
+                }
+                Some('*') => {
+                    *index += 1;
+                    return vec![prefix];
+                }
+                Some(':') => {
+                    *index += 2;
+                }
+                Some(c) if c.is_alphanumeric() || *c == '_' => {
+                    let start = *index;
+                    while *index < tree.len() && (tree[*index].is_alphanumeric() || tree[*index] == '_') {
+                        *index += 1;
+                    }
+                    prefix.push(tree[start..*index].iter().collect());
+                    while *index < tree.len() && tree[*index].is_whitespace() {
+                        *index += 1;
+                    }
+                    if tree.get(*index) == Some(&':') {
+                        continue;
+                    }
+                    if tree[*index..].starts_with(&['a', 's']) {
+                        *index += 2;
+                        while *index < tree.len() && tree[*index].is_whitespace() {
+                            *index += 1;
+                        }
+                        while
+                            *index < tree.len() &&
+                            (tree[*index].is_alphanumeric() || tree[*index] == '_')
+                        {
+                            *index += 1;
+                        }
+                    }
+                    return vec![prefix];
+                }
+                _ => {
+                    return if prefix.is_empty() { vec![] } else { vec![prefix] };
+                }
+            }
+        }
+    }
+
+    fn resolve_path(&self, module_path: &[String], path: Vec<String>) -> Option<Vec<String>> {
+        let mut segments = path.into_iter().peekable();
+        let mut resolved_path = match segments.peek().map(|s| s.as_str()) {
+            Some("crate") => {
+                segments.next();
+                vec![]
+            }
+            Some("self") => {
+                segments.next();
+                module_path.to_vec()
+            }
+            Some("super") => module_path.to_vec(),
+            _ => {
+                return None;
+            }
+        };
+        while segments.peek().is_some_and(|s| s == "super") {
+            segments.next();
+            resolved_path.pop()?;
+        }
+        resolved_path.extend(segments);
+        Some(resolved_path)
+    }
+}
+
+impl SourceAnalyzer for LexicalSourceAnalyzer {
+    fn find_public_items(&self, source_file: &SourceFile) -> Vec<SourceItem> {
+        let code = self.strip_comments_and_literals(&source_file.get_value().content);
+        let tokens = self.tokenize(&code);
+        let get_token = |index: usize| tokens.get(index).map_or("", |(token, _)| token.as_str());
+        let mut items = vec![];
+        for (index, (token, line)) in tokens.iter().enumerate() {
+            if token != "pub" {
+                continue;
+            }
+            let mut item_index = index + 1;
+            if get_token(item_index) == "(" {
+                while !matches!(get_token(item_index), ")" | "") {
+                    item_index += 1;
+                }
+                item_index += 1;
+            }
+            while
+                matches!(get_token(item_index), "async" | "unsafe" | "extern" | "\"") ||
+                (get_token(item_index) == "const" &&
+                    matches!(get_token(item_index + 1), "fn" | "async" | "unsafe" | "extern"))
+            {
+                item_index += 1;
+            }
+            let Some(kind) = SourceItemKind::from_keyword(get_token(item_index)) else {
+                continue;
+            };
+            let name = get_token(item_index + 1);
+            if name.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+                items.push(
+                    SourceItem::new(SourceItemValue {
+                        kind,
+                        name: name.to_string(),
+                        line: *line,
+                    })
+                );
+            }
+        }
+        items
+    }
+
+    fn find_raised_events(&self, source_file: &SourceFile) -> Vec<RaisedEvent> {
+        let code = self.strip_comments_and_literals(&source_file.get_value().content);
+        let tokens = self.tokenize(&code);
+        let mut raised_events = vec![];
+        for (index, (token, line)) in tokens.iter().enumerate() {
+            let is_call =
+                token == "add_domain_event" &&
+                tokens.get(index + 1).is_some_and(|(token, _)| token == "(") &&
+                (index == 0 || tokens[index - 1].0 != "fn");
+            if !is_call {
+                continue;
+            }
+            let mut depth = 0;
+            let event_name = tokens[index + 1..]
+                .iter()
+                .take_while(|(token, _)| {
+                    match token.as_str() {
+                        "(" => depth += 1,
+                        ")" => depth -= 1,
+                        _ => {}
+                    }
+                    depth > 0
+                })
+                .map(|(token, _)| token)
+                .find(|token| {
+                    token.starts_with(|c: char| c.is_uppercase()) &&
+                        !matches!(token.as_str(), "Box" | "Arc" | "Rc" | "Some")
+                });
+            if let Some(event_name) = event_name {
+                raised_events.push(
+                    RaisedEvent::new(RaisedEventValue {
+                        event_name: event_name.to_string(),
+                        line: *line,
+                    })
+                );
+            }
+        }
+        raised_events
+    }
+
+    fn find_type_definitions(&self, source_file: &SourceFile) -> Vec<TypeDefinition> {
+        let code = self.strip_comments_and_literals(&source_file.get_value().content);
+        let mut tokens = self.tokenize(&code);
+        let test_module_position = tokens.windows(6).position(|window| {
+            window
+                .iter()
+                .map(|(token, _)| token.as_str())
+                .eq(["#", "[", "cfg", "(", "test", ")"])
+        });
+        if let Some(test_module_position) = test_module_position {
+            tokens.truncate(test_module_position);
+        }
+        let mut definitions: Vec<TypeDefinitionValue> = vec![];
+        let mut index = 0;
+        while index < tokens.len() {
+            let token = &tokens[index].0;
+            let kind = match token.as_str() {
+                "struct" | "enum" | "trait" => SourceItemKind::from_keyword(token),
+                _ => None,
+            };
+            let name = tokens
+                .get(index + 1)
+                .map(|(name, _)| name.clone())
+                .filter(|name| self.is_identifier(name));
+            let (Some(kind), Some(name)) = (kind, name) else {
+                index += 1;
+                continue;
+            };
+            let mut body = index + 2;
+            while
+                tokens.get(body).is_some_and(|(token, _)| {
+                    !matches!(token.as_str(), "{" | "(" | ";")
+                })
+            {
+                if tokens[body].0 == "<" {
+                    body = self.find_closing_token(&tokens, body);
+                }
+                body += 1;
+            }
+            let opening = tokens.get(body).map_or("", |(token, _)| token.as_str());
+            let end = match opening {
+                "{" | "(" => self.find_closing_token(&tokens, body),
+                _ => body,
+            };
+            let inner = &tokens[(body + 1).min(end)..end];
+            let members = match (&kind, opening) {
+                (SourceItemKind::Struct, "{") => self.find_fields(inner, false),
+                (SourceItemKind::Struct, "(") => self.find_fields(inner, true),
+                (SourceItemKind::Enum, "{") => self.find_variants(inner),
+                (SourceItemKind::Trait, "{") => self.find_methods(inner),
+                _ => vec![],
+            };
+            definitions.push(TypeDefinitionValue {
+                kind,
+                name,
+                members,
+                implemented_traits: vec![],
+            });
+            index = end + 1;
+        }
+        for implementation in self.find_trait_implementations(&tokens) {
+            let definition = definitions
+                .iter_mut()
+                .find(|definition| definition.name == implementation.type_name);
+            if let Some(definition) = definition {
+                if !definition.implemented_traits.contains(&implementation.trait_name) {
+                    definition.implemented_traits.push(implementation.trait_name);
+                }
+            }
+        }
+        definitions.into_iter().map(TypeDefinition::new).collect()
+    }
+
+    fn find_domain_events(&self, source_file: &SourceFile) -> Vec<DomainEventDeclaration> {
+        let content = &source_file.get_value().content;
+        let code = self.strip_comments_and_literals(content);
+        let tokens = self.tokenize(&code);
+        let literals = self.find_string_literals(content, &code);
+        self.find_trait_implementations(&tokens)
+            .into_iter()
+            .filter(|implementation| implementation.trait_name == "DomainEvent")
+            .map(|implementation| {
+                let event_name = self
+                    .find_method_body(&tokens, &implementation.body, "get_name")
+                    .and_then(|body| self.find_returned_name(&tokens, &body, &literals))
+                    .unwrap_or_else(|| implementation.type_name.clone());
+                DomainEventDeclaration::new(DomainEventDeclarationValue {
+                    type_name: implementation.type_name,
+                    event_name,
+                    line: implementation.line,
+                })
+            })
+            .collect()
+    }
+
+    fn find_event_subscriptions(&self, source_file: &SourceFile) -> Vec<EventSubscription> {
+        let content = &source_file.get_value().content;
+        let code = self.strip_comments_and_literals(content);
+        let tokens = self.tokenize(&code);
+        let literals = self.find_string_literals(content, &code);
+        self.find_trait_implementations(&tokens)
+            .into_iter()
+            .filter(|implementation| implementation.trait_name == "DomainEventSubscriber")
+            .map(|implementation| {
+                let body = self.find_method_body(&tokens, &implementation.body, "subscribed_to");
+                let referenced_types = body
+                    .clone()
+                    .map(|body| {
+                        tokens[body]
+                            .iter()
+                            .map(|(token, _)| token.clone())
+                            .filter(|token| {
+                                token.starts_with(|c: char| c.is_uppercase()) &&
+                                    token.contains(|c: char| c.is_lowercase()) &&
+                                    !matches!(token.as_str(), "Self" | "String")
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                EventSubscription::new(EventSubscriptionValue {
+                    subscriber_name: implementation.type_name,
+                    event_name: body.and_then(|body| {
+                        self.find_returned_name(&tokens, &body, &literals)
+                    }),
+                    referenced_types,
+                    line: implementation.line,
+                })
+            })
+            .collect()
+    }
+
+    fn find_stub_markers(&self, source_file: &SourceFile) -> Vec<StubMarker> {
+        let code = self.strip_comments_and_literals(&source_file.get_value().content);
+        let tokens = self.tokenize(&code);
+        tokens
+            .windows(2)
+            .filter(|window| {
+                matches!(window[0].0.as_str(), "todo" | "unimplemented") && window[1].0 == "!"
+            })
+            .map(|window| {
+                StubMarker::new(StubMarkerValue {
+                    macro_name: format!("{}!", window[0].0),
+                    line: window[0].1,
+                })
+            })
+            .collect()
+    }
+
+    fn is_blank(&self, source_file: &SourceFile) -> bool {
+        self.strip_comments_and_literals(&source_file.get_value().content).trim().is_empty()
+    }
+
+    fn has_tests(&self, source_file: &SourceFile) -> bool {
+        let code = self.strip_comments_and_literals(&source_file.get_value().content);
+        let tokens = self.tokenize(&code);
+        tokens
+            .iter()
+            .enumerate()
+            .filter(|(index, (token, _))| {
+                token == "#" && tokens.get(index + 1).is_some_and(|(token, _)| token == "[")
+            })
+            .any(|(index, _)| {
+                tokens[index + 2..]
+                    .iter()
+                    .take_while(|(token, _)| token != "]")
+                    .any(|(token, _)| token == "test")
+            })
+    }
+
+    fn find_imports(&self, source_file: &SourceFile) -> Vec<SourceImport> {
+        let code = self.strip_comments_and_literals(&source_file.get_value().content);
+        let module_path = self.get_module_path(&source_file.get_value().path);
+        let chars = code.chars().collect::<Vec<_>>();
+        let mut imports = vec![];
+        let mut index = 0;
+        while index + 3 < chars.len() {
+            let is_use_keyword =
+                chars[index..index + 3] == ['u', 's', 'e'] &&
+                chars[index + 3].is_whitespace() &&
+                (index == 0 || matches!(chars[index - 1], ' ' | '\n' | '\t' | ';' | '{' | '}' | ')'));
+            if !is_use_keyword {
+                index += 1;
+                continue;
+            }
+            let line = chars[..index].iter().filter(|c| **c == '\n').count() + 1;
+            let end = chars[index..]
+                .iter()
+                .position(|c| *c == ';')
+                .map_or(chars.len(), |position| index + position);
+            let tree = &chars[index + 3..end];
+            let statement = format!(
+                "use {};",
+                tree.iter().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ")
+            );
+            let mut tree_index = 0;
+            for path in self.expand_use_tree(tree, &mut tree_index) {
+                if let Some(resolved_path) = self.resolve_path(&module_path, path) {
+                    imports.push(
+                        SourceImport::new(SourceImportValue {
+                            path: resolved_path,
+                            line,
+                            statement: statement.clone(),
+                        })
+                    );
+                }
+            }
+            index = end;
+        }
+        imports
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::architecture::{
+            domain::{
+                services::source_analyzer::SourceAnalyzer,
+                value_objects::source_file::{ SourceFile, SourceFileValue },
+            },
+            infrastructure::services::lexical_source_analyzer::LexicalSourceAnalyzer,
+        },
+        cli::bounded_context::domain::enums::{
+            component_type::ComponentType,
+            layer_name::LayerName,
+        },
+        core::domain::models::value_object::ValueObject,
+    };
+
+    fn source_file(content: &str) -> SourceFile {
+        SourceFile::new(SourceFileValue {
+            path: "src/sales/order/domain/entities/order.rs".to_string(),
+            bounded_context_name: "sales".to_string(),
+            aggregate_name: Some("order".to_string()),
+            layer_name: Some(LayerName::Domain),
+            component_type: Some(ComponentType::Entities),
+            content: content.to_string(),
+        })
+    }
+
+    #[test]
+    fn should_expand_grouped_and_relative_imports() {
+        let content =
+            "use std::fmt;\n\
+            use crate::{\n    sales::customer::domain::{ self, entities::customer::Customer as C },\n    billing::*,\n};\n\
+            pub(crate) use super::line::Line;\n";
+        let imports = LexicalSourceAnalyzer.find_imports(&source_file(content))
+            .iter()
+            .map(|import| (import.get_value().path.join("::"), import.get_value().line))
+            .collect::<Vec<_>>();
+        assert_eq!(imports, vec![
+            ("sales::customer::domain".to_string(), 2),
+            ("sales::customer::domain::entities::customer::Customer".to_string(), 2),
+            ("billing".to_string(), 2),
+            ("sales::order::domain::entities::line::Line".to_string(), 6)
+        ]);
+    }
+
+    #[test]
+    fn should_ignore_imports_inside_comments_and_literals() {
+        let content =
+            "// use crate::billing::invoice;\n\
+            /* use crate::billing::invoice; */\n\
+            const TEMPLATE: &str = \"use crate::billing::invoice;\";\n\
+            const RAW: &str = r#\"use crate::billing::invoice;\"#;\n\
+            fn get<'a>(c: char) -> bool { c == '\"' }\n\
+            use crate::sales::customer;\n";
+        let imports = LexicalSourceAnalyzer.find_imports(&source_file(content));
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].get_value().path.join("::"), "sales::customer");
+        assert_eq!(imports[0].get_value().line, 6);
+    }
+
+    #[test]
+    fn should_find_public_items_and_raised_events() {
+        let content =
+            "pub struct Order { id: String }\n\
+            pub(crate) enum Status { Open }\n\
+            struct Hidden;\n\
+            impl Order {\n    pub async fn place(&mut self) {\n\
+            self.add_domain_event(Box::new(OrderPlaced::new(self.id.clone())));\n    }\n}\n\
+            impl AggregateRoot for Order {\n\
+            fn add_domain_event(&mut self, event: Box<dyn DomainEvent>) {}\n}\n\
+            // pub fn commented_out() {}\n";
+        let items = LexicalSourceAnalyzer.find_public_items(&source_file(content))
+            .iter()
+            .map(|item| format!("{} {}", item.get_value().kind, item.get_value().name))
+            .collect::<Vec<_>>();
+        assert_eq!(items, vec!["struct Order", "enum Status", "fn place"]);
+        let raised_events = LexicalSourceAnalyzer.find_raised_events(&source_file(content))
+            .iter()
+            .map(|event| (event.get_value().event_name.clone(), event.get_value().line))
+            .collect::<Vec<_>>();
+        assert_eq!(raised_events, vec![("OrderPlaced".to_string(), 6)]);
+    }
+
+    #[test]
+    fn should_find_type_definitions_with_members_and_implemented_traits() {
+        let content =
+            "#[derive(Clone)]\n\
+            pub struct Order<'a> {\n    #[allow(dead_code)]\n    pub(crate) id: OrderId,\n\
+            lines: Vec<OrderLine>,\n    notes: HashMap<String, &'a str>,\n}\n\
+            pub struct OrderId(String);\n\
+            pub enum Status { Open, Closed(String), Cancelled { reason: String } }\n\
+            #[async_trait]\n\
+            pub trait OrderRepository: Send + Sync {\n\
+            async fn find(&self, id: &OrderId) -> Result<Option<Order>, Error>;\n\
+            fn count(&self) -> usize { 0 }\n}\n\
+            impl<'a> AggregateRoot for Order<'a> {}\n\
+            #[cfg(test)]\nmod tests { struct Fixture; }\n";
+        let definitions = LexicalSourceAnalyzer.find_type_definitions(&source_file(content))
+            .iter()
+            .map(|definition| {
+                let value = definition.get_value();
+                let members = value.members
+                    .iter()
+                    .map(|member| {
+                        match (&member.parameters, &member.type_name) {
+                            (Some(parameters), type_name) =>
+                                format!(
+                                    "{}({}): {}",
+                                    member.name,
+                                    parameters.join(", "),
+                                    type_name.clone().unwrap_or_default()
+                                ),
+                            (None, Some(type_name)) => format!("{}: {}", member.name, type_name),
+                            (None, None) => member.name.clone(),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                format!(
+                    "{} {} [{}] <{}>",
+                    value.kind,
+                    value.name,
+                    members.join("; "),
+                    value.implemented_traits.join(", ")
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(definitions, vec![
+            "struct Order [id: OrderId; lines: Vec<OrderLine>; notes: HashMap<String, &'a str>] \
+            <AggregateRoot>",
+            "struct OrderId [0: String] <>",
+            "enum Status [Open; Closed; Cancelled] <>",
+            "trait OrderRepository [find(id: &OrderId): Result<Option<Order>, Error>; \
+            count(): usize] <>"
+        ]);
+    }
+
+    #[test]
+    fn should_find_domain_events_and_event_subscriptions() {
+        let content =
+            "const ORDER_SHIPPED: &str = \"sales.order_shipped\";\n\
+            impl DomainEvent for OrderPlaced {\n\
+            fn get_name(&self) -> String { \"sales.order_placed\".to_string() }\n}\n\
+            impl DomainEvent for OrderShipped {\n\
+            fn get_name(&self) -> String { ORDER_SHIPPED.to_string() }\n}\n\
+            impl DomainEvent for OrderCancelled {}\n\
+            #[async_trait]\n\
+            impl DomainEventSubscriber for SendReceipt {\n\
+            fn subscribed_to(&self) -> String { \"billing.invoice_paid\".to_string() }\n}\n\
+            impl DomainEventSubscriber for NotifyCustomer {\n\
+            fn subscribed_to(&self) -> String { OrderPlaced::NAME.to_string() }\n}\n";
+        let domain_events = LexicalSourceAnalyzer.find_domain_events(&source_file(content))
+            .iter()
+            .map(|event| {
+                let value = event.get_value();
+                format!("{} {} {}", value.type_name, value.event_name, value.line)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(domain_events, vec![
+            "OrderPlaced sales.order_placed 2",
+            "OrderShipped sales.order_shipped 5",
+            "OrderCancelled OrderCancelled 8"
+        ]);
+        let subscriptions = LexicalSourceAnalyzer.find_event_subscriptions(&source_file(content))
+            .iter()
+            .map(|subscription| {
+                let value = subscription.get_value();
+                format!(
+                    "{} {:?} {:?}",
+                    value.subscriber_name,
+                    value.event_name,
+                    value.referenced_types
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(subscriptions, vec![
+            "SendReceipt Some(\"billing.invoice_paid\") []",
+            "NotifyCustomer None [\"OrderPlaced\"]"
+        ]);
+    }
+
+    #[test]
+    fn should_detect_stubs_blank_files_and_tests() {
+        let content =
+            "// TODO: todo!() in a comment\n\
+            fn place() { todo!() }\n\
+            fn cancel() { unimplemented!(\"soon\") }\n\
+            #[cfg(test)]\nmod tests {}\n";
+        let stub_markers = LexicalSourceAnalyzer.find_stub_markers(&source_file(content))
+            .iter()
+            .map(|marker| format!("{}:{}", marker.get_value().macro_name, marker.get_value().line))
+            .collect::<Vec<_>>();
+        assert_eq!(stub_markers, vec!["todo!:2", "unimplemented!:3"]);
+        assert!(LexicalSourceAnalyzer.has_tests(&source_file(content)));
+        assert!(!LexicalSourceAnalyzer.has_tests(&source_file("#[cfg(feature = \"test\")]\n")));
+        assert!(LexicalSourceAnalyzer.is_blank(&source_file("// nothing yet\n\n")));
+    }
+}
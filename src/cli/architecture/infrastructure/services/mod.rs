@@ -0,0 +1 @@
+pub mod lexical_source_analyzer;
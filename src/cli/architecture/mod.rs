@@ -0,0 +1,4 @@
+pub mod adapters;
+pub mod application;
+pub mod domain;
+pub mod infrastructure;
@@ -0,0 +1,26 @@
+use anyhow::Result;
+use crate::{
+    cli::bounded_context::{
+        adapters::controllers::import_model_controller::ImportModelController,
+        application::queries::diff_model_use_case::DiffModelRequestModel,
+    },
+    core::application::use_case_input_port::UseCaseInputPort,
+};
+
+pub struct DiffModelController<'a> {
+    use_case: &'a dyn UseCaseInputPort<DiffModelRequestModel>,
+}
+
+impl<'a> DiffModelController<'a> {
+    pub fn new(use_case: &'a dyn UseCaseInputPort<DiffModelRequestModel>) -> Self {
+        Self { use_case }
+    }
+
+    pub async fn diff_model(&self, content: &str) -> Result<()> {
+        let request_model = ImportModelController::parse_model(content)?;
+        self.use_case.interact(DiffModelRequestModel {
+            bounded_contexts: request_model.bounded_contexts,
+        }).await;
+        Ok(())
+    }
+}
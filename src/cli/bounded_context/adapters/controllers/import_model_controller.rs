@@ -0,0 +1,116 @@
+use std::str::FromStr;
+use anyhow::{ Context, Result };
+use serde::Deserialize;
+use crate::{
+    cli::bounded_context::{
+        application::commands::import_model_use_case::{
+            ImportAggregateRequestModel,
+            ImportBoundedContextRequestModel,
+            ImportComponentRequestModel,
+            ImportLayerRequestModel,
+            ImportModelRequestModel,
+        },
+        domain::enums::{ component_type::ComponentType, layer_name::LayerName },
+    },
+    core::application::use_case_input_port::UseCaseInputPort,
+};
+
+#[derive(Deserialize)]
+struct ComponentDocument {
+    #[serde(rename = "type")]
+    component_type: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct LayerDocument {
+    name: String,
+    #[serde(default)]
+    components: Vec<ComponentDocument>,
+}
+
+#[derive(Deserialize)]
+struct AggregateDocument {
+    name: String,
+    #[serde(default)]
+    layers: Vec<LayerDocument>,
+}
+
+#[derive(Deserialize)]
+struct BoundedContextDocument {
+    name: String,
+    #[serde(default)]
+    aggregates: Vec<AggregateDocument>,
+}
+
+#[derive(Deserialize)]
+struct ModelDocument {
+    bounded_contexts: Vec<BoundedContextDocument>,
+}
+
+pub struct ImportModelController<'a> {
+    use_case: &'a dyn UseCaseInputPort<ImportModelRequestModel>,
+}
+
+impl<'a> ImportModelController<'a> {
+    pub fn new(use_case: &'a dyn UseCaseInputPort<ImportModelRequestModel>) -> Self {
+        Self { use_case }
+    }
+
+    pub async fn import_model(&self, content: &str) -> Result<()> {
+        let request_model = Self::parse_model(content)?;
+        self.use_case.interact(request_model).await;
+        Ok(())
+    }
+
+    pub fn parse_model(content: &str) -> Result<ImportModelRequestModel> {
+        let document: ModelDocument = serde_json
+            ::from_str(content)
+            .context("Invalid model document")?;
+        let bounded_contexts = document.bounded_contexts
+            .into_iter()
+            .map(|bounded_context| {
+                Ok(ImportBoundedContextRequestModel {
+                    name: bounded_context.name,
+                    aggregates: bounded_context.aggregates
+                        .into_iter()
+                        .map(|aggregate| {
+                            Ok(ImportAggregateRequestModel {
+                                name: aggregate.name,
+                                layers: aggregate.layers
+                                    .into_iter()
+                                    .map(Self::parse_layer)
+                                    .collect::<Result<Vec<_>>>()?,
+                            })
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ImportModelRequestModel { bounded_contexts })
+    }
+
+    fn parse_layer(layer: LayerDocument) -> Result<ImportLayerRequestModel> {
+        Ok(ImportLayerRequestModel {
+            layer_name: LayerName::from_str(&layer.name).with_context(||
+                format!("Invalid layer <{}> in model document", layer.name)
+            )?,
+            components: layer.components
+                .into_iter()
+                .map(|component| {
+                    Ok(ImportComponentRequestModel {
+                        component_type: ComponentType::from_str(
+                            &component.component_type
+                        ).with_context(||
+                            format!(
+                                "Invalid component type <{}> in model document",
+                                component.component_type
+                            )
+                        )?,
+                        component_name: component.name,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod diff_model_controller;
+pub mod import_model_controller;
@@ -1 +1,2 @@
+pub mod controllers;
 pub mod presenters;
@@ -8,7 +8,11 @@ pub struct AddAggregatePresenter;
 #[async_trait]
 impl UseCaseOutputPort<AddAggregateResponseModel> for AddAggregatePresenter {
     async fn success(&self, response_model: AddAggregateResponseModel) {
-        println!("Aggregate <{}> added successfully.", response_model.aggregate_name)
+        response_model.warnings.iter().for_each(|warning| println!("Warning: {}", warning));
+        println!("Aggregate <{}> added successfully.", response_model.aggregate_name);
+        response_model.generated_components
+            .iter()
+            .for_each(|component| println!("  Generated {}", component))
     }
 
     async fn failure(&self, error: Box<dyn Error + Send>) {
@@ -2,7 +2,10 @@ use std::error::Error;
 use async_trait::async_trait;
 use crate::core::application::use_case_output_port::UseCaseOutputPort;
 use crate::cli::bounded_context::{
-    domain::enums::component_type::ComponentType,
+    domain::{
+        entities::bounded_context::{ BoundedContext, BoundedContextError },
+        enums::component_type::ComponentType,
+    },
     application::commands::add_component_use_case::AddComponentResponseModel,
 };
 
@@ -25,19 +28,56 @@ impl AddComponentPresenter {
             }
         ).to_string()
     }
+
+    fn get_suggestion(&self, error: &BoundedContextError) -> String {
+        let BoundedContextError::InvalidLayerComponent { component_type, component_name, .. } =
+            error;
+        let layer_names = BoundedContext::get_component_layer_names(component_type);
+        let command = format!(
+            "arpeggio add {} {}",
+            component_type.get_singular_name().replace('_', "-"),
+            component_name
+        );
+        let commands = if layer_names.len() > 1 {
+            layer_names
+                .iter()
+                .map(|layer_name| format!("`{} --layer {}`", command, layer_name))
+                .collect::<Vec<_>>()
+                .join(" or ")
+        } else {
+            format!("`{}`", command)
+        };
+        format!(
+            "{} belong to {}; did you mean {}? (run `arpeggio explain layers` to see the layer matrix)",
+            component_type,
+            layer_names
+                .iter()
+                .map(|layer_name| layer_name.to_string())
+                .collect::<Vec<_>>()
+                .join(" or "),
+            commands
+        )
+    }
 }
 
 #[async_trait]
 impl UseCaseOutputPort<AddComponentResponseModel> for AddComponentPresenter {
     async fn success(&self, response_model: AddComponentResponseModel) {
+        response_model.warnings.iter().for_each(|warning| println!("Warning: {}", warning));
         println!(
             "{} <{}> added successfully.",
             self.get_component_type(&response_model.component_type),
             response_model.component_name
-        )
+        );
+        response_model.generated_components
+            .iter()
+            .for_each(|component| println!("  Generated {}", component))
     }
 
     async fn failure(&self, error: Box<dyn Error + Send>) {
-        eprintln!("Failed to add component due to: {}", error)
+        eprintln!("Failed to add component due to: {}", error);
+        if let Some(error) = error.downcast_ref::<BoundedContextError>() {
+            eprintln!("Hint: {}", self.get_suggestion(error));
+        }
     }
 }
@@ -0,0 +1,20 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::bounded_context::application::commands::add_crud_use_case::AddCrudResponseModel;
+
+pub struct AddCrudPresenter;
+
+#[async_trait]
+impl UseCaseOutputPort<AddCrudResponseModel> for AddCrudPresenter {
+    async fn success(&self, response_model: AddCrudResponseModel) {
+        println!("CRUD for <{}> added successfully.", response_model.aggregate_name);
+        response_model.generated_components
+            .iter()
+            .for_each(|component| println!("  Generated {}", component))
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to add CRUD due to: {}", error)
+    }
+}
@@ -0,0 +1,49 @@
+use std::{ error::Error, sync::atomic::{ AtomicBool, Ordering } };
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::bounded_context::application::queries::diff_model_use_case::{
+    DiffModelResponseModel,
+    DriftItemReadModel,
+};
+
+#[derive(Default)]
+pub struct DiffModelPresenter {
+    has_failed: AtomicBool,
+}
+
+impl DiffModelPresenter {
+    pub fn has_failed(&self) -> bool {
+        self.has_failed.load(Ordering::SeqCst)
+    }
+
+    fn print_items(&self, title: &str, sign: char, items: &[DriftItemReadModel]) {
+        if items.is_empty() {
+            return;
+        }
+        println!("{} ({}):", title, items.len());
+        items.iter().for_each(|item| {
+            println!("  {} {} <{}>", sign, item.kind, item.path);
+        });
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<DiffModelResponseModel> for DiffModelPresenter {
+    async fn success(&self, response_model: DiffModelResponseModel) {
+        if !response_model.has_drift() {
+            println!("Project matches the spec, no drift found.");
+            return;
+        }
+        self.has_failed.store(true, Ordering::SeqCst);
+        self.print_items("In the spec but missing on disk", '+', &response_model.missing_items);
+        self.print_items("On disk but not in the spec", '-', &response_model.unexpected_items);
+        if !response_model.missing_items.is_empty() {
+            println!("Run `arpeggio sync` to create the missing items.");
+        }
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        self.has_failed.store(true, Ordering::SeqCst);
+        eprintln!("Failed to diff project against the spec due to: {}", error)
+    }
+}
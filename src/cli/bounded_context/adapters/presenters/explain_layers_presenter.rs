@@ -0,0 +1,29 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::bounded_context::application::queries::explain_layers_use_case::ExplainLayersResponseModel;
+
+pub struct ExplainLayersPresenter;
+
+#[async_trait]
+impl UseCaseOutputPort<ExplainLayersResponseModel> for ExplainLayersPresenter {
+    async fn success(&self, response_model: ExplainLayersResponseModel) {
+        response_model.layers.iter().for_each(|layer| {
+            println!("{}", layer.layer_name);
+            layer.component_types.iter().for_each(|component_type| {
+                let mut command = format!(
+                    "arpeggio add {} <NAME>",
+                    component_type.component_type.get_singular_name().replace('_', "-")
+                );
+                if component_type.layer_names.len() > 1 {
+                    command.push_str(&format!(" --layer {}", layer.layer_name));
+                }
+                println!("  {:<16}{}", component_type.component_type.to_string(), command);
+            });
+        });
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to explain layers due to: {}", error)
+    }
+}
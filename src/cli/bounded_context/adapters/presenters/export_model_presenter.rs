@@ -0,0 +1,34 @@
+use std::error::Error;
+use async_trait::async_trait;
+use serde_json::json;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::bounded_context::application::queries::export_model_use_case::ExportModelResponseModel;
+
+pub struct ExportModelPresenter;
+
+#[async_trait]
+impl UseCaseOutputPort<ExportModelResponseModel> for ExportModelPresenter {
+    async fn success(&self, response_model: ExportModelResponseModel) {
+        let model =
+            json!({
+            "bounded_contexts": response_model.bounded_contexts.iter().map(|bounded_context| json!({
+                "name": bounded_context.name,
+                "aggregates": bounded_context.aggregates.iter().map(|aggregate| json!({
+                    "name": aggregate.name,
+                    "layers": aggregate.layers.iter().map(|layer| json!({
+                        "name": layer.layer_name.to_string(),
+                        "components": layer.components.iter().map(|component| json!({
+                            "type": component.component_type.to_string(),
+                            "name": component.component_name,
+                        })).collect::<Vec<_>>(),
+                    })).collect::<Vec<_>>(),
+                })).collect::<Vec<_>>(),
+            })).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&model).unwrap_or_default());
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to export model due to: {}", error)
+    }
+}
@@ -0,0 +1,51 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::bounded_context::application::queries::find_components_use_case::FindComponentsResponseModel;
+
+pub struct FindComponentsPresenter;
+
+#[async_trait]
+impl UseCaseOutputPort<FindComponentsResponseModel> for FindComponentsPresenter {
+    async fn success(&self, response_model: FindComponentsResponseModel) {
+        if response_model.components.is_empty() {
+            println!("No components found.");
+            return;
+        }
+        let mut rows = vec![
+            ["CONTEXT", "AGGREGATE", "LAYER", "TYPE", "NAME"].map(|header| header.to_string())
+        ];
+        rows.extend(
+            response_model.components.iter().map(|component| {
+                [
+                    component.bounded_context_name.clone(),
+                    component.aggregate_name.clone(),
+                    component.layer_name.to_string(),
+                    component.component_type.to_string(),
+                    component.component_name.clone(),
+                ]
+            })
+        );
+        let widths = (0..5)
+            .map(|column| {
+                rows.iter()
+                    .map(|row| row[column].len())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+        rows.iter().for_each(|row| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            println!("{}", line.trim_end());
+        });
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to find components due to: {}", error)
+    }
+}
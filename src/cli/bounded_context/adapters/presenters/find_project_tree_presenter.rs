@@ -0,0 +1,70 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::bounded_context::application::queries::find_project_tree_use_case::FindProjectTreeResponseModel;
+
+struct TreeNode {
+    label: String,
+    children: Vec<TreeNode>,
+}
+
+pub struct FindProjectTreePresenter;
+
+impl FindProjectTreePresenter {
+    fn print_nodes(&self, nodes: &[TreeNode], prefix: &str) {
+        nodes
+            .iter()
+            .enumerate()
+            .for_each(|(index, node)| {
+                let is_last = index == nodes.len() - 1;
+                println!("{}{}{}", prefix, if is_last { "└── " } else { "├── " }, node.label);
+                self.print_nodes(
+                    &node.children,
+                    &format!("{}{}", prefix, if is_last { "    " } else { "│   " })
+                );
+            });
+    }
+}
+
+#[async_trait]
+impl UseCaseOutputPort<FindProjectTreeResponseModel> for FindProjectTreePresenter {
+    async fn success(&self, response_model: FindProjectTreeResponseModel) {
+        if response_model.bounded_contexts.is_empty() {
+            println!("No bounded contexts found.");
+            return;
+        }
+        response_model.bounded_contexts.iter().for_each(|bounded_context| {
+            println!("{}", bounded_context.name);
+            let nodes = bounded_context.aggregates
+                .iter()
+                .map(|aggregate| TreeNode {
+                    label: aggregate.name.clone(),
+                    children: aggregate.layers
+                        .iter()
+                        .map(|layer| TreeNode {
+                            label: layer.layer_name.to_string(),
+                            children: layer.component_types
+                                .iter()
+                                .map(|component_type| TreeNode {
+                                    label: component_type.component_type.to_string(),
+                                    children: component_type.components
+                                        .iter()
+                                        .map(|component| TreeNode {
+                                            label: component.clone(),
+                                            children: vec![],
+                                        })
+                                        .collect(),
+                                })
+                                .collect(),
+                        })
+                        .collect(),
+                })
+                .collect::<Vec<_>>();
+            self.print_nodes(&nodes, "");
+        });
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to find project tree due to: {}", error)
+    }
+}
@@ -0,0 +1,25 @@
+use std::error::Error;
+use async_trait::async_trait;
+use crate::core::application::use_case_output_port::UseCaseOutputPort;
+use crate::cli::bounded_context::application::commands::import_model_use_case::ImportModelResponseModel;
+
+pub struct ImportModelPresenter;
+
+#[async_trait]
+impl UseCaseOutputPort<ImportModelResponseModel> for ImportModelPresenter {
+    async fn success(&self, response_model: ImportModelResponseModel) {
+        response_model.warnings.iter().for_each(|warning| println!("Warning: {}", warning));
+        if response_model.imported_items.is_empty() {
+            println!("Model is already up to date, nothing to import.");
+            return;
+        }
+        response_model.imported_items.iter().for_each(|imported_item| {
+            println!("Added {} <{}>", imported_item.kind, imported_item.path);
+        });
+        println!("Model imported successfully.");
+    }
+
+    async fn failure(&self, error: Box<dyn Error + Send>) {
+        eprintln!("Failed to import model due to: {}", error)
+    }
+}
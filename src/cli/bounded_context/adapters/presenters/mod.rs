@@ -1,4 +1,11 @@
 pub mod add_aggregate_presenter;
 pub mod add_component_presenter;
+pub mod add_crud_presenter;
 pub mod create_bounded_context_presenter;
+pub mod diff_model_presenter;
+pub mod explain_layers_presenter;
+pub mod export_model_presenter;
 pub mod find_bounded_contexts_presenter;
+pub mod find_components_presenter;
+pub mod find_project_tree_presenter;
+pub mod import_model_presenter;
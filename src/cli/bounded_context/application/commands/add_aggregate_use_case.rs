@@ -4,10 +4,17 @@ use anyhow::Result;
 use async_trait::async_trait;
 use crate::{
     cli::bounded_context::domain::{
-        enums::layer_name::LayerName,
-        repositories::bounded_context_repository::BoundedContextRepository,
+        entities::bounded_context::BoundedContext,
+        enums::{ component_type::ComponentType, layer_name::LayerName },
+        repositories::{
+            bounded_context_repository::BoundedContextRepository,
+            glossary_repository::GlossaryRepository,
+        },
+        services::component_generator::ComponentGenerator,
         value_objects::{
             aggregate_layer::{ AggregateLayer, AggregateLayerValue },
+            component_name::ComponentName,
+            component_specification::{ ComponentSpecification, ComponentSpecificationValue },
             layer_component::LayerComponent,
         },
     },
@@ -16,7 +23,11 @@ use crate::{
             use_case_input_port::UseCaseInputPort,
             use_case_output_port::UseCaseOutputPort,
         },
-        domain::models::{ identity_object::IdentityObject, value_object::ValueObject },
+        domain::models::{
+            entity::Entity,
+            identity_object::IdentityObject,
+            value_object::ValueObject,
+        },
     },
 };
 
@@ -25,28 +36,41 @@ pub struct AddAggregateLayerRequestModel {
     pub components: Vec<LayerComponent>,
 }
 
+pub struct AddAggregateRootRequestModel {
+    pub event_names: Vec<String>,
+}
+
 pub struct AddAggregateRequestModel {
     pub bounded_context_name: String,
     pub aggregate_name: String,
     pub aggregate_layers: Option<Vec<AddAggregateLayerRequestModel>>,
+    pub aggregate_root: Option<AddAggregateRootRequestModel>,
 }
 
 pub struct AddAggregateResponseModel {
     pub aggregate_name: String,
+    pub generated_components: Vec<String>,
+    pub warnings: Vec<String>,
 }
 
 pub struct AddAggregateUseCase<'a> {
     repository: &'a dyn BoundedContextRepository,
+    glossary_repository: &'a dyn GlossaryRepository,
+    component_generator: &'a dyn ComponentGenerator,
     output_port: &'a dyn UseCaseOutputPort<AddAggregateResponseModel>,
 }
 
 impl<'a> AddAggregateUseCase<'a> {
     pub fn new(
         repository: &'a dyn BoundedContextRepository,
+        glossary_repository: &'a dyn GlossaryRepository,
+        component_generator: &'a dyn ComponentGenerator,
         output_port: &'a dyn UseCaseOutputPort<AddAggregateResponseModel>
     ) -> Self {
         Self {
             repository,
+            glossary_repository,
+            component_generator,
             output_port,
         }
     }
@@ -87,6 +111,61 @@ impl<'a> AddAggregateUseCase<'a> {
             .collect()
     }
 
+    // Events are added before the root so the root can import the one it
+    // records on creation.
+    fn add_aggregate_root(
+        &self,
+        bounded_context: &mut BoundedContext,
+        aggregate_id: &IdentityObject,
+        aggregate_root: AddAggregateRootRequestModel
+    ) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
+        let mut event_names = aggregate_root.event_names
+            .into_iter()
+            .map(ComponentName::new)
+            .collect::<Vec<_>>();
+        if event_names.is_empty() {
+            event_names.push(ComponentName::new(format!("{}_created", aggregate_id)));
+        }
+        let mut components = event_names
+            .iter()
+            .map(|event_name| (ComponentType::Events, event_name.clone()))
+            .collect::<Vec<_>>();
+        components.push((ComponentType::Entities, ComponentName::new(aggregate_id.to_string())));
+        let mut generated_components = vec![];
+        for (component_type, component_name) in components {
+            let content = self.component_generator.generate_component(
+                &ComponentSpecification::new(ComponentSpecificationValue {
+                    bounded_context_name: bounded_context.get_id().to_string(),
+                    aggregate_name: aggregate_id.to_string(),
+                    component_type: component_type.clone(),
+                    component_name: component_name.clone(),
+                    layer_name: Some(LayerName::Domain),
+                    fields: vec![],
+                    value_type: None,
+                    rules: vec![],
+                    is_aggregate_root: component_type == ComponentType::Entities,
+                    subscribed_event: None,
+                    value_object_names: vec![],
+                    event_names: event_names.clone(),
+                    repository_names: vec![],
+                    query_name: None,
+                    use_case_type: None,
+                    use_case_name: None,
+                    entity_name: None,
+                })
+            )?;
+            generated_components.push(format!("{}/{}", component_type, component_name.get_value()));
+            bounded_context.add_aggregate_component(
+                aggregate_id,
+                component_type,
+                component_name,
+                Some(LayerName::Domain),
+                content
+            )?;
+        }
+        Ok(generated_components)
+    }
+
     async fn try_interact(
         &self,
         request_model: AddAggregateRequestModel
@@ -96,15 +175,30 @@ impl<'a> AddAggregateUseCase<'a> {
         ).await?;
         match result {
             Some(mut bounded_context) => {
+                bounded_context.set_glossary(
+                    self.glossary_repository.read_glossary(bounded_context.get_id()).await?
+                );
                 let aggregate_id = IdentityObject::new(request_model.aggregate_name);
                 let layers = request_model.aggregate_layers.map_or_else(
                     || self.get_aggregate_layers(),
                     |layers| self.prepare_aggregate_layers(layers)
                 );
                 bounded_context.add_aggregate(&aggregate_id, &layers)?;
+                let generated_components = match request_model.aggregate_root {
+                    Some(aggregate_root) => {
+                        self.add_aggregate_root(
+                            &mut bounded_context,
+                            &aggregate_id,
+                            aggregate_root
+                        )?
+                    }
+                    None => vec![],
+                };
                 self.repository.write_bounded_context(&bounded_context).await?;
                 Ok(AddAggregateResponseModel {
                     aggregate_name: aggregate_id.get_value().to_string(),
+                    generated_components,
+                    warnings: bounded_context.get_glossary_warnings(aggregate_id.get_value()),
                 })
             }
             None => { Err("Bounded context not found".into()) }
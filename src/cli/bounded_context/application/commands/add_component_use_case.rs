@@ -3,23 +3,80 @@ use std::error::Error;
 use anyhow::Result;
 use async_trait::async_trait;
 use crate::{
-    cli::bounded_context::domain::{
-        enums::component_type::ComponentType,
-        repositories::bounded_context_repository::BoundedContextRepository,
-        value_objects::component_name::ComponentName,
+    cli::{
+        architecture::domain::{
+            entities::event_catalog::EventCatalog,
+            repositories::{
+                project_config_repository::ProjectConfigRepository,
+                source_file_repository::SourceFileRepository,
+            },
+            services::source_analyzer::SourceAnalyzer,
+        },
+        bounded_context::domain::{
+            entities::{
+                aggregate::Aggregate,
+                bounded_context::{ BoundedContext, BoundedContextError },
+            },
+            enums::{ component_type::ComponentType, layer_name::LayerName },
+            repositories::{
+                bounded_context_repository::BoundedContextRepository,
+                glossary_repository::GlossaryRepository,
+            },
+            services::component_generator::ComponentGenerator,
+            value_objects::{
+                component_field::{ ComponentField, ComponentFieldValue },
+                component_method::{ ComponentMethod, ComponentMethodValue },
+                component_name::ComponentName,
+                component_rule::{ ComponentRule, ComponentRuleValue },
+                component_specification::{
+                    ComponentSpecification,
+                    ComponentSpecificationValue,
+                },
+                subscribed_event::{ SubscribedEvent, SubscribedEventValue },
+            },
+        },
     },
     core::{
         application::{
             use_case_input_port::UseCaseInputPort,
             use_case_output_port::UseCaseOutputPort,
         },
-        domain::models::{ identity_object::IdentityObject, value_object::ValueObject },
+        domain::models::{
+            entity::Entity,
+            identity_object::IdentityObject,
+            value_object::ValueObject,
+        },
     },
 };
 
+#[derive(Clone)]
+pub struct ComponentFieldRequestModel {
+    pub name: String,
+    pub type_name: String,
+}
+
+#[derive(Clone)]
+pub struct ComponentRuleRequestModel {
+    pub name: String,
+    pub argument: String,
+}
+
+pub struct SubscribedEventRequestModel {
+    pub event: String,
+    pub module_name: Option<String>,
+}
+
 pub struct ComponentRequestModel {
     pub component_type: ComponentType,
     pub component_name: String,
+    pub layer_name: Option<LayerName>,
+    pub fields: Vec<ComponentFieldRequestModel>,
+    pub value_type: Option<String>,
+    pub rules: Vec<ComponentRuleRequestModel>,
+    pub subscribed_event: Option<SubscribedEventRequestModel>,
+    pub repository_names: Option<Vec<String>>,
+    pub implementation_name: Option<String>,
+    pub is_feature: bool,
 }
 
 pub struct AddComponentRequestModel {
@@ -31,24 +88,467 @@ pub struct AddComponentRequestModel {
 pub struct AddComponentResponseModel {
     pub component_type: ComponentType,
     pub component_name: String,
+    pub generated_components: Vec<String>,
+    pub warnings: Vec<String>,
 }
 
 pub struct AddComponentUseCase<'a> {
     repository: &'a dyn BoundedContextRepository,
+    glossary_repository: &'a dyn GlossaryRepository,
+    component_generator: &'a dyn ComponentGenerator,
+    source_file_repository: &'a dyn SourceFileRepository,
+    source_analyzer: &'a dyn SourceAnalyzer,
+    project_config_repository: &'a dyn ProjectConfigRepository,
     output_port: &'a dyn UseCaseOutputPort<AddComponentResponseModel>,
 }
 
 impl<'a> AddComponentUseCase<'a> {
     pub fn new(
         repository: &'a dyn BoundedContextRepository,
+        glossary_repository: &'a dyn GlossaryRepository,
+        component_generator: &'a dyn ComponentGenerator,
+        source_file_repository: &'a dyn SourceFileRepository,
+        source_analyzer: &'a dyn SourceAnalyzer,
+        project_config_repository: &'a dyn ProjectConfigRepository,
         output_port: &'a dyn UseCaseOutputPort<AddComponentResponseModel>
     ) -> Self {
         Self {
             repository,
+            glossary_repository,
+            component_generator,
+            source_file_repository,
+            source_analyzer,
+            project_config_repository,
             output_port,
         }
     }
 
+    // `Order` is written as `order.rs`, like the modules it sits next to, and
+    // the use case of a feature is named after it.
+    fn get_component_name(component: &ComponentRequestModel) -> ComponentName {
+        let snake_case_name = ComponentName::new(
+            component.component_name.clone()
+        ).get_snake_case_name();
+        match component.is_feature {
+            true => ComponentName::new(format!("{}_use_case", snake_case_name.get_value())),
+            false => snake_case_name,
+        }
+    }
+
+    fn validate_component(
+        component: &ComponentRequestModel
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let component_type = &component.component_type;
+        let is_implementable =
+            *component_type == ComponentType::Queries ||
+            (component.is_feature && *component_type == ComponentType::Commands);
+        if !is_implementable && component.implementation_name.is_some() {
+            return Err("--implementation is only supported for queries and features".into());
+        }
+        if
+            component.is_feature &&
+            ![ComponentType::Commands, ComponentType::Queries].contains(component_type)
+        {
+            return Err("A feature is built around either a command or a query".into());
+        }
+        Ok(())
+    }
+
+    // Commands inject every repository of the aggregate's domain layer unless
+    // told which ones to use, while queries get their own find repository.
+    // Find repositories are generic over a query's read model, so they are
+    // left to their queries.
+    fn get_repository_names(
+        aggregate: Option<&Aggregate>,
+        component_name: &ComponentName,
+        component_type: &ComponentType,
+        repository_names: Option<&Vec<String>>
+    ) -> Result<Vec<ComponentName>, Box<dyn Error + Send + Sync>> {
+        if *component_type == ComponentType::Queries {
+            if repository_names.is_some() {
+                return Err(
+                    "--repository is not supported for queries, they get their own find repository"
+                        .into()
+                );
+            }
+            return Ok(
+                vec![
+                    ComponentName::new(
+                        format!("{}_repository", component_name.get_use_case_name().get_value())
+                    )
+                ]
+            );
+        }
+        let find_repository_names = aggregate
+            .map(|aggregate| aggregate.get_component_names(&ComponentType::Queries))
+            .unwrap_or_default()
+            .iter()
+            .map(|query_name| format!("{}_repository", query_name.get_use_case_name().get_value()))
+            .collect::<Vec<_>>();
+        let available_names = aggregate
+            .map(|aggregate| {
+                aggregate.get_layer_component_names(
+                    &LayerName::Domain,
+                    &ComponentType::Repositories
+                )
+            })
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|repository_name| !find_repository_names.contains(repository_name.get_value()))
+            .collect::<Vec<_>>();
+        let Some(repository_names) = repository_names else {
+            return match component_type {
+                ComponentType::Commands => Ok(available_names),
+                _ => Ok(vec![]),
+            };
+        };
+        repository_names
+            .iter()
+            .map(|repository_name| {
+                let available_name = available_names
+                    .iter()
+                    .find(|available_name| available_name.get_value() == repository_name);
+                match available_name {
+                    Some(available_name) => Ok(available_name.clone()),
+                    None => {
+                        Err(
+                            format!(
+                                "Repository <{}> not found in the domain layer, \
+                                expected one of: {}",
+                                repository_name,
+                                available_names
+                                    .iter()
+                                    .map(|available_name| available_name.get_value().as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ).into()
+                        )
+                    }
+                }
+            })
+            .collect()
+    }
+
+    // A query comes with the domain trait of its find repository and,
+    // optionally, an infrastructure implementation of it.
+    fn get_query_specifications(
+        query_specification: &ComponentSpecification,
+        implementation_name: Option<&String>
+    ) -> Vec<ComponentSpecification> {
+        let value = query_specification.get_value();
+        let mut specifications = vec![];
+        for repository_name in &value.repository_names {
+            specifications.push(
+                ComponentSpecification::new(ComponentSpecificationValue {
+                    component_type: ComponentType::Repositories,
+                    component_name: repository_name.clone(),
+                    layer_name: Some(LayerName::Domain),
+                    repository_names: vec![],
+                    ..value.clone()
+                })
+            );
+            if let Some(implementation_name) = implementation_name {
+                specifications.push(
+                    ComponentSpecification::new(ComponentSpecificationValue {
+                        component_type: ComponentType::Repositories,
+                        component_name: ComponentName::new(
+                            format!("{}_{}", implementation_name, repository_name.get_value())
+                        ),
+                        layer_name: Some(LayerName::Infrastructure),
+                        repository_names: vec![repository_name.clone()],
+                        ..value.clone()
+                    })
+                );
+            }
+        }
+        specifications
+    }
+
+    // A feature is a use case together with the controller driving it and the
+    // presenter receiving its response.
+    fn get_feature_specifications(
+        use_case_specification: &ComponentSpecification
+    ) -> Vec<ComponentSpecification> {
+        let value = use_case_specification.get_value();
+        let feature_name = value.component_name.get_use_case_name();
+        [ComponentType::Controllers, ComponentType::Presenters]
+            .into_iter()
+            .map(|component_type| {
+                ComponentSpecification::new(ComponentSpecificationValue {
+                    component_name: ComponentName::new(
+                        format!(
+                            "{}_{}",
+                            feature_name.get_value(),
+                            component_type.get_singular_name()
+                        )
+                    ),
+                    component_type,
+                    layer_name: None,
+                    repository_names: vec![],
+                    query_name: None,
+                    use_case_type: Some(value.component_type.clone()),
+                    use_case_name: Some(value.component_name.clone()),
+                    ..value.clone()
+                })
+            })
+            .collect()
+    }
+
+    fn get_component_specification(
+        bounded_context_name: &str,
+        aggregate: Option<&Aggregate>,
+        aggregate_id: &IdentityObject,
+        component: &ComponentRequestModel,
+        subscribed_event: Option<SubscribedEvent>
+    ) -> Result<ComponentSpecification, Box<dyn Error + Send + Sync>> {
+        let component_name = Self::get_component_name(component);
+        let component_type = component.component_type.clone();
+        let repository_names = Self::get_repository_names(
+            aggregate,
+            &component_name,
+            &component_type,
+            component.repository_names.as_ref()
+        )?;
+        Ok(
+            ComponentSpecification::new(ComponentSpecificationValue {
+                bounded_context_name: bounded_context_name.to_string(),
+                aggregate_name: aggregate_id.to_string(),
+                component_type: component_type.clone(),
+                component_name: component_name.clone(),
+                layer_name: component.layer_name.clone(),
+                fields: component.fields
+                    .iter()
+                    .map(|field| {
+                        ComponentField::new(ComponentFieldValue {
+                            name: field.name.clone(),
+                            type_name: field.type_name.clone(),
+                        })
+                    })
+                    .collect(),
+                value_type: component.value_type.clone(),
+                rules: component.rules
+                    .iter()
+                    .map(|rule| {
+                        ComponentRule::new(ComponentRuleValue {
+                            name: rule.name.clone(),
+                            argument: rule.argument.clone(),
+                        })
+                    })
+                    .collect(),
+                is_aggregate_root: false,
+                subscribed_event,
+                value_object_names: aggregate
+                    .map(|aggregate| aggregate.get_component_names(&ComponentType::ValueObjects))
+                    .unwrap_or_default(),
+                event_names: aggregate
+                    .map(|aggregate| aggregate.get_component_names(&ComponentType::Events))
+                    .unwrap_or_default(),
+                repository_names,
+                query_name: (component_type == ComponentType::Queries).then_some(component_name),
+                use_case_type: None,
+                use_case_name: None,
+                entity_name: None,
+            })
+        )
+    }
+
+    // The requested component comes first, followed by the ones it brings
+    // along: the find repository of a query and the adapters of a feature.
+    fn get_component_specifications(
+        component_specification: ComponentSpecification,
+        component: &ComponentRequestModel
+    ) -> Vec<ComponentSpecification> {
+        let mut component_specifications = vec![];
+        if component.component_type == ComponentType::Queries {
+            component_specifications.extend(
+                Self::get_query_specifications(
+                    &component_specification,
+                    component.implementation_name.as_ref()
+                )
+            );
+        }
+        if component.is_feature {
+            component_specifications.extend(
+                Self::get_feature_specifications(&component_specification)
+            );
+        }
+        component_specifications.insert(0, component_specification);
+        component_specifications
+    }
+
+    // A feature command gets a method named after it on the repository it
+    // receives and, with `--implementation`, on that repository's
+    // implementation in the infrastructure layer.
+    fn get_method_specifications(
+        component_specification: &ComponentSpecification,
+        component: &ComponentRequestModel
+    ) -> Result<Vec<ComponentSpecification>, Box<dyn Error + Send + Sync>> {
+        let value = component_specification.get_value();
+        if !component.is_feature || value.component_type != ComponentType::Commands {
+            return Ok(vec![]);
+        }
+        let repository_name = match value.repository_names.as_slice() {
+            [repository_name] => repository_name,
+            [] if component.implementation_name.is_none() => {
+                return Ok(vec![]);
+            }
+            [] => {
+                return Err(
+                    "--implementation needs a repository in the domain layer to implement".into()
+                );
+            }
+            repository_names => {
+                return Err(
+                    format!(
+                        "A feature adds its method to a single repository, pick it with \
+                        --repository (one of: {})",
+                        repository_names
+                            .iter()
+                            .map(|repository_name| repository_name.get_value().as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ).into()
+                );
+            }
+        };
+        let method_specification = ComponentSpecificationValue {
+            component_type: ComponentType::Repositories,
+            component_name: repository_name.clone(),
+            layer_name: Some(LayerName::Domain),
+            repository_names: vec![],
+            query_name: None,
+            use_case_type: Some(value.component_type.clone()),
+            use_case_name: Some(value.component_name.clone()),
+            ..value.clone()
+        };
+        let mut method_specifications = vec![method_specification.clone()];
+        if let Some(implementation_name) = &component.implementation_name {
+            method_specifications.push(ComponentSpecificationValue {
+                component_name: ComponentName::new(
+                    format!("{}_{}", implementation_name, repository_name.get_value())
+                ),
+                layer_name: Some(LayerName::Infrastructure),
+                repository_names: vec![repository_name.clone()],
+                ..method_specification
+            });
+        }
+        Ok(method_specifications.into_iter().map(ComponentSpecification::new).collect())
+    }
+
+    fn add_methods(
+        &self,
+        bounded_context: &mut BoundedContext,
+        aggregate_id: &IdentityObject,
+        method_specifications: &[ComponentSpecification]
+    ) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
+        let mut generated_methods = vec![];
+        for method_specification in method_specifications {
+            let content = self.component_generator.generate_component_method(
+                method_specification
+            )?;
+            let value = method_specification.get_value();
+            let method_name = value.use_case_name
+                .as_ref()
+                .map(|use_case_name| use_case_name.get_use_case_name().get_value().to_string())
+                .unwrap_or_default();
+            generated_methods.push(
+                format!(
+                    "{}/{}::{}",
+                    value.component_type,
+                    value.component_name.get_value(),
+                    method_name
+                )
+            );
+            bounded_context.add_aggregate_component_method(
+                aggregate_id,
+                value.component_type.clone(),
+                value.component_name.clone(),
+                value.layer_name.clone().unwrap_or(LayerName::Domain),
+                ComponentMethod::new(ComponentMethodValue {
+                    name: method_name,
+                    content,
+                })
+            )?;
+        }
+        Ok(generated_methods)
+    }
+
+    // Every component is added before anything is written, so a clash on any
+    // of them leaves the project untouched. The requested component is left
+    // out of the generated ones.
+    fn add_components(
+        &self,
+        bounded_context: &mut BoundedContext,
+        aggregate_id: &IdentityObject,
+        component_specifications: &[ComponentSpecification]
+    ) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
+        let mut generated_components = vec![];
+        for (index, component_specification) in component_specifications.iter().enumerate() {
+            let content = self.component_generator.generate_component(component_specification)?;
+            let value = component_specification.get_value();
+            if index > 0 {
+                generated_components.push(
+                    format!("{}/{}", value.component_type, value.component_name.get_value())
+                );
+            }
+            bounded_context.add_aggregate_component(
+                aggregate_id,
+                value.component_type.clone(),
+                value.component_name.clone(),
+                value.layer_name.clone(),
+                content
+            ).map_err(|error| -> Box<dyn Error + Send + Sync> {
+                match error.downcast::<BoundedContextError>() {
+                    Ok(error) => Box::new(error),
+                    Err(error) => error.into(),
+                }
+            })?;
+        }
+        Ok(generated_components)
+    }
+
+    async fn find_subscribed_event(
+        &self,
+        subscribed_event: &SubscribedEventRequestModel
+    ) -> Result<SubscribedEvent, Box<dyn Error + Send + Sync>> {
+        let source_files = self.source_file_repository.read_source_files().await?;
+        let mut event_catalog = EventCatalog::new(IdentityObject::new("subscription".to_string()));
+        event_catalog.add_source_files(&source_files, self.source_analyzer);
+        let events = event_catalog.find_events(
+            &subscribed_event.event,
+            subscribed_event.module_name.as_deref()
+        );
+        match events.as_slice() {
+            [event] =>
+                Ok(
+                    SubscribedEvent::new(SubscribedEventValue {
+                        event_name: event.event_name.clone(),
+                        type_name: event.declaration.name.clone(),
+                        path: event.declaration.path.clone(),
+                    })
+                ),
+            [] => {
+                let scope = match &subscribed_event.module_name {
+                    Some(module_name) => format!("<{}>", module_name),
+                    None => "the project".to_string(),
+                };
+                Err(format!("Event <{}> not found in {}", subscribed_event.event, scope).into())
+            }
+            _ =>
+                Err(
+                    format!(
+                        "Event <{}> is declared in {}, use --from to pick one",
+                        subscribed_event.event,
+                        events
+                            .iter()
+                            .map(|event| event.declaration.module_name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ).into()
+                ),
+        }
+    }
+
     /*
     fn get_aggregate_layers(&self) -> Vec<AggregateLayer> {
         vec![
@@ -103,29 +603,78 @@ impl<'a> AddComponentUseCase<'a> {
     }
     */
 
+    // The generated code of some rules relies on crates the project may not
+    // depend on yet.
+    async fn get_dependency_warnings(
+        &self,
+        rules: &[ComponentRuleRequestModel]
+    ) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
+        if !rules.iter().any(|rule| rule.name == "regex") {
+            return Ok(vec![]);
+        }
+        let dependency_names = self.project_config_repository.read_dependency_names().await?;
+        if dependency_names.iter().any(|name| name == "regex") {
+            return Ok(vec![]);
+        }
+        Ok(vec!["the regex rule relies on the regex crate, add it with `cargo add regex`".into()])
+    }
+
     async fn try_interact(
         &self,
         request_model: AddComponentRequestModel
     ) -> Result<AddComponentResponseModel, Box<dyn Error + Send + Sync>> {
         let result = self.repository.read_bounded_context(
-            &IdentityObject::new(request_model.bounded_context_name)
+            &IdentityObject::new(request_model.bounded_context_name.clone())
         ).await?;
         match result {
             Some(mut bounded_context) => {
+                bounded_context.set_glossary(
+                    self.glossary_repository.read_glossary(bounded_context.get_id()).await?
+                );
                 let aggregate_id = IdentityObject::new(request_model.aggregate_name);
-                let component_name = ComponentName::new(
-                    request_model.component.component_name.clone()
+                let component = &request_model.component;
+                Self::validate_component(component)?;
+                let subscribed_event = match &component.subscribed_event {
+                    Some(subscribed_event) => {
+                        Some(self.find_subscribed_event(subscribed_event).await?)
+                    }
+                    None => None,
+                };
+                let component_specification = Self::get_component_specification(
+                    &request_model.bounded_context_name,
+                    bounded_context.get_aggregate(&aggregate_id),
+                    &aggregate_id,
+                    component,
+                    subscribed_event
+                )?;
+                let component_name = component_specification.get_value().component_name.clone();
+                let method_specifications = Self::get_method_specifications(
+                    &component_specification,
+                    component
+                )?;
+                let component_specifications = Self::get_component_specifications(
+                    component_specification,
+                    component
                 );
-                bounded_context.add_aggregate_component(
+                let mut generated_components = self.add_components(
+                    &mut bounded_context,
                     &aggregate_id,
-                    request_model.component.component_type.clone(),
-                    component_name
+                    &component_specifications
                 )?;
-
+                generated_components.extend(
+                    self.add_methods(&mut bounded_context, &aggregate_id, &method_specifications)?
+                );
+                let dependency_warnings = self.get_dependency_warnings(&component.rules).await?;
                 self.repository.write_bounded_context(&bounded_context).await?;
+                let mut warnings = bounded_context.get_glossary_warnings(
+                    &component.component_name
+                );
+                warnings.extend(dependency_warnings);
                 Ok(AddComponentResponseModel {
+                    generated_components,
+                    warnings,
                     component_type: request_model.component.component_type,
-                    component_name: request_model.component.component_name,
+                    component_name: component_name.get_value().to_string(),
                 })
             }
             None => { Err("Bounded context not found".into()) }
@@ -147,3 +696,151 @@ impl<'a> UseCaseInputPort<AddComponentRequestModel> for AddComponentUseCase<'a>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::bounded_context::{
+            application::commands::add_component_use_case::{
+                AddComponentUseCase,
+                ComponentRequestModel,
+            },
+            domain::{
+                entities::aggregate::Aggregate,
+                enums::{ component_type::ComponentType, layer_name::LayerName },
+                value_objects::{
+                    aggregate_layer::{ AggregateLayer, AggregateLayerValue },
+                    component_name::ComponentName,
+                    component_specification::ComponentSpecification,
+                    layer_component::{ LayerComponent, LayerComponentValue },
+                },
+            },
+        },
+        core::domain::models::{
+            entity::Entity,
+            identity_object::IdentityObject,
+            value_object::ValueObject,
+        },
+    };
+
+    fn create_aggregate() -> Aggregate {
+        Aggregate::new(IdentityObject::new("order".to_string()), vec![
+            AggregateLayer::new(AggregateLayerValue {
+                name: LayerName::Domain,
+                components: vec![
+                    LayerComponent::new(LayerComponentValue {
+                        component_type: ComponentType::Repositories,
+                        component_name: ComponentName::new("order_repository".to_string()),
+                        content: None,
+                        methods: vec![],
+                    })
+                ],
+            })
+        ])
+    }
+
+    fn create_component(
+        component_type: ComponentType,
+        component_name: &str,
+        is_feature: bool
+    ) -> ComponentRequestModel {
+        ComponentRequestModel {
+            component_type,
+            component_name: component_name.to_string(),
+            layer_name: None,
+            fields: vec![],
+            value_type: None,
+            rules: vec![],
+            subscribed_event: None,
+            repository_names: None,
+            implementation_name: None,
+            is_feature,
+        }
+    }
+
+    fn get_specifications(component: &ComponentRequestModel) -> Vec<ComponentSpecification> {
+        let aggregate = create_aggregate();
+        let component_specification = AddComponentUseCase::get_component_specification(
+            "sales",
+            Some(&aggregate),
+            aggregate.get_id(),
+            component,
+            None
+        ).unwrap();
+        AddComponentUseCase::get_component_specifications(component_specification, component)
+    }
+
+    fn get_names(specifications: &[ComponentSpecification]) -> Vec<String> {
+        specifications
+            .iter()
+            .map(|specification| {
+                let value = specification.get_value();
+                format!("{}/{}", value.component_type, value.component_name.get_value())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_build_a_feature_around_a_command() {
+        let specifications = get_specifications(
+            &create_component(ComponentType::Commands, "PlaceOrder", true)
+        );
+        assert_eq!(get_names(&specifications), vec![
+            "commands/place_order_use_case",
+            "controllers/place_order_controller",
+            "presenters/place_order_presenter"
+        ]);
+        assert_eq!(
+            specifications[0].get_value().repository_names
+                .iter()
+                .map(|repository_name| repository_name.get_value().as_str())
+                .collect::<Vec<_>>(),
+            vec!["order_repository"]
+        );
+    }
+
+    #[test]
+    fn should_give_a_query_its_find_repository() {
+        let component = ComponentRequestModel {
+            implementation_name: Some("postgres".to_string()),
+            ..create_component(ComponentType::Queries, "find_orders", false)
+        };
+        assert_eq!(get_names(&get_specifications(&component)), vec![
+            "queries/find_orders",
+            "repositories/find_orders_repository",
+            "repositories/postgres_find_orders_repository"
+        ]);
+    }
+
+    #[test]
+    fn should_add_the_method_of_a_feature_command_to_its_repository() {
+        let component = ComponentRequestModel {
+            implementation_name: Some("in_memory".to_string()),
+            ..create_component(ComponentType::Commands, "PlaceOrder", true)
+        };
+        let aggregate = create_aggregate();
+        let component_specification = AddComponentUseCase::get_component_specification(
+            "sales",
+            Some(&aggregate),
+            aggregate.get_id(),
+            &component,
+            None
+        ).unwrap();
+        let method_specifications = AddComponentUseCase::get_method_specifications(
+            &component_specification,
+            &component
+        ).unwrap();
+        assert_eq!(get_names(&method_specifications), vec![
+            "repositories/order_repository",
+            "repositories/in_memory_order_repository"
+        ]);
+        assert!(
+            AddComponentUseCase::get_method_specifications(
+                &component_specification,
+                &create_component(ComponentType::Commands, "PlaceOrder", false)
+            )
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
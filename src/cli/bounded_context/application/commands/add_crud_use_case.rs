@@ -0,0 +1,331 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use crate::{
+    cli::{
+        architecture::domain::{
+            enums::source_item_kind::SourceItemKind,
+            repositories::source_file_repository::SourceFileRepository,
+            services::source_analyzer::SourceAnalyzer,
+        },
+        bounded_context::domain::{
+            entities::bounded_context::{ BoundedContext, BoundedContextError },
+            enums::{ component_type::ComponentType, layer_name::LayerName },
+            repositories::{
+                bounded_context_repository::BoundedContextRepository,
+                glossary_repository::GlossaryRepository,
+            },
+            services::component_generator::ComponentGenerator,
+            value_objects::{
+                component_field::{ ComponentField, ComponentFieldValue },
+                component_name::ComponentName,
+                component_specification::{
+                    ComponentSpecification,
+                    ComponentSpecificationValue,
+                },
+            },
+        },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::{
+            entity::Entity,
+            identity_object::IdentityObject,
+            value_object::ValueObject,
+        },
+    },
+};
+
+pub struct AddCrudRequestModel {
+    pub bounded_context_name: String,
+    pub aggregate_name: String,
+}
+
+pub struct AddCrudResponseModel {
+    pub aggregate_name: String,
+    pub generated_components: Vec<String>,
+}
+
+pub struct AddCrudUseCase<'a> {
+    repository: &'a dyn BoundedContextRepository,
+    glossary_repository: &'a dyn GlossaryRepository,
+    component_generator: &'a dyn ComponentGenerator,
+    source_file_repository: &'a dyn SourceFileRepository,
+    source_analyzer: &'a dyn SourceAnalyzer,
+    output_port: &'a dyn UseCaseOutputPort<AddCrudResponseModel>,
+}
+
+impl<'a> AddCrudUseCase<'a> {
+    pub fn new(
+        repository: &'a dyn BoundedContextRepository,
+        glossary_repository: &'a dyn GlossaryRepository,
+        component_generator: &'a dyn ComponentGenerator,
+        source_file_repository: &'a dyn SourceFileRepository,
+        source_analyzer: &'a dyn SourceAnalyzer,
+        output_port: &'a dyn UseCaseOutputPort<AddCrudResponseModel>
+    ) -> Self {
+        Self {
+            repository,
+            glossary_repository,
+            component_generator,
+            source_file_repository,
+            source_analyzer,
+            output_port,
+        }
+    }
+
+    fn get_specification(
+        &self,
+        bounded_context: &BoundedContext,
+        entity_name: &ComponentName,
+        component_type: ComponentType,
+        component_name: String,
+        layer_name: Option<LayerName>
+    ) -> ComponentSpecificationValue {
+        ComponentSpecificationValue {
+            bounded_context_name: bounded_context.get_id().to_string(),
+            aggregate_name: entity_name.get_value().to_string(),
+            component_type,
+            component_name: ComponentName::new(component_name),
+            layer_name,
+            fields: vec![],
+            value_type: None,
+            rules: vec![],
+            is_aggregate_root: false,
+            subscribed_event: None,
+            value_object_names: vec![],
+            event_names: vec![],
+            repository_names: vec![],
+            query_name: None,
+            use_case_type: None,
+            use_case_name: None,
+            entity_name: Some(entity_name.clone()),
+        }
+    }
+
+    // The fields of the entity in the order of its `new`, which the create and
+    // update use cases take from their request.
+    async fn find_entity_fields(
+        &self,
+        bounded_context: &BoundedContext,
+        entity_name: &ComponentName
+    ) -> Result<Vec<ComponentField>, Box<dyn Error + Send + Sync>> {
+        let source_files = self.source_file_repository.read_source_files().await?;
+        let type_name = entity_name.get_type_name();
+        let definition = source_files
+            .iter()
+            .filter(|source_file| {
+                let value = source_file.get_value();
+                value.bounded_context_name == bounded_context.get_id().to_string() &&
+                    value.aggregate_name.as_ref() == Some(entity_name.get_value()) &&
+                    value.component_type == Some(ComponentType::Entities) &&
+                    source_file.get_component_name() == *entity_name.get_value()
+            })
+            .flat_map(|source_file| self.source_analyzer.find_type_definitions(source_file))
+            .find(|definition| {
+                let value = definition.get_value();
+                value.kind == SourceItemKind::Struct && value.name == type_name
+            })
+            .ok_or_else(|| {
+                format!("Entity <{}> has no <{}> struct", entity_name.get_value(), type_name)
+            })?;
+        let mut fields = vec![];
+        for member in &definition.get_value().members {
+            let member_type = member.type_name.clone().unwrap_or_default();
+            let type_name = match member.name.as_str() {
+                "domain_events" => {
+                    continue;
+                }
+                "id" if member_type != "IdentityObject" => {
+                    return Err(
+                        format!(
+                            "Entity <{}> must be identified by an IdentityObject, \
+                            got <{}>",
+                            entity_name.get_value(),
+                            member_type
+                        ).into()
+                    );
+                }
+                "id" => "String".to_string(),
+                _ => member_type,
+            };
+            fields.push(
+                ComponentField::new(ComponentFieldValue {
+                    name: member.name.clone(),
+                    type_name,
+                })
+            );
+        }
+        Ok(fields)
+    }
+
+    // The aggregate's entity is managed through a repository trait with one
+    // method per use case, backed by an in-memory implementation; every use
+    // case gets a presenter for its response.
+    fn get_crud_specifications(
+        &self,
+        bounded_context: &BoundedContext,
+        entity_name: &ComponentName,
+        entity_fields: Vec<ComponentField>,
+        value_object_names: Vec<ComponentName>
+    ) -> Vec<ComponentSpecification> {
+        let name = entity_name.get_value();
+        let repository_name = ComponentName::new(format!("{}_repository", name));
+        let id_field = ComponentField::new(ComponentFieldValue {
+            name: "id".to_string(),
+            type_name: "String".to_string(),
+        });
+        let mut specifications = vec![
+            self.get_specification(
+                bounded_context,
+                entity_name,
+                ComponentType::Repositories,
+                repository_name.get_value().to_string(),
+                Some(LayerName::Domain)
+            ),
+            ComponentSpecificationValue {
+                repository_names: vec![repository_name.clone()],
+                ..self.get_specification(
+                    bounded_context,
+                    entity_name,
+                    ComponentType::Repositories,
+                    format!("in_memory_{}", repository_name.get_value()),
+                    Some(LayerName::Infrastructure)
+                )
+            }
+        ];
+        let use_cases = [
+            (ComponentType::Commands, format!("create_{}", name), entity_fields.clone()),
+            (ComponentType::Commands, format!("update_{}", name), entity_fields),
+            (ComponentType::Commands, format!("delete_{}", name), vec![id_field.clone()]),
+            (ComponentType::Queries, format!("get_{}", name), vec![id_field]),
+            (
+                ComponentType::Queries,
+                format!("list_{}", entity_name.get_plural_name().get_value()),
+                vec![],
+            ),
+        ];
+        for (component_type, use_case_name, fields) in use_cases {
+            let use_case_specification = ComponentSpecificationValue {
+                fields,
+                repository_names: vec![repository_name.clone()],
+                value_object_names: value_object_names.clone(),
+                ..self.get_specification(
+                    bounded_context,
+                    entity_name,
+                    component_type.clone(),
+                    format!("{}_use_case", use_case_name),
+                    None
+                )
+            };
+            let presenter_specification = ComponentSpecificationValue {
+                use_case_type: Some(component_type),
+                use_case_name: Some(use_case_specification.component_name.clone()),
+                ..self.get_specification(
+                    bounded_context,
+                    entity_name,
+                    ComponentType::Presenters,
+                    format!("{}_presenter", use_case_name),
+                    None
+                )
+            };
+            specifications.push(use_case_specification);
+            specifications.push(presenter_specification);
+        }
+        specifications.into_iter().map(ComponentSpecification::new).collect()
+    }
+
+    async fn try_interact(
+        &self,
+        request_model: AddCrudRequestModel
+    ) -> Result<AddCrudResponseModel, Box<dyn Error + Send + Sync>> {
+        let result = self.repository.read_bounded_context(
+            &IdentityObject::new(request_model.bounded_context_name)
+        ).await?;
+        match result {
+            Some(mut bounded_context) => {
+                bounded_context.set_glossary(
+                    self.glossary_repository.read_glossary(bounded_context.get_id()).await?
+                );
+                let aggregate_id = IdentityObject::new(request_model.aggregate_name);
+                let entity_name = ComponentName::new(aggregate_id.to_string());
+                let aggregate = bounded_context
+                    .get_aggregate(&aggregate_id)
+                    .ok_or_else(|| format!("Aggregate <{}> not found", aggregate_id))?;
+                let has_entity = aggregate
+                    .get_component_names(&ComponentType::Entities)
+                    .iter()
+                    .any(|component_name| component_name.is_equal(&entity_name));
+                let value_object_names = aggregate.get_component_names(
+                    &ComponentType::ValueObjects
+                );
+                if !has_entity {
+                    return Err(
+                        format!(
+                            "Aggregate <{}> has no <{}> entity to manage, add it first with \
+                            `arpeggio add entity {} {} {}`",
+                            aggregate_id,
+                            aggregate_id,
+                            aggregate_id,
+                            aggregate_id,
+                            bounded_context.get_id()
+                        ).into()
+                    );
+                }
+                let entity_fields = self.find_entity_fields(&bounded_context, &entity_name).await?;
+                let specifications = self.get_crud_specifications(
+                    &bounded_context,
+                    &entity_name,
+                    entity_fields,
+                    value_object_names
+                );
+                // Every component is added before anything is written, so a
+                // clash on any of them leaves the project untouched.
+                let mut generated_components = vec![];
+                for specification in specifications {
+                    let content = self.component_generator.generate_component(&specification)?;
+                    let value = specification.get_value();
+                    generated_components.push(
+                        format!("{}/{}", value.component_type, value.component_name.get_value())
+                    );
+                    bounded_context.add_aggregate_component(
+                        &aggregate_id,
+                        value.component_type.clone(),
+                        value.component_name.clone(),
+                        value.layer_name.clone(),
+                        content
+                    ).map_err(|error| -> Box<dyn Error + Send + Sync> {
+                        match error.downcast::<BoundedContextError>() {
+                            Ok(error) => Box::new(error),
+                            Err(error) => error.into(),
+                        }
+                    })?;
+                }
+                self.repository.write_bounded_context(&bounded_context).await?;
+                Ok(AddCrudResponseModel {
+                    aggregate_name: aggregate_id.get_value().to_string(),
+                    generated_components,
+                })
+            }
+            None => { Err("Bounded context not found".into()) }
+        }
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<AddCrudRequestModel> for AddCrudUseCase<'a> {
+    async fn interact(&self, request_model: AddCrudRequestModel) {
+        let result = self.try_interact(request_model).await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
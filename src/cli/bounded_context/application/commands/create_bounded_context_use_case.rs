@@ -50,7 +50,8 @@ impl<'a> CreateBoundedContextUseCase<'a> {
     ) -> Result<CreateBoundedContextResponseModel, Box<dyn Error + Send + Sync>> {
         let bounded_context = BoundedContext::new(
             IdentityObject::new(request_model.bounded_context_name),
-            vec![]
+            vec![],
+            None
         );
 
         self.repository.write_bounded_context(&bounded_context).await?;
@@ -0,0 +1,223 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use crate::{
+    cli::bounded_context::domain::{
+        entities::bounded_context::BoundedContext,
+        enums::{ component_type::ComponentType, layer_name::LayerName },
+        repositories::{
+            bounded_context_repository::{
+                BoundedContextRepository,
+                BoundedContextRepositoryError,
+            },
+            glossary_repository::GlossaryRepository,
+        },
+        value_objects::{
+            aggregate_layer::{ AggregateLayer, AggregateLayerValue },
+            component_name::ComponentName,
+            layer_component::{ LayerComponent, LayerComponentValue },
+        },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::{
+            entity::Entity,
+            identity_object::IdentityObject,
+            value_object::ValueObject,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct ImportComponentRequestModel {
+    pub component_type: ComponentType,
+    pub component_name: String,
+}
+
+#[derive(Clone)]
+pub struct ImportLayerRequestModel {
+    pub layer_name: LayerName,
+    pub components: Vec<ImportComponentRequestModel>,
+}
+
+#[derive(Clone)]
+pub struct ImportAggregateRequestModel {
+    pub name: String,
+    pub layers: Vec<ImportLayerRequestModel>,
+}
+
+#[derive(Clone)]
+pub struct ImportBoundedContextRequestModel {
+    pub name: String,
+    pub aggregates: Vec<ImportAggregateRequestModel>,
+}
+
+pub struct ImportModelRequestModel {
+    pub bounded_contexts: Vec<ImportBoundedContextRequestModel>,
+}
+
+pub struct ImportedItemReadModel {
+    pub kind: String,
+    pub path: String,
+}
+
+pub struct ImportModelResponseModel {
+    pub imported_items: Vec<ImportedItemReadModel>,
+    pub warnings: Vec<String>,
+}
+
+pub struct ImportModelUseCase<'a> {
+    repository: &'a dyn BoundedContextRepository,
+    glossary_repository: &'a dyn GlossaryRepository,
+    output_port: &'a dyn UseCaseOutputPort<ImportModelResponseModel>,
+}
+
+impl<'a> ImportModelUseCase<'a> {
+    pub fn new(
+        repository: &'a dyn BoundedContextRepository,
+        glossary_repository: &'a dyn GlossaryRepository,
+        output_port: &'a dyn UseCaseOutputPort<ImportModelResponseModel>
+    ) -> Self {
+        Self {
+            repository,
+            glossary_repository,
+            output_port,
+        }
+    }
+
+    fn import_aggregate(
+        &self,
+        bounded_context: &mut BoundedContext,
+        aggregate_request_model: &ImportAggregateRequestModel,
+        response_model: &mut ImportModelResponseModel
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let aggregate_id = IdentityObject::new(aggregate_request_model.name.clone());
+        let aggregate_path = format!("{}/{}", bounded_context.id, aggregate_id);
+        if bounded_context.get_aggregate(&aggregate_id).is_none() {
+            let layers = aggregate_request_model.layers
+                .iter()
+                .map(|layer| {
+                    AggregateLayer::new(AggregateLayerValue {
+                        name: layer.layer_name.clone(),
+                        components: vec![],
+                    })
+                })
+                .collect::<Vec<_>>();
+            bounded_context.add_aggregate(&aggregate_id, &layers)?;
+            response_model.warnings.extend(
+                bounded_context.get_glossary_warnings(&aggregate_id.to_string())
+            );
+            response_model.imported_items.push(ImportedItemReadModel {
+                kind: "aggregate".to_string(),
+                path: aggregate_path.clone(),
+            });
+        }
+        for layer in &aggregate_request_model.layers {
+            for component in &layer.components {
+                let component_name = ComponentName::new(
+                    component.component_name.clone()
+                ).get_snake_case_name();
+                let layer_component = LayerComponent::new(LayerComponentValue {
+                    component_type: component.component_type.clone(),
+                    component_name: component_name.clone(),
+                    content: None,
+                    methods: vec![],
+                });
+                let is_existing = bounded_context
+                    .get_aggregate(&aggregate_id)
+                    .is_some_and(|aggregate| {
+                        aggregate.has_layer_component(&layer.layer_name, &layer_component)
+                    });
+                if is_existing {
+                    continue;
+                }
+                bounded_context.add_aggregate_component(
+                    &aggregate_id,
+                    component.component_type.clone(),
+                    component_name.clone(),
+                    Some(layer.layer_name.clone()),
+                    None
+                )?;
+                response_model.warnings.extend(
+                    bounded_context.get_glossary_warnings(&component.component_name)
+                );
+                response_model.imported_items.push(ImportedItemReadModel {
+                    kind: component.component_type.get_singular_name().replace('_', " "),
+                    path: format!(
+                        "{}/{}/{}/{}",
+                        aggregate_path,
+                        layer.layer_name,
+                        component.component_type,
+                        component_name.get_value()
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    async fn try_interact(
+        &self,
+        request_model: ImportModelRequestModel
+    ) -> Result<ImportModelResponseModel, Box<dyn Error + Send + Sync>> {
+        let mut response_model = ImportModelResponseModel {
+            imported_items: vec![],
+            warnings: vec![],
+        };
+        // Every bounded context is validated before anything is written, so an
+        // invalid model leaves the project untouched.
+        let mut bounded_contexts = vec![];
+        for bounded_context_request_model in &request_model.bounded_contexts {
+            let bounded_context_id = IdentityObject::new(
+                bounded_context_request_model.name.clone()
+            );
+            let result = self.repository.read_bounded_context(&bounded_context_id).await;
+            let mut bounded_context = match result {
+                Ok(Some(bounded_context)) => bounded_context,
+                Ok(None) | Err(BoundedContextRepositoryError::NotFound(_)) => {
+                    response_model.imported_items.push(ImportedItemReadModel {
+                        kind: "bounded context".to_string(),
+                        path: bounded_context_id.to_string(),
+                    });
+                    BoundedContext::new(bounded_context_id, vec![], None)
+                }
+                Err(error) => {
+                    return Err(Box::new(error));
+                }
+            };
+            bounded_context.set_glossary(
+                self.glossary_repository.read_glossary(bounded_context.get_id()).await?
+            );
+            for aggregate_request_model in &bounded_context_request_model.aggregates {
+                self.import_aggregate(
+                    &mut bounded_context,
+                    aggregate_request_model,
+                    &mut response_model
+                )?;
+            }
+            bounded_contexts.push(bounded_context);
+        }
+        for bounded_context in &bounded_contexts {
+            self.repository.write_bounded_context(bounded_context).await?;
+        }
+        Ok(response_model)
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<ImportModelRequestModel> for ImportModelUseCase<'a> {
+    async fn interact(&self, request_model: ImportModelRequestModel) {
+        let result = self.try_interact(request_model).await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
 pub mod add_aggregate_use_case;
 pub mod add_component_use_case;
+pub mod add_crud_use_case;
 pub mod create_bounded_context_use_case;
+pub mod import_model_use_case;
@@ -0,0 +1,184 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use crate::{
+    cli::bounded_context::{
+        application::{
+            commands::import_model_use_case::ImportBoundedContextRequestModel,
+            queries::find_bounded_contexts_use_case::BoundedContextReadModel,
+        },
+        domain::{
+            entities::bounded_context::BoundedContext,
+            repositories::{
+                bounded_context_repository::BoundedContextRepository,
+                find_bounded_contexts_repository::FindBoundedContextsRepository,
+            },
+        },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::{
+            entity::Entity,
+            identity_object::IdentityObject,
+            value_object::ValueObject,
+        },
+    },
+};
+
+pub struct DiffModelRequestModel {
+    pub bounded_contexts: Vec<ImportBoundedContextRequestModel>,
+}
+
+#[derive(Clone)]
+pub struct DriftItemReadModel {
+    pub kind: String,
+    pub path: String,
+}
+
+pub struct DiffModelResponseModel {
+    pub missing_items: Vec<DriftItemReadModel>,
+    pub unexpected_items: Vec<DriftItemReadModel>,
+}
+
+impl DiffModelResponseModel {
+    pub fn has_drift(&self) -> bool {
+        !self.missing_items.is_empty() || !self.unexpected_items.is_empty()
+    }
+}
+
+pub struct DiffModelUseCase<'a> {
+    find_repository: &'a dyn FindBoundedContextsRepository<BoundedContextReadModel>,
+    repository: &'a dyn BoundedContextRepository,
+    output_port: &'a dyn UseCaseOutputPort<DiffModelResponseModel>,
+}
+
+impl<'a> DiffModelUseCase<'a> {
+    pub fn new(
+        find_repository: &'a dyn FindBoundedContextsRepository<BoundedContextReadModel>,
+        repository: &'a dyn BoundedContextRepository,
+        output_port: &'a dyn UseCaseOutputPort<DiffModelResponseModel>
+    ) -> Self {
+        Self {
+            find_repository,
+            repository,
+            output_port,
+        }
+    }
+
+    fn push_item(items: &mut Vec<DriftItemReadModel>, kind: &str, path: String) {
+        if !items.iter().any(|item| item.path == path) {
+            items.push(DriftItemReadModel { kind: kind.to_string(), path });
+        }
+    }
+
+    fn get_spec_items(request_model: &DiffModelRequestModel) -> Vec<DriftItemReadModel> {
+        let mut items = vec![];
+        for bounded_context in &request_model.bounded_contexts {
+            Self::push_item(&mut items, "bounded context", bounded_context.name.clone());
+            for aggregate in &bounded_context.aggregates {
+                let aggregate_path = format!("{}/{}", bounded_context.name, aggregate.name);
+                Self::push_item(&mut items, "aggregate", aggregate_path.clone());
+                for layer in &aggregate.layers {
+                    let layer_path = format!("{}/{}", aggregate_path, layer.layer_name);
+                    Self::push_item(&mut items, "layer", layer_path.clone());
+                    for component in &layer.components {
+                        Self::push_item(
+                            &mut items,
+                            &component.component_type.get_singular_name().replace('_', " "),
+                            format!(
+                                "{}/{}/{}",
+                                layer_path,
+                                component.component_type,
+                                component.component_name
+                            )
+                        );
+                    }
+                }
+            }
+        }
+        items
+    }
+
+    fn get_project_items(bounded_context: &BoundedContext, items: &mut Vec<DriftItemReadModel>) {
+        let bounded_context_path = bounded_context.get_id().to_string();
+        Self::push_item(items, "bounded context", bounded_context_path.clone());
+        for aggregate in &bounded_context.aggregates {
+            let aggregate_path = format!("{}/{}", bounded_context_path, aggregate.get_id());
+            Self::push_item(items, "aggregate", aggregate_path.clone());
+            for layer in aggregate.get_layers() {
+                let layer_path = format!("{}/{}", aggregate_path, layer.get_value().name);
+                Self::push_item(items, "layer", layer_path.clone());
+                for component in &layer.get_value().components {
+                    let component_type = &component.get_value().component_type;
+                    Self::push_item(
+                        items,
+                        &component_type.get_singular_name().replace('_', " "),
+                        format!(
+                            "{}/{}/{}",
+                            layer_path,
+                            component_type,
+                            component.get_value().component_name.get_value()
+                        )
+                    );
+                }
+            }
+        }
+    }
+
+    // Items nested under an already reported path are left out, so a missing
+    // bounded context is reported once instead of once per component.
+    fn get_drift_items(
+        items: &[DriftItemReadModel],
+        other_items: &[DriftItemReadModel]
+    ) -> Vec<DriftItemReadModel> {
+        let mut drift_items: Vec<DriftItemReadModel> = vec![];
+        for item in items {
+            let is_known = other_items.iter().any(|other_item| other_item.path == item.path);
+            let is_nested = drift_items
+                .iter()
+                .any(|drift_item| item.path.starts_with(&format!("{}/", drift_item.path)));
+            if !is_known && !is_nested {
+                drift_items.push(item.clone());
+            }
+        }
+        drift_items
+    }
+
+    async fn try_interact(
+        &self,
+        request_model: DiffModelRequestModel
+    ) -> Result<DiffModelResponseModel, Box<dyn Error + Send + Sync>> {
+        let spec_items = Self::get_spec_items(&request_model);
+        let mut project_items = vec![];
+        for bounded_context_read_model in self.find_repository.list_bounded_contexts().await? {
+            let bounded_context = self.repository.read_bounded_context(
+                &IdentityObject::new(bounded_context_read_model.name)
+            ).await?;
+            if let Some(bounded_context) = bounded_context {
+                Self::get_project_items(&bounded_context, &mut project_items);
+            }
+        }
+        Ok(DiffModelResponseModel {
+            missing_items: Self::get_drift_items(&spec_items, &project_items),
+            unexpected_items: Self::get_drift_items(&project_items, &spec_items),
+        })
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<DiffModelRequestModel> for DiffModelUseCase<'a> {
+    async fn interact(&self, request_model: DiffModelRequestModel) {
+        let result = self.try_interact(request_model).await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use crate::{
+    cli::bounded_context::domain::{
+        entities::bounded_context::BoundedContext,
+        enums::{ component_type::ComponentType, layer_name::LayerName },
+    },
+    core::application::{
+        use_case_input_port::UseCaseInputPort,
+        use_case_output_port::UseCaseOutputPort,
+    },
+};
+
+pub struct ExplainLayersRequestModel;
+
+pub struct LayerComponentTypeReadModel {
+    pub component_type: ComponentType,
+    pub layer_names: Vec<LayerName>,
+}
+
+pub struct LayerReadModel {
+    pub layer_name: LayerName,
+    pub component_types: Vec<LayerComponentTypeReadModel>,
+}
+
+pub struct ExplainLayersResponseModel {
+    pub layers: Vec<LayerReadModel>,
+}
+
+pub struct ExplainLayersUseCase<'a> {
+    output_port: &'a dyn UseCaseOutputPort<ExplainLayersResponseModel>,
+}
+
+impl<'a> ExplainLayersUseCase<'a> {
+    pub fn new(output_port: &'a dyn UseCaseOutputPort<ExplainLayersResponseModel>) -> Self {
+        Self { output_port }
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<ExplainLayersRequestModel> for ExplainLayersUseCase<'a> {
+    async fn interact(&self, _request_model: ExplainLayersRequestModel) {
+        let layers = LayerName::get_layer_names()
+            .into_iter()
+            .map(|layer_name| LayerReadModel {
+                component_types: layer_name
+                    .get_component_types()
+                    .into_iter()
+                    .map(|component_type| LayerComponentTypeReadModel {
+                        layer_names: BoundedContext::get_component_layer_names(&component_type),
+                        component_type,
+                    })
+                    .collect(),
+                layer_name,
+            })
+            .collect();
+        self.output_port.success(ExplainLayersResponseModel { layers }).await;
+    }
+}
@@ -0,0 +1,147 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use crate::{
+    cli::bounded_context::{
+        application::queries::find_bounded_contexts_use_case::BoundedContextReadModel,
+        domain::{
+            enums::{ component_type::ComponentType, layer_name::LayerName },
+            repositories::{
+                bounded_context_repository::BoundedContextRepository,
+                find_bounded_contexts_repository::FindBoundedContextsRepository,
+            },
+        },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::{
+            entity::Entity,
+            identity_object::IdentityObject,
+            value_object::ValueObject,
+        },
+    },
+};
+
+pub struct ExportModelRequestModel;
+
+pub struct ModelComponentReadModel {
+    pub component_type: ComponentType,
+    pub component_name: String,
+}
+
+pub struct ModelLayerReadModel {
+    pub layer_name: LayerName,
+    pub components: Vec<ModelComponentReadModel>,
+}
+
+pub struct ModelAggregateReadModel {
+    pub name: String,
+    pub layers: Vec<ModelLayerReadModel>,
+}
+
+pub struct ModelBoundedContextReadModel {
+    pub name: String,
+    pub aggregates: Vec<ModelAggregateReadModel>,
+}
+
+pub struct ExportModelResponseModel {
+    pub bounded_contexts: Vec<ModelBoundedContextReadModel>,
+}
+
+pub struct ExportModelUseCase<'a> {
+    find_repository: &'a dyn FindBoundedContextsRepository<BoundedContextReadModel>,
+    repository: &'a dyn BoundedContextRepository,
+    output_port: &'a dyn UseCaseOutputPort<ExportModelResponseModel>,
+}
+
+impl<'a> ExportModelUseCase<'a> {
+    pub fn new(
+        find_repository: &'a dyn FindBoundedContextsRepository<BoundedContextReadModel>,
+        repository: &'a dyn BoundedContextRepository,
+        output_port: &'a dyn UseCaseOutputPort<ExportModelResponseModel>
+    ) -> Self {
+        Self {
+            find_repository,
+            repository,
+            output_port,
+        }
+    }
+
+    async fn try_interact(&self) -> Result<ExportModelResponseModel, Box<dyn Error + Send + Sync>> {
+        let mut bounded_contexts = vec![];
+        for bounded_context_read_model in self.find_repository.list_bounded_contexts().await? {
+            let Some(bounded_context) = self.repository.read_bounded_context(
+                &IdentityObject::new(bounded_context_read_model.name)
+            ).await? else {
+                continue;
+            };
+            bounded_contexts.push(ModelBoundedContextReadModel {
+                name: bounded_context.get_id().to_string(),
+                aggregates: bounded_context.aggregates
+                    .iter()
+                    .map(|aggregate| ModelAggregateReadModel {
+                        name: aggregate.get_id().to_string(),
+                        layers: LayerName::get_layer_names()
+                            .into_iter()
+                            .filter_map(|layer_name| {
+                                aggregate
+                                    .get_layers()
+                                    .iter()
+                                    .find(|layer| layer.get_value().name == layer_name)
+                            })
+                            .map(|layer| {
+                                let component_types = layer.get_value().name.get_component_types();
+                                let mut components = layer
+                                    .get_value()
+                                    .components.iter()
+                                    .map(|component| ModelComponentReadModel {
+                                        component_type: component
+                                            .get_value()
+                                            .component_type.clone(),
+                                        component_name: component
+                                            .get_value()
+                                            .component_name.get_value()
+                                            .clone(),
+                                    })
+                                    .collect::<Vec<_>>();
+                                components.sort_by_key(|component| {
+                                    (
+                                        component_types
+                                            .iter()
+                                            .position(|component_type| {
+                                                component_type == &component.component_type
+                                            }),
+                                        component.component_name.clone(),
+                                    )
+                                });
+                                ModelLayerReadModel {
+                                    layer_name: layer.get_value().name.clone(),
+                                    components,
+                                }
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            });
+        }
+        Ok(ExportModelResponseModel { bounded_contexts })
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<ExportModelRequestModel> for ExportModelUseCase<'a> {
+    async fn interact(&self, _request_model: ExportModelRequestModel) {
+        let result = self.try_interact().await;
+        match result {
+            Ok(response_model) => {
+                self.output_port.success(response_model).await;
+            }
+            Err(error) => {
+                self.output_port.failure(error).await;
+            }
+        }
+    }
+}
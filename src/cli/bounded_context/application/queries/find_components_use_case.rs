@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use crate::{
+    cli::bounded_context::{
+        application::queries::find_project_tree_use_case::BoundedContextTreeReadModel,
+        domain::{
+            enums::{ component_type::ComponentType, layer_name::LayerName },
+            repositories::find_project_tree_repository::FindProjectTreeRepository,
+            value_objects::component_filter::{ ComponentFilter, ComponentFilterValue },
+        },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::value_object::ValueObject,
+    },
+};
+
+pub struct FindComponentsRequestModel {
+    pub bounded_context_name: Option<String>,
+    pub layer_name: Option<LayerName>,
+    pub component_type: Option<ComponentType>,
+}
+
+pub struct ComponentReadModel {
+    pub bounded_context_name: String,
+    pub aggregate_name: String,
+    pub layer_name: LayerName,
+    pub component_type: ComponentType,
+    pub component_name: String,
+}
+
+pub struct FindComponentsResponseModel {
+    pub components: Vec<ComponentReadModel>,
+}
+
+pub struct FindComponentsUseCase<'a> {
+    repository: &'a dyn FindProjectTreeRepository<BoundedContextTreeReadModel>,
+    output_port: &'a dyn UseCaseOutputPort<FindComponentsResponseModel>,
+}
+
+impl<'a> FindComponentsUseCase<'a> {
+    pub fn new(
+        repository: &'a dyn FindProjectTreeRepository<BoundedContextTreeReadModel>,
+        output_port: &'a dyn UseCaseOutputPort<FindComponentsResponseModel>
+    ) -> Self {
+        Self {
+            repository,
+            output_port,
+        }
+    }
+
+    fn flatten_components(
+        &self,
+        bounded_contexts: &[BoundedContextTreeReadModel]
+    ) -> Vec<ComponentReadModel> {
+        let mut components = vec![];
+        bounded_contexts.iter().for_each(|bounded_context| {
+            bounded_context.aggregates.iter().for_each(|aggregate| {
+                aggregate.layers.iter().for_each(|layer| {
+                    layer.component_types.iter().for_each(|component_type| {
+                        component_type.components.iter().for_each(|component_name| {
+                            components.push(ComponentReadModel {
+                                bounded_context_name: bounded_context.name.clone(),
+                                aggregate_name: aggregate.name.clone(),
+                                layer_name: layer.layer_name.clone(),
+                                component_type: component_type.component_type.clone(),
+                                component_name: component_name.clone(),
+                            });
+                        });
+                    });
+                });
+            });
+        });
+        components
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<FindComponentsRequestModel> for FindComponentsUseCase<'a> {
+    async fn interact(&self, request_model: FindComponentsRequestModel) {
+        let bounded_contexts = self.repository.find_project_tree().await;
+        match bounded_contexts {
+            Ok(bounded_contexts) => {
+                let filter = ComponentFilter::new(ComponentFilterValue {
+                    bounded_context_name: request_model.bounded_context_name,
+                    layer_name: request_model.layer_name,
+                    component_type: request_model.component_type,
+                });
+                let components = self
+                    .flatten_components(&bounded_contexts)
+                    .into_iter()
+                    .filter(|component| {
+                        filter.matches(
+                            &component.bounded_context_name,
+                            &component.layer_name,
+                            &component.component_type
+                        )
+                    })
+                    .collect();
+                self.output_port.success(FindComponentsResponseModel { components }).await;
+            }
+            Err(error) => {
+                self.output_port.failure(Box::new(error)).await;
+            }
+        }
+    }
+}
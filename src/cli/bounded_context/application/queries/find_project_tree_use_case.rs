@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use crate::{
+    cli::bounded_context::domain::{
+        enums::{ component_type::ComponentType, layer_name::LayerName },
+        repositories::find_project_tree_repository::FindProjectTreeRepository,
+        value_objects::component_filter::{ ComponentFilter, ComponentFilterValue },
+    },
+    core::{
+        application::{
+            use_case_input_port::UseCaseInputPort,
+            use_case_output_port::UseCaseOutputPort,
+        },
+        domain::models::value_object::ValueObject,
+    },
+};
+
+pub struct FindProjectTreeRequestModel {
+    pub bounded_context_name: Option<String>,
+    pub layer_name: Option<LayerName>,
+    pub component_type: Option<ComponentType>,
+}
+
+#[derive(Clone)]
+pub struct ComponentTypeTreeReadModel {
+    pub component_type: ComponentType,
+    pub components: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct LayerTreeReadModel {
+    pub layer_name: LayerName,
+    pub component_types: Vec<ComponentTypeTreeReadModel>,
+}
+
+#[derive(Clone)]
+pub struct AggregateTreeReadModel {
+    pub name: String,
+    pub layers: Vec<LayerTreeReadModel>,
+}
+
+#[derive(Clone)]
+pub struct BoundedContextTreeReadModel {
+    pub name: String,
+    pub aggregates: Vec<AggregateTreeReadModel>,
+}
+
+pub struct FindProjectTreeResponseModel {
+    pub bounded_contexts: Vec<BoundedContextTreeReadModel>,
+}
+
+pub struct FindProjectTreeUseCase<'a> {
+    repository: &'a dyn FindProjectTreeRepository<BoundedContextTreeReadModel>,
+    output_port: &'a dyn UseCaseOutputPort<FindProjectTreeResponseModel>,
+}
+
+impl<'a> FindProjectTreeUseCase<'a> {
+    pub fn new(
+        repository: &'a dyn FindProjectTreeRepository<BoundedContextTreeReadModel>,
+        output_port: &'a dyn UseCaseOutputPort<FindProjectTreeResponseModel>
+    ) -> Self {
+        Self {
+            repository,
+            output_port,
+        }
+    }
+
+    fn filter_bounded_contexts(
+        &self,
+        bounded_contexts: Vec<BoundedContextTreeReadModel>,
+        filter: &ComponentFilter
+    ) -> Vec<BoundedContextTreeReadModel> {
+        let is_pruning = filter.is_pruning();
+        bounded_contexts
+            .into_iter()
+            .filter(|bounded_context| filter.matches_bounded_context(&bounded_context.name))
+            .map(|bounded_context| BoundedContextTreeReadModel {
+                aggregates: bounded_context.aggregates
+                    .into_iter()
+                    .map(|aggregate| AggregateTreeReadModel {
+                        layers: aggregate.layers
+                            .into_iter()
+                            .filter(|layer| filter.matches_layer(&layer.layer_name))
+                            .map(|layer| LayerTreeReadModel {
+                                component_types: layer.component_types
+                                    .into_iter()
+                                    .filter(|component_type| {
+                                        filter.matches_component_type(
+                                            &component_type.component_type
+                                        )
+                                    })
+                                    .collect(),
+                                layer_name: layer.layer_name,
+                            })
+                            .filter(|layer| {
+                                filter.get_value().component_type.is_none() ||
+                                    !layer.component_types.is_empty()
+                            })
+                            .collect(),
+                        name: aggregate.name,
+                    })
+                    .filter(|aggregate| !is_pruning || !aggregate.layers.is_empty())
+                    .collect(),
+                name: bounded_context.name,
+            })
+            .filter(|bounded_context| !is_pruning || !bounded_context.aggregates.is_empty())
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<'a> UseCaseInputPort<FindProjectTreeRequestModel> for FindProjectTreeUseCase<'a> {
+    async fn interact(&self, request_model: FindProjectTreeRequestModel) {
+        let bounded_contexts = self.repository.find_project_tree().await;
+        match bounded_contexts {
+            Ok(bounded_contexts) => {
+                self.output_port.success(FindProjectTreeResponseModel {
+                    bounded_contexts: self.filter_bounded_contexts(
+                        bounded_contexts,
+                        &ComponentFilter::new(ComponentFilterValue {
+                            bounded_context_name: request_model.bounded_context_name,
+                            layer_name: request_model.layer_name,
+                            component_type: request_model.component_type,
+                        })
+                    ),
+                }).await;
+            }
+            Err(error) => {
+                self.output_port.failure(Box::new(error)).await;
+            }
+        }
+    }
+}
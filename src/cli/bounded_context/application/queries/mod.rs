@@ -1 +1,6 @@
+pub mod diff_model_use_case;
+pub mod explain_layers_use_case;
+pub mod export_model_use_case;
 pub mod find_bounded_contexts_use_case;
+pub mod find_components_use_case;
+pub mod find_project_tree_use_case;
@@ -1,8 +1,13 @@
 use anyhow::Result;
 use crate::{
     cli::bounded_context::domain::{
-        enums::layer_name::LayerName,
-        value_objects::{ aggregate_layer::AggregateLayer, layer_component::LayerComponent },
+        enums::{ component_type::ComponentType, layer_name::LayerName },
+        value_objects::{
+            aggregate_layer::{ AggregateLayer, AggregateLayerValue },
+            component_method::ComponentMethod,
+            component_name::ComponentName,
+            layer_component::LayerComponent,
+        },
     },
     core::domain::models::{
         entity::Entity,
@@ -25,11 +30,49 @@ impl Aggregate {
         &self.layers
     }
 
+    pub fn get_component_names(&self, component_type: &ComponentType) -> Vec<ComponentName> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.get_value().components.iter())
+            .filter(|component| component_type.eq(&component.get_value().component_type))
+            .map(|component| component.get_value().component_name.clone())
+            .collect()
+    }
+
+    pub fn get_layer_component_names(
+        &self,
+        layer_name: &LayerName,
+        component_type: &ComponentType
+    ) -> Vec<ComponentName> {
+        self.layers
+            .iter()
+            .filter(|layer| layer_name.eq(&layer.get_value().name))
+            .flat_map(|layer| layer.get_value().components.iter())
+            .filter(|component| component_type.eq(&component.get_value().component_type))
+            .map(|component| component.get_value().component_name.clone())
+            .collect()
+    }
+
+    pub fn has_layer_component(&self, layer_name: &LayerName, component: &LayerComponent) -> bool {
+        self.layers
+            .iter()
+            .filter(|layer| layer_name.eq(&layer.get_value().name))
+            .any(|layer| layer.get_value().components.iter().any(|c| c.is_equal(component)))
+    }
+
     pub fn add_layer_component(
         &mut self,
         layer_name: LayerName,
         component: LayerComponent
     ) -> Result<()> {
+        if !self.layers.iter().any(|layer| layer_name.eq(&layer.get_value().name)) {
+            self.layers.push(
+                AggregateLayer::new(AggregateLayerValue {
+                    name: layer_name.clone(),
+                    components: vec![],
+                })
+            );
+        }
         let layer = self.layers
             .iter_mut()
             .find(|layer| layer_name.eq(&layer.get_value().name))
@@ -37,6 +80,19 @@ impl Aggregate {
         layer.add_component(component)?;
         Ok(())
     }
+
+    pub fn add_layer_component_method(
+        &mut self,
+        layer_name: &LayerName,
+        component: &LayerComponent,
+        method: ComponentMethod
+    ) -> Result<()> {
+        self.layers
+            .iter_mut()
+            .find(|layer| layer_name.eq(&layer.get_value().name))
+            .ok_or_else(|| anyhow::anyhow!("Layer <{}> not found", layer_name))?
+            .add_component_method(component, method)
+    }
 }
 
 impl Entity<IdentityObject> for Aggregate {
@@ -1,11 +1,14 @@
 use anyhow::Result;
+use thiserror::Error;
 use crate::{
     cli::bounded_context::domain::{
         entities::aggregate::Aggregate,
         enums::{ component_type::ComponentType, layer_name::LayerName },
         value_objects::{
             aggregate_layer::AggregateLayer,
+            component_method::ComponentMethod,
             component_name::ComponentName,
+            glossary::Glossary,
             layer_component::{ LayerComponent, LayerComponentValue },
         },
     },
@@ -20,14 +23,62 @@ use crate::{
     },
 };
 
+#[derive(Error, Debug)]
+pub enum BoundedContextError {
+    #[error(
+        "{component_type} do not belong to the {layer_name} layer (the {layer_name} layer accepts {})",
+        format_component_types(&layer_name.get_component_types())
+    )] InvalidLayerComponent {
+        component_type: ComponentType,
+        component_name: String,
+        layer_name: LayerName,
+    },
+}
+
+fn format_component_types(component_types: &[ComponentType]) -> String {
+    component_types
+        .iter()
+        .map(|component_type| component_type.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 pub struct BoundedContext {
     pub id: IdentityObject,
     pub aggregates: Vec<Aggregate>,
+    pub glossary: Option<Glossary>,
 }
 
 impl BoundedContext {
-    pub fn new(id: IdentityObject, aggregates: Vec<Aggregate>) -> Self {
-        Self { id, aggregates }
+    pub fn new(id: IdentityObject, aggregates: Vec<Aggregate>, glossary: Option<Glossary>) -> Self {
+        Self { id, aggregates, glossary }
+    }
+
+    pub fn set_glossary(&mut self, glossary: Option<Glossary>) {
+        self.glossary = glossary;
+    }
+
+    pub fn get_glossary_warnings(&self, name: &str) -> Vec<String> {
+        let Some(glossary) = &self.glossary else {
+            return vec![];
+        };
+        glossary
+            .find_forbidden_terms(name)
+            .iter()
+            .map(|usage| {
+                format!(
+                    "Name <{}> uses the forbidden term <{}>; the <{}> glossary prefers <{}>",
+                    name,
+                    usage.forbidden_term,
+                    self.id,
+                    usage.preferred_term
+                )
+            })
+            .collect()
+    }
+
+    pub fn get_aggregate(&self, aggregate_id: &IdentityObject) -> Option<&Aggregate> {
+        self.aggregates.iter().find(|aggregate| aggregate.get_id().is_equal(aggregate_id))
     }
 
     pub fn add_aggregate(
@@ -35,6 +86,7 @@ impl BoundedContext {
         aggregate_id: &IdentityObject,
         layers: &Vec<AggregateLayer>
     ) -> Result<()> {
+        self.validate_glossary_terms(aggregate_id.get_value())?;
         self.validate_aggregate_layers(layers)?;
         let aggregate = Aggregate::new(aggregate_id.to_owned(), layers.to_owned());
         self.validate_aggregate_id_duplication(&aggregate)?;
@@ -46,33 +98,79 @@ impl BoundedContext {
         &mut self,
         aggregate_id: &IdentityObject,
         component_type: ComponentType,
-        component_name: ComponentName
+        component_name: ComponentName,
+        layer_name: Option<LayerName>,
+        content: Option<String>
     ) -> Result<()> {
-        let layer_name = self.get_layer_name(&component_type);
+        self.validate_glossary_terms(component_name.get_value())?;
+        let layer_name = match layer_name {
+            Some(layer_name) => layer_name,
+            None => self.get_layer_name(&component_type),
+        };
+        self.validate_layer_component(&layer_name, &component_type, &component_name)?;
         let layer_component: LayerComponent = LayerComponent::new(LayerComponentValue {
             component_type,
             component_name,
+            content,
+            methods: vec![],
         });
         let aggregate = self.aggregates
             .iter_mut()
             .find(|aggregate| { aggregate.get_id().is_equal(aggregate_id) })
-            .unwrap();
+            .ok_or_else(|| anyhow::anyhow!("Aggregate <{}> not found", aggregate_id))?;
         aggregate.add_layer_component(layer_name, layer_component)?;
         Ok(())
     }
 
+    pub fn add_aggregate_component_method(
+        &mut self,
+        aggregate_id: &IdentityObject,
+        component_type: ComponentType,
+        component_name: ComponentName,
+        layer_name: LayerName,
+        method: ComponentMethod
+    ) -> Result<()> {
+        let layer_component = LayerComponent::new(LayerComponentValue {
+            component_type,
+            component_name,
+            content: None,
+            methods: vec![],
+        });
+        let aggregate = self.aggregates
+            .iter_mut()
+            .find(|aggregate| { aggregate.get_id().is_equal(aggregate_id) })
+            .ok_or_else(|| anyhow::anyhow!("Aggregate <{}> not found", aggregate_id))?;
+        if !aggregate.has_layer_component(&layer_name, &layer_component) {
+            return Err(
+                anyhow::anyhow!(
+                    "Component <{}> not found in the {} layer",
+                    layer_component.get_value().component_name.get_value(),
+                    layer_name
+                )
+            );
+        }
+        aggregate.add_layer_component_method(&layer_name, &layer_component, method)?;
+        Ok(())
+    }
+
+    pub fn get_component_layer_names(component_type: &ComponentType) -> Vec<LayerName> {
+        LayerName::get_layer_names()
+            .into_iter()
+            .filter(|layer_name| layer_name.get_component_types().contains(component_type))
+            .collect()
+    }
+
     fn get_layer_name(&self, component_type: &ComponentType) -> LayerName {
-        match component_type {
-            ComponentType::Entities => LayerName::Domain,
-            ComponentType::Events => LayerName::Domain,
-            ComponentType::ValueObjects => LayerName::Domain,
-            ComponentType::Repositories => LayerName::Domain,
-            ComponentType::Services => LayerName::Domain,
-            ComponentType::Commands => LayerName::Application,
-            ComponentType::Queries => LayerName::Application,
-            ComponentType::Subscribers => LayerName::Application,
-            ComponentType::Controllers => LayerName::Adapters,
-            ComponentType::Presenters => LayerName::Adapters,
+        Self::get_component_layer_names(component_type).remove(0)
+    }
+
+    fn validate_glossary_terms(&self, name: &str) -> Result<()> {
+        let is_strict = self.glossary
+            .as_ref()
+            .is_some_and(|glossary| glossary.get_value().is_strict);
+        match self.get_glossary_warnings(name).first() {
+            Some(warning) if is_strict => Err(anyhow::anyhow!("{}", warning)),
+            _ => Ok(()),
         }
     }
 
@@ -87,76 +185,36 @@ impl BoundedContext {
         Ok(())
     }
 
+    fn validate_layer_component(
+        &self,
+        layer_name: &LayerName,
+        component_type: &ComponentType,
+        component_name: &ComponentName
+    ) -> Result<()> {
+        if layer_name.get_component_types().contains(component_type) {
+            return Ok(());
+        }
+        Err(
+            (BoundedContextError::InvalidLayerComponent {
+                component_type: component_type.clone(),
+                component_name: component_name.get_value().to_string(),
+                layer_name: layer_name.clone(),
+            }).into()
+        )
+    }
+
     fn validate_aggregate_layers(&self, layers: &[AggregateLayer]) -> Result<()> {
         layers.iter().try_for_each(|layer| {
-            let components = &layer.get_value().components;
-            match layer.get_value().name {
-                LayerName::Domain => {
-                    let available_components = [
-                        ComponentType::Entities,
-                        ComponentType::Events,
-                        ComponentType::ValueObjects,
-                        ComponentType::Repositories,
-                        ComponentType::Services,
-                    ];
-                    if
-                        !components
-                            .iter()
-                            .all(|component| {
-                                available_components.contains(&component.get_value().component_type)
-                            })
-                    {
-                        return Err(anyhow::anyhow!("Invalid components for Domain layer"));
-                    }
-                }
-                LayerName::Application => {
-                    let available_components = [
-                        ComponentType::Commands,
-                        ComponentType::Queries,
-                        ComponentType::Subscribers,
-                    ];
-                    if
-                        !components
-                            .iter()
-                            .all(|component| {
-                                available_components.contains(&component.get_value().component_type)
-                            })
-                    {
-                        return Err(anyhow::anyhow!("Invalid components for Application layer"));
-                    }
-                }
-                LayerName::Adapters => {
-                    let available_components = [
-                        ComponentType::Controllers,
-                        ComponentType::Presenters,
-                    ];
-                    if
-                        !components
-                            .iter()
-                            .all(|component| {
-                                available_components.contains(&component.get_value().component_type)
-                            })
-                    {
-                        return Err(anyhow::anyhow!("Invalid components for Adapters layer"));
-                    }
-                }
-                LayerName::Infrastructure => {
-                    let available_components = [
-                        ComponentType::Repositories,
-                        ComponentType::Services,
-                    ];
-                    if
-                        !components
-                            .iter()
-                            .all(|component| {
-                                available_components.contains(&component.get_value().component_type)
-                            })
-                    {
-                        return Err(anyhow::anyhow!("Invalid components for Infrastructure layer"));
-                    }
-                }
-            }
-            Ok(())
+            layer
+                .get_value()
+                .components.iter()
+                .try_for_each(|component| {
+                    self.validate_layer_component(
+                        &layer.get_value().name,
+                        &component.get_value().component_type,
+                        &component.get_value().component_name
+                    )
+                })
         })
     }
 }
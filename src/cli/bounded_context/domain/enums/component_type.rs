@@ -1,9 +1,8 @@
 use std::fmt;
-use std::cmp::PartialEq;
 use std::str::FromStr;
 use anyhow::Error;
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ComponentType {
     Controllers,
     Presenters,
@@ -18,9 +17,22 @@ pub enum ComponentType {
     // Custom(String),
 }
 
-impl PartialEq for ComponentType {
-    fn eq(&self, _other: &Self) -> bool {
-        matches!(self, _other)
+impl ComponentType {
+    pub fn get_singular_name(&self) -> String {
+        (
+            match self {
+                ComponentType::Controllers => "controller",
+                ComponentType::Presenters => "presenter",
+                ComponentType::Commands => "command",
+                ComponentType::Queries => "query",
+                ComponentType::Subscribers => "subscriber",
+                ComponentType::Entities => "entity",
+                ComponentType::Events => "event",
+                ComponentType::Repositories => "repository",
+                ComponentType::Services => "service",
+                ComponentType::ValueObjects => "value_object",
+            }
+        ).to_string()
     }
 }
 
@@ -2,8 +2,9 @@ use std::fmt;
 use std::cmp::PartialEq;
 use std::str::FromStr;
 use anyhow::Error;
+use crate::cli::bounded_context::domain::enums::component_type::ComponentType;
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum LayerName {
     Domain,
     Application,
@@ -11,6 +12,29 @@ pub enum LayerName {
     Infrastructure,
 }
 
+impl LayerName {
+    pub fn get_layer_names() -> Vec<LayerName> {
+        vec![LayerName::Domain, LayerName::Application, LayerName::Adapters, LayerName::Infrastructure]
+    }
+
+    pub fn get_component_types(&self) -> Vec<ComponentType> {
+        match self {
+            LayerName::Domain =>
+                vec![
+                    ComponentType::Entities,
+                    ComponentType::Events,
+                    ComponentType::ValueObjects,
+                    ComponentType::Repositories,
+                    ComponentType::Services
+                ],
+            LayerName::Application =>
+                vec![ComponentType::Commands, ComponentType::Queries, ComponentType::Subscribers],
+            LayerName::Adapters => vec![ComponentType::Controllers, ComponentType::Presenters],
+            LayerName::Infrastructure => vec![ComponentType::Repositories, ComponentType::Services],
+        }
+    }
+}
+
 impl fmt::Display for LayerName {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -0,0 +1,12 @@
+use thiserror::Error;
+use async_trait::async_trait;
+
+#[derive(Error, Debug)]
+pub enum FindProjectTreeRepositoryError {
+    #[error("An error occurred while trying to read the project tree: {0}")] ReadError(String),
+}
+
+#[async_trait]
+pub trait FindProjectTreeRepository<ReadModel>: Send + Sync {
+    async fn find_project_tree(&self) -> Result<Vec<ReadModel>, FindProjectTreeRepositoryError>;
+}
@@ -0,0 +1,19 @@
+use thiserror::Error;
+use async_trait::async_trait;
+use crate::{
+    cli::bounded_context::domain::value_objects::glossary::Glossary,
+    core::domain::models::identity_object::IdentityObject,
+};
+
+#[derive(Error, Debug)]
+pub enum GlossaryRepositoryError {
+    #[error("Error reading glossary: {0}")] ReadError(String),
+}
+
+#[async_trait]
+pub trait GlossaryRepository: Send + Sync {
+    async fn read_glossary(
+        &self,
+        bounded_context_id: &IdentityObject
+    ) -> Result<Option<Glossary>, GlossaryRepositoryError>;
+}
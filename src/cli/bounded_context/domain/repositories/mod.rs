@@ -1,2 +1,4 @@
 pub mod bounded_context_repository;
 pub mod find_bounded_contexts_repository;
+pub mod find_project_tree_repository;
+pub mod glossary_repository;
@@ -0,0 +1,18 @@
+use thiserror::Error;
+use crate::cli::bounded_context::domain::value_objects::component_specification::ComponentSpecification;
+
+#[derive(Error, Debug)]
+pub enum ComponentGeneratorError {
+    #[error("Invalid component specification: {0}")] InvalidSpecification(String),
+}
+
+pub trait ComponentGenerator: Send + Sync {
+    fn generate_component(
+        &self,
+        component_specification: &ComponentSpecification
+    ) -> Result<Option<String>, ComponentGeneratorError>;
+    fn generate_component_method(
+        &self,
+        component_specification: &ComponentSpecification
+    ) -> Result<String, ComponentGeneratorError>;
+}
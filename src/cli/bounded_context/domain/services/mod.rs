@@ -1 +1,2 @@
+pub mod component_generator;
 pub mod project_service;
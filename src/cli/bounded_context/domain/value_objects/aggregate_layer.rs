@@ -2,7 +2,10 @@ use anyhow::Result;
 use crate::core::domain::models::value_object::ValueObject;
 use crate::cli::bounded_context::domain::enums::layer_name::LayerName;
 
-use crate::cli::bounded_context::domain::value_objects::layer_component::LayerComponent;
+use crate::cli::bounded_context::domain::value_objects::{
+    component_method::ComponentMethod,
+    layer_component::LayerComponent,
+};
 
 #[derive(Clone)]
 pub struct AggregateLayerValue {
@@ -23,6 +26,18 @@ impl AggregateLayer {
         self.value.components.push(component);
         Ok(())
     }
+
+    pub fn add_component_method(
+        &mut self,
+        component: &LayerComponent,
+        method: ComponentMethod
+    ) -> Result<()> {
+        self.value.components
+            .iter_mut()
+            .find(|c| c.is_equal(component))
+            .ok_or_else(|| anyhow::anyhow!("Component not found"))?
+            .add_method(method)
+    }
 }
 
 impl ValueObject<AggregateLayerValue> for AggregateLayer {
@@ -0,0 +1,26 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct ComponentFieldValue {
+    pub name: String,
+    pub type_name: String,
+}
+
+#[derive(Clone)]
+pub struct ComponentField {
+    value: ComponentFieldValue,
+}
+
+impl ValueObject<ComponentFieldValue> for ComponentField {
+    fn new(value: ComponentFieldValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &ComponentFieldValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.name == other.value.name
+    }
+}
@@ -0,0 +1,86 @@
+use crate::core::domain::models::value_object::ValueObject;
+use crate::cli::bounded_context::domain::enums::{
+    component_type::ComponentType,
+    layer_name::LayerName,
+};
+
+#[derive(Clone)]
+pub struct ComponentFilterValue {
+    pub bounded_context_name: Option<String>,
+    pub layer_name: Option<LayerName>,
+    pub component_type: Option<ComponentType>,
+}
+
+#[derive(Clone)]
+pub struct ComponentFilter {
+    value: ComponentFilterValue,
+}
+
+impl ComponentFilter {
+    pub fn matches_bounded_context(&self, bounded_context_name: &str) -> bool {
+        self.value.bounded_context_name.iter().all(|name| name == bounded_context_name)
+    }
+
+    pub fn matches_layer(&self, layer_name: &LayerName) -> bool {
+        self.value.layer_name.iter().all(|name| name == layer_name)
+    }
+
+    pub fn matches_component_type(&self, component_type: &ComponentType) -> bool {
+        self.value.component_type.iter().all(|t| t == component_type)
+    }
+
+    pub fn matches(
+        &self,
+        bounded_context_name: &str,
+        layer_name: &LayerName,
+        component_type: &ComponentType
+    ) -> bool {
+        self.matches_bounded_context(bounded_context_name) &&
+            self.matches_layer(layer_name) &&
+            self.matches_component_type(component_type)
+    }
+
+    /// Whether branches left without components should be dropped.
+    pub fn is_pruning(&self) -> bool {
+        self.value.layer_name.is_some() || self.value.component_type.is_some()
+    }
+}
+
+impl ValueObject<ComponentFilterValue> for ComponentFilter {
+    fn new(value: ComponentFilterValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &ComponentFilterValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.bounded_context_name == other.value.bounded_context_name &&
+            self.value.layer_name == other.value.layer_name &&
+            self.value.component_type == other.value.component_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::domain::models::value_object::ValueObject;
+    use crate::cli::bounded_context::domain::{
+        enums::{ component_type::ComponentType, layer_name::LayerName },
+        value_objects::component_filter::{ ComponentFilter, ComponentFilterValue },
+    };
+
+    #[test]
+    fn should_match_only_the_requested_layer_and_component_type() {
+        let filter = ComponentFilter::new(ComponentFilterValue {
+            bounded_context_name: None,
+            layer_name: Some(LayerName::Domain),
+            component_type: Some(ComponentType::Entities),
+        });
+
+        assert!(filter.matches("sales", &LayerName::Domain, &ComponentType::Entities));
+        assert!(!filter.matches("sales", &LayerName::Application, &ComponentType::Entities));
+        assert!(!filter.matches("sales", &LayerName::Domain, &ComponentType::ValueObjects));
+        assert!(filter.is_pruning());
+    }
+}
@@ -0,0 +1,26 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct ComponentMethodValue {
+    pub name: String,
+    pub content: String,
+}
+
+#[derive(Clone)]
+pub struct ComponentMethod {
+    value: ComponentMethodValue,
+}
+
+impl ValueObject<ComponentMethodValue> for ComponentMethod {
+    fn new(value: ComponentMethodValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &ComponentMethodValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.name == other.value.name
+    }
+}
@@ -5,6 +5,56 @@ pub struct ComponentName {
     value: String,
 }
 
+impl ComponentName {
+    // `place_order_use_case` and `place_order` name the same use case.
+    pub fn get_use_case_name(&self) -> ComponentName {
+        ComponentName::new(
+            self.value.strip_suffix("_use_case").unwrap_or(&self.value).to_string()
+        )
+    }
+
+    // `PlaceOrder` and `place_order` name the same component.
+    pub fn get_snake_case_name(&self) -> ComponentName {
+        let mut snake_case_name = String::new();
+        let mut previous_character = '_';
+        for character in self.value.chars() {
+            if character.is_uppercase() && previous_character != '_' {
+                snake_case_name.push('_');
+            }
+            snake_case_name.extend(character.to_lowercase());
+            previous_character = character;
+        }
+        ComponentName::new(snake_case_name)
+    }
+
+    // `category` lists as `categories`, `box` as `boxes`.
+    pub fn get_plural_name(&self) -> ComponentName {
+        let value = &self.value;
+        let plural_name = match value.strip_suffix('y') {
+            Some(stem) if !stem.ends_with(['a', 'e', 'i', 'o', 'u']) => format!("{}ies", stem),
+            _ if ["s", "x", "z", "ch", "sh"].iter().any(|suffix| value.ends_with(suffix)) => {
+                format!("{}es", value)
+            }
+            _ => format!("{}s", value),
+        };
+        ComponentName::new(plural_name)
+    }
+
+    pub fn get_type_name(&self) -> String {
+        self.value
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let mut characters = word.chars();
+                match characters.next() {
+                    Some(first) => first.to_uppercase().chain(characters).collect::<String>(),
+                    None => String::new(),
+                }
+            })
+            .collect()
+    }
+}
+
 impl ValueObject<String> for ComponentName {
     fn new(value: String) -> Self {
         Self { value }
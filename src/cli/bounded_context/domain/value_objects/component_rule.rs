@@ -0,0 +1,26 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct ComponentRuleValue {
+    pub name: String,
+    pub argument: String,
+}
+
+#[derive(Clone)]
+pub struct ComponentRule {
+    value: ComponentRuleValue,
+}
+
+impl ValueObject<ComponentRuleValue> for ComponentRule {
+    fn new(value: ComponentRuleValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &ComponentRuleValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.name == other.value.name && self.value.argument == other.value.argument
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    cli::bounded_context::domain::{
+        enums::{ component_type::ComponentType, layer_name::LayerName },
+        value_objects::{
+            component_field::ComponentField,
+            component_name::ComponentName,
+            component_rule::ComponentRule,
+            subscribed_event::SubscribedEvent,
+        },
+    },
+    core::domain::models::value_object::ValueObject,
+};
+
+#[derive(Clone)]
+pub struct ComponentSpecificationValue {
+    pub bounded_context_name: String,
+    pub aggregate_name: String,
+    pub component_type: ComponentType,
+    pub component_name: ComponentName,
+    pub layer_name: Option<LayerName>,
+    pub fields: Vec<ComponentField>,
+    pub value_type: Option<String>,
+    pub rules: Vec<ComponentRule>,
+    pub is_aggregate_root: bool,
+    pub subscribed_event: Option<SubscribedEvent>,
+    pub value_object_names: Vec<ComponentName>,
+    pub event_names: Vec<ComponentName>,
+    pub repository_names: Vec<ComponentName>,
+    pub query_name: Option<ComponentName>,
+    pub use_case_type: Option<ComponentType>,
+    pub use_case_name: Option<ComponentName>,
+    pub entity_name: Option<ComponentName>,
+}
+
+#[derive(Clone)]
+pub struct ComponentSpecification {
+    value: ComponentSpecificationValue,
+}
+
+impl ValueObject<ComponentSpecificationValue> for ComponentSpecification {
+    fn new(value: ComponentSpecificationValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &ComponentSpecificationValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.bounded_context_name == other.value.bounded_context_name &&
+            self.value.aggregate_name == other.value.aggregate_name &&
+            self.value.component_type == other.value.component_type &&
+            self.value.component_name.is_equal(&other.value.component_name)
+    }
+}
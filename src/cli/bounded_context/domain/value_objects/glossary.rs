@@ -0,0 +1,154 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct GlossaryTerm {
+    pub name: String,
+    pub definition: Option<String>,
+    pub forbidden_synonyms: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct ForbiddenTermUsage {
+    pub forbidden_term: String,
+    pub preferred_term: String,
+}
+
+#[derive(Clone)]
+pub struct GlossaryValue {
+    pub terms: Vec<GlossaryTerm>,
+    pub is_strict: bool,
+}
+
+#[derive(Clone)]
+pub struct Glossary {
+    value: GlossaryValue,
+}
+
+impl Glossary {
+    pub fn find_forbidden_terms(&self, name: &str) -> Vec<ForbiddenTermUsage> {
+        let name_words = self.split_words(name);
+        self.value.terms
+            .iter()
+            .flat_map(|term| {
+                term.forbidden_synonyms
+                    .iter()
+                    .filter(|synonym| self.contains_words(&name_words, &self.split_words(synonym)))
+                    .map(|synonym| ForbiddenTermUsage {
+                        forbidden_term: synonym.clone(),
+                        preferred_term: term.name.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    fn contains_words(&self, name_words: &[String], term_words: &[String]) -> bool {
+        if term_words.is_empty() || term_words.len() > name_words.len() {
+            return false;
+        }
+        name_words.windows(term_words.len()).any(|window| {
+            window
+                .iter()
+                .zip(term_words)
+                .all(|(word, term_word)| {
+                    word == term_word ||
+                        word.strip_suffix('s').is_some_and(|singular| singular == term_word)
+                })
+        })
+    }
+
+    fn split_words(&self, name: &str) -> Vec<String> {
+        let mut words = vec![];
+        let mut word = String::new();
+        let mut previous: Option<char> = None;
+        for c in name.chars() {
+            let is_boundary =
+                !c.is_alphanumeric() ||
+                (c.is_uppercase() && previous.is_some_and(|p| p.is_lowercase() || p.is_numeric()));
+            if is_boundary && !word.is_empty() {
+                words.push(word.to_lowercase());
+                word.clear();
+            }
+            if c.is_alphanumeric() {
+                word.push(c);
+            }
+            previous = Some(c);
+        }
+        if !word.is_empty() {
+            words.push(word.to_lowercase());
+        }
+        words
+    }
+}
+
+impl ValueObject<GlossaryValue> for Glossary {
+    fn new(value: GlossaryValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &GlossaryValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.is_strict == other.value.is_strict &&
+            self.value.terms.len() == other.value.terms.len() &&
+            self.value.terms
+                .iter()
+                .zip(&other.value.terms)
+                .all(|(a, b)| a.name == b.name && a.forbidden_synonyms == b.forbidden_synonyms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::bounded_context::domain::value_objects::glossary::{
+            Glossary,
+            GlossaryTerm,
+            GlossaryValue,
+        },
+        core::domain::models::value_object::ValueObject,
+    };
+
+    fn create_glossary() -> Glossary {
+        Glossary::new(GlossaryValue {
+            terms: vec![
+                GlossaryTerm {
+                    name: "Customer".to_string(),
+                    definition: None,
+                    forbidden_synonyms: vec!["Client".to_string()],
+                },
+                GlossaryTerm {
+                    name: "Order".to_string(),
+                    definition: None,
+                    forbidden_synonyms: vec!["Purchase Order".to_string()],
+                }
+            ],
+            is_strict: false,
+        })
+    }
+
+    #[test]
+    fn should_find_forbidden_terms_in_snake_and_pascal_case_names() {
+        let glossary = create_glossary();
+        let usages = ["client_repository", "ClientsPresenter", "PurchaseOrderPlaced"]
+            .iter()
+            .map(|name| {
+                glossary
+                    .find_forbidden_terms(name)
+                    .iter()
+                    .map(|usage| format!("{}->{}", usage.forbidden_term, usage.preferred_term))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(usages, vec!["Client->Customer", "Client->Customer", "Purchase Order->Order"]);
+    }
+
+    #[test]
+    fn should_not_match_forbidden_terms_inside_other_words() {
+        let glossary = create_glossary();
+        assert!(glossary.find_forbidden_terms("clientele_report").is_empty());
+        assert!(glossary.find_forbidden_terms("purchase").is_empty());
+    }
+}
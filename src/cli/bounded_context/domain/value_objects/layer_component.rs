@@ -1,14 +1,20 @@
+use anyhow::Result;
 use crate::{
     cli::bounded_context::domain::enums::component_type::ComponentType,
     core::domain::models::value_object::ValueObject,
 };
 
-use crate::cli::bounded_context::domain::value_objects::component_name::ComponentName;
+use crate::cli::bounded_context::domain::value_objects::{
+    component_method::ComponentMethod,
+    component_name::ComponentName,
+};
 
 #[derive(Clone)]
 pub struct LayerComponentValue {
     pub component_type: ComponentType,
     pub component_name: ComponentName,
+    pub content: Option<String>,
+    pub methods: Vec<ComponentMethod>,
 }
 
 #[derive(Clone)]
@@ -16,6 +22,16 @@ pub struct LayerComponent {
     value: LayerComponentValue,
 }
 
+impl LayerComponent {
+    pub fn add_method(&mut self, method: ComponentMethod) -> Result<()> {
+        if self.value.methods.iter().any(|m| m.is_equal(&method)) {
+            return Err(anyhow::anyhow!("Method already exists"));
+        }
+        self.value.methods.push(method);
+        Ok(())
+    }
+}
+
 impl ValueObject<LayerComponentValue> for LayerComponent {
     fn new(value: LayerComponentValue) -> Self {
         Self { value }
@@ -25,8 +41,55 @@ impl ValueObject<LayerComponentValue> for LayerComponent {
         &self.value
     }
 
+    // `place_order` and `place_order_use_case` generate the same use case, so
+    // commands and queries are told apart by their use case name.
     fn is_equal(&self, other: &Self) -> bool {
-        self.value.component_name.is_equal(&other.value.component_name) &&
+        let get_name = |component: &Self| {
+            match component.value.component_type {
+                ComponentType::Commands | ComponentType::Queries => {
+                    component.value.component_name.get_use_case_name()
+                }
+                _ => component.value.component_name.clone(),
+            }
+        };
+        get_name(self).is_equal(&get_name(other)) &&
             self.value.component_type.eq(&other.value.component_type)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::bounded_context::domain::{
+            enums::component_type::ComponentType,
+            value_objects::{
+                component_name::ComponentName,
+                layer_component::{ LayerComponent, LayerComponentValue },
+            },
+        },
+        core::domain::models::value_object::ValueObject,
+    };
+
+    fn create_component(component_type: ComponentType, component_name: &str) -> LayerComponent {
+        LayerComponent::new(LayerComponentValue {
+            component_type,
+            component_name: ComponentName::new(component_name.to_string()),
+            content: None,
+            methods: vec![],
+        })
+    }
+
+    #[test]
+    fn should_match_use_cases_with_and_without_their_suffix() {
+        assert!(
+            create_component(ComponentType::Commands, "place_order").is_equal(
+                &create_component(ComponentType::Commands, "place_order_use_case")
+            )
+        );
+        assert!(
+            !create_component(ComponentType::Entities, "order").is_equal(
+                &create_component(ComponentType::Entities, "order_use_case")
+            )
+        );
+    }
+}
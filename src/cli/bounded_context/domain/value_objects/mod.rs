@@ -1,3 +1,10 @@
 pub mod aggregate_layer;
+pub mod component_field;
+pub mod component_filter;
+pub mod component_method;
 pub mod component_name;
+pub mod component_rule;
+pub mod component_specification;
+pub mod glossary;
 pub mod layer_component;
+pub mod subscribed_event;
@@ -0,0 +1,27 @@
+use crate::core::domain::models::value_object::ValueObject;
+
+#[derive(Clone)]
+pub struct SubscribedEventValue {
+    pub event_name: String,
+    pub type_name: String,
+    pub path: String,
+}
+
+#[derive(Clone)]
+pub struct SubscribedEvent {
+    value: SubscribedEventValue,
+}
+
+impl ValueObject<SubscribedEventValue> for SubscribedEvent {
+    fn new(value: SubscribedEventValue) -> Self {
+        Self { value }
+    }
+
+    fn get_value(&self) -> &SubscribedEventValue {
+        &self.value
+    }
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self.value.event_name == other.value.event_name
+    }
+}
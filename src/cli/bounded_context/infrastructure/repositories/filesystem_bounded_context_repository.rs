@@ -1,19 +1,26 @@
-use std::{ path::Path, str::FromStr };
+use std::{ collections::BTreeMap, path::Path, str::FromStr };
 use anyhow::Result;
 use std::fs;
 use async_trait::async_trait;
 use crate::{
-    cli::bounded_context::domain::{
-        entities::{ aggregate::Aggregate, bounded_context::BoundedContext },
-        enums::component_type::ComponentType,
-        repositories::bounded_context_repository::{
-            BoundedContextRepository,
-            BoundedContextRepositoryError,
+    cli::bounded_context::{
+        domain::{
+            entities::{ aggregate::Aggregate, bounded_context::BoundedContext },
+            enums::{ component_type::ComponentType, layer_name::LayerName },
+            repositories::bounded_context_repository::{
+                BoundedContextRepository,
+                BoundedContextRepositoryError,
+            },
+            value_objects::{
+                aggregate_layer::{ AggregateLayer, AggregateLayerValue },
+                component_method::ComponentMethod,
+                component_name::ComponentName,
+                layer_component::{ LayerComponent, LayerComponentValue },
+            },
         },
-        value_objects::{
-            aggregate_layer::{ AggregateLayer, AggregateLayerValue },
-            component_name::ComponentName,
-            layer_component::{ LayerComponent, LayerComponentValue },
+        infrastructure::services::filesystem_directory_index::{
+            DirectoryEntries,
+            FilesystemDirectoryIndex,
         },
     },
     core::domain::models::{
@@ -77,6 +84,64 @@ impl FilesystemBoundedContextRepository {
         Ok(directory_names)
     }
 
+    // `core` holds the shared building blocks of the template, not an aggregate.
+    fn get_aggregates(
+        bounded_context_path: &str,
+        directories: &BTreeMap<String, DirectoryEntries>
+    ) -> Vec<Aggregate> {
+        let get_directory_names = |path: &str| {
+            directories
+                .get(path)
+                .map(|entries| entries.directories.clone())
+                .unwrap_or_default()
+        };
+        get_directory_names(bounded_context_path)
+            .iter()
+            .filter(|aggregate_dir| *aggregate_dir != "core")
+            .map(|aggregate_dir| {
+                let aggregate_path = format!("{}/{}", bounded_context_path, aggregate_dir);
+                let layers = get_directory_names(&aggregate_path)
+                    .iter()
+                    .filter_map(|layer| LayerName::from_str(layer).ok())
+                    .map(|layer_name| {
+                        let layer_path = format!("{}/{}", aggregate_path, layer_name);
+                        let layer_components = get_directory_names(&layer_path)
+                            .iter()
+                            .filter_map(|component_directory| {
+                                ComponentType::from_str(component_directory)
+                                    .ok()
+                                    .map(|component_type| (component_directory, component_type))
+                            })
+                            .flat_map(|(component_directory, component_type)| {
+                                directories
+                                    .get(&format!("{}/{}", layer_path, component_directory))
+                                    .map(|entries| entries.files.clone())
+                                    .unwrap_or_default()
+                                    .iter()
+                                    .filter_map(|file| file.strip_suffix(".rs"))
+                                    .filter(|name| *name != "mod")
+                                    .map(|name| {
+                                        LayerComponent::new(LayerComponentValue {
+                                            component_type: component_type.clone(),
+                                            component_name: ComponentName::new(name.to_string()),
+                                            content: None,
+                                            methods: vec![],
+                                        })
+                                    })
+                                    .collect::<Vec<_>>()
+                            })
+                            .collect::<Vec<_>>();
+                        AggregateLayer::new(AggregateLayerValue {
+                            name: layer_name,
+                            components: layer_components,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                Aggregate::new(IdentityObject::new(aggregate_dir.to_string()), layers)
+            })
+            .collect()
+    }
+
     fn get_dir_file_names(&self, path: &str) -> Result<Vec<String>> {
         let mut file_names: Vec<String> = fs
             ::read_dir(path)?
@@ -92,6 +157,104 @@ impl FilesystemBoundedContextRepository {
         file_names.sort();
         Ok(file_names)
     }
+
+    // `pub trait OrderRepository: Send + Sync {` and
+    // `impl OrderRepository for InMemoryOrderRepository {` open the blocks
+    // methods are added to.
+    fn is_block_of(line: &str, type_name: &str) -> bool {
+        let starts_with_type = |rest: &str| {
+            rest.split(|c: char| !c.is_alphanumeric() && c != '_').next() == Some(type_name)
+        };
+        match line.strip_prefix("pub trait ") {
+            Some(rest) => starts_with_type(rest),
+            None => {
+                line.starts_with("impl") &&
+                    line.split_once(" for ").is_some_and(|(_, rest)| starts_with_type(rest))
+            }
+        }
+    }
+
+    fn add_methods(
+        &self,
+        content: &str,
+        type_name: &str,
+        methods: &[ComponentMethod]
+    ) -> Result<String> {
+        let mut lines = content.lines().map(|line| line.to_string()).collect::<Vec<_>>();
+        let block_start = lines
+            .iter()
+            .position(|line| Self::is_block_of(line, type_name))
+            .ok_or_else(|| anyhow::anyhow!("no <{}> trait or implementation found", type_name))?;
+        for method in methods {
+            let value = method.get_value();
+            let block_end = lines[block_start..]
+                .iter()
+                .position(|line| line == "}")
+                .map(|position| block_start + position)
+                .ok_or_else(|| anyhow::anyhow!("the <{}> block is never closed", type_name))?;
+            let is_declared = lines[block_start..block_end]
+                .iter()
+                .any(|line| line.contains(&format!("fn {}(", value.name)));
+            if is_declared {
+                continue;
+            }
+            let mut method_lines = value.content
+                .lines()
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>();
+            if lines[block_end - 1] == "    }" {
+                method_lines.insert(0, String::new());
+            }
+            lines.splice(block_end..block_end, method_lines);
+        }
+        let mut content = lines.join("\n");
+        content.push('\n');
+        Ok(content)
+    }
+
+    // Methods go into components that usually exist already, so their files
+    // are prepared before anything is written.
+    fn get_method_writes(
+        &self,
+        bounded_context_path: &str,
+        bounded_context: &BoundedContext
+    ) -> Result<Vec<(String, String)>> {
+        let mut method_writes = vec![];
+        for aggregate in &bounded_context.aggregates {
+            for layer in aggregate.get_layers() {
+                for component in &layer.get_value().components {
+                    let value = component.get_value();
+                    if value.methods.is_empty() {
+                        continue;
+                    }
+                    let component_file_path = format!(
+                        "{}/{}/{}/{}/{}.rs",
+                        bounded_context_path,
+                        aggregate.get_id().get_value(),
+                        layer.get_value().name,
+                        value.component_type,
+                        value.component_name.get_value()
+                    );
+                    let content = match Path::new(&component_file_path).exists() {
+                        true => fs::read_to_string(&component_file_path)?,
+                        false => value.content.clone().unwrap_or_default(),
+                    };
+                    let type_name = value.component_name.get_type_name();
+                    let content = self
+                        .add_methods(&content, &type_name, &value.methods)
+                        .map_err(|error| {
+                            anyhow::anyhow!(
+                                "Cannot add methods to {}: {}",
+                                component_file_path,
+                                error
+                            )
+                        })?;
+                    method_writes.push((component_file_path, content));
+                }
+            }
+        }
+        Ok(method_writes)
+    }
 }
 
 #[async_trait]
@@ -105,6 +268,9 @@ impl BoundedContextRepository for FilesystemBoundedContextRepository {
             Self::SOURCE_DIR,
             &bounded_context.get_id().to_string()
         );
+        let method_writes = self
+            .get_method_writes(&bounded_context_path, bounded_context)
+            .map_err(|e| BoundedContextRepositoryError::WriteError(e.to_string()))?;
         self
             .create_directory(&bounded_context_path)
             .map_err(|e| BoundedContextRepositoryError::WriteError(e.to_string()))?;
@@ -132,7 +298,8 @@ impl BoundedContextRepository for FilesystemBoundedContextRepository {
                     );
                     self.create_directory(&component_path).unwrap();
                     if !Path::new(&component_file_path).exists() {
-                        fs::write(component_file_path, "").unwrap();
+                        let content = component.get_value().content.as_deref().unwrap_or_default();
+                        fs::write(component_file_path, content).unwrap();
                     }
                     let component_definitions = self.get_dir_file_names(&component_path).unwrap();
                     self.initialize_directory(&component_path, component_definitions).unwrap();
@@ -159,6 +326,11 @@ impl BoundedContextRepository for FilesystemBoundedContextRepository {
                 .map(|aggregate| aggregate.get_id().to_string())
                 .collect::<Vec<_>>()
         ).unwrap();
+        for (component_file_path, content) in method_writes {
+            fs
+                ::write(component_file_path, content)
+                .map_err(|e| BoundedContextRepositoryError::WriteError(e.to_string()))?;
+        }
         Ok(())
     }
 
@@ -179,95 +351,89 @@ impl BoundedContextRepository for FilesystemBoundedContextRepository {
                 BoundedContextRepositoryError::NotFound(bounded_context_id.get_value().to_string())
             );
         }
-        let aggregate_directories = fs
-            ::read_dir(bounded_context_path.clone())
-            .map_err(|e| BoundedContextRepositoryError::ReadError(e.to_string()))?
-            .filter_map(Result::ok)
-            .filter(|e|
-                e
-                    .file_type()
-                    .map(|t| t.is_dir())
-                    .unwrap_or(false)
-            )
-            .filter_map(|e| e.file_name().into_string().ok())
-            .filter(|name| name != "core")
-            .collect::<Vec<_>>();
-        let aggregates = aggregate_directories
+        let directories = FilesystemDirectoryIndex.scan(&bounded_context_path, 3).await.map_err(|e|
+            BoundedContextRepositoryError::ReadError(e.to_string())
+        )?;
+        let aggregates = Self::get_aggregates(&bounded_context_path, &directories);
+        Ok(Some(BoundedContext::new(bounded_context_id.clone(), aggregates, None)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use crate::{
+        cli::bounded_context::{
+            domain::value_objects::component_method::{ ComponentMethod, ComponentMethodValue },
+            infrastructure::{
+                repositories::filesystem_bounded_context_repository::{
+                    FilesystemBoundedContextRepository,
+                },
+                services::filesystem_directory_index::DirectoryEntries,
+            },
+        },
+        core::domain::models::{ entity::Entity, value_object::ValueObject },
+    };
+
+    fn get_entries(directories: &[&str]) -> DirectoryEntries {
+        DirectoryEntries {
+            modified: 0,
+            directories: directories.iter().map(|name| name.to_string()).collect(),
+            files: vec![],
+        }
+    }
+
+    #[test]
+    fn should_not_list_core_directory_as_aggregate() {
+        let directories = BTreeMap::from([
+            ("./src/sales".to_string(), get_entries(&["core", "order"])),
+            ("./src/sales/core".to_string(), get_entries(&["domain"])),
+            ("./src/sales/order".to_string(), get_entries(&["domain"])),
+        ]);
+        let aggregates = FilesystemBoundedContextRepository::get_aggregates(
+            "./src/sales",
+            &directories
+        );
+        let aggregate_names = aggregates
             .iter()
-            .map(|aggregate_dir| {
-                let aggregate_path = format!("{}/{}", bounded_context_path, aggregate_dir);
-                let layer_directories = fs
-                    ::read_dir(aggregate_path.clone())
-                    .unwrap()
-                    .filter_map(Result::ok)
-                    .filter(|e|
-                        e
-                            .file_type()
-                            .map(|t| t.is_dir())
-                            .unwrap_or(false)
-                    )
-                    .filter_map(|e| e.file_name().into_string().ok())
-                    .collect::<Vec<_>>();
-                let layers = layer_directories
-                    .iter()
-                    .map(|layer| {
-                        let layer_path = format!("{}/{}", aggregate_path, layer);
-                        let layer_component_directories = fs
-                            ::read_dir(layer_path.clone())
-                            .unwrap()
-                            .filter_map(Result::ok)
-                            .filter(|e|
-                                e
-                                    .file_type()
-                                    .map(|t| t.is_dir())
-                                    .unwrap_or(false)
-                            )
-                            .filter_map(|e| e.file_name().into_string().ok())
-                            .collect::<Vec<_>>();
-                        let mut layer_components = vec![];
-                        layer_component_directories
-                            .iter()
-                            .filter(|component_directory| {
-                                ComponentType::from_str(component_directory).is_ok()
-                            })
-                            .for_each(|component_directory| {
-                                let component_path = format!(
-                                    "{}/{}",
-                                    layer_path,
-                                    component_directory.clone()
-                                );
-                                let component_files = fs
-                                    ::read_dir(component_path)
-                                    .unwrap()
-                                    .filter_map(Result::ok)
-                                    .filter(|e|
-                                        e
-                                            .file_type()
-                                            .map(|t| t.is_file())
-                                            .unwrap_or(false)
-                                    )
-                                    .filter_map(|e| e.file_name().into_string().ok())
-                                    .collect::<Vec<_>>();
-                                component_files.iter().for_each(|file| {
-                                    layer_components.push(
-                                        LayerComponent::new(LayerComponentValue {
-                                            component_type: component_directory.parse().unwrap(),
-                                            component_name: ComponentName::new(
-                                                file.replace(".rs", "")
-                                            ),
-                                        })
-                                    )
-                                });
-                            });
-                        AggregateLayer::new(AggregateLayerValue {
-                            name: layer.parse().unwrap(),
-                            components: layer_components,
-                        })
-                    })
-                    .collect::<Vec<_>>();
-                Aggregate::new(IdentityObject::new(aggregate_dir.to_string()), layers)
-            })
+            .map(|aggregate| aggregate.get_id().to_string())
             .collect::<Vec<_>>();
-        Ok(Some(BoundedContext::new(bounded_context_id.clone(), aggregates)))
+        assert_eq!(aggregate_names, vec!["order"]);
+    }
+
+    #[test]
+    fn should_add_methods_at_the_end_of_their_block() {
+        let method = |content: &str| {
+            ComponentMethod::new(ComponentMethodValue {
+                name: "place_order".to_string(),
+                content: content.to_string(),
+            })
+        };
+        let repository = FilesystemBoundedContextRepository;
+        let content = repository.add_methods(
+            "pub trait OrderRepository: Send + Sync {\n    async fn get_order(&self);\n}\n",
+            "OrderRepository",
+            &[method("    async fn place_order(&self);\n")]
+        );
+        assert_eq!(
+            content.unwrap(),
+            "pub trait OrderRepository: Send + Sync {\n    async fn get_order(&self);\n    \
+            async fn place_order(&self);\n}\n"
+        );
+        let content = repository.add_methods(
+            "impl InMemoryOrderRepository {\n}\n\n\
+            impl OrderRepository for InMemoryOrderRepository {\n    \
+            async fn get_order(&self) {\n    }\n}\n",
+            "InMemoryOrderRepository",
+            &[method("    async fn place_order(&self) {\n        todo!()\n    }\n")]
+        );
+        assert_eq!(
+            content.unwrap(),
+            "impl InMemoryOrderRepository {\n}\n\n\
+            impl OrderRepository for InMemoryOrderRepository {\n    \
+            async fn get_order(&self) {\n    }\n\n    \
+            async fn place_order(&self) {\n        todo!()\n    }\n}\n"
+        );
+        assert!(repository.add_methods("", "OrderRepository", &[method("")]).is_err());
     }
 }
@@ -1,5 +1,3 @@
-use anyhow::Result;
-use std::fs;
 use async_trait::async_trait;
 use crate::cli::bounded_context::{
     domain::repositories::find_bounded_contexts_repository::{
@@ -7,6 +5,7 @@ use crate::cli::bounded_context::{
         FindBoundedContextsRepositoryError,
     },
     application::queries::find_bounded_contexts_use_case::BoundedContextReadModel,
+    infrastructure::services::filesystem_directory_index::FilesystemDirectoryIndex,
 };
 
 pub struct FilesystemFindBoundedContextsRepository;
@@ -21,41 +20,32 @@ for FilesystemFindBoundedContextsRepository {
     async fn list_bounded_contexts(
         &self
     ) -> Result<Vec<BoundedContextReadModel>, FindBoundedContextsRepositoryError> {
-        let mut bounded_contexts: Vec<BoundedContextReadModel> = vec![];
-        let bounded_context_directories = fs
-            ::read_dir(Self::SOURCE_DIR)
-            .map_err(|e| FindBoundedContextsRepositoryError::ListError(e.to_string()))?
-            .filter_map(Result::ok)
-            .filter(|e|
-                e
-                    .file_type()
-                    .map(|t| t.is_dir())
-                    .unwrap_or(false)
-            )
-            .filter_map(|e| e.file_name().into_string().ok())
-            .filter(|name| name != "core")
-            .collect::<Vec<_>>();
+        let directories = FilesystemDirectoryIndex.scan(Self::SOURCE_DIR, 1).await.map_err(|e|
+            FindBoundedContextsRepositoryError::ListError(e.to_string())
+        )?;
+        let bounded_context_directories = directories
+            .get(Self::SOURCE_DIR)
+            .map(|entries| entries.directories.clone())
+            .unwrap_or_default();
 
-        bounded_context_directories.iter().for_each(|name| {
-            let aggregate_directories = fs
-                ::read_dir(format!("{}/{}", Self::SOURCE_DIR, name))
-                .map_err(|e| FindBoundedContextsRepositoryError::ListError(e.to_string()))
-                .unwrap()
-                .filter_map(Result::ok)
-                .filter(|e|
-                    e
-                        .file_type()
-                        .map(|t| t.is_dir())
-                        .unwrap_or(false)
-                )
-                .filter_map(|e| e.file_name().into_string().ok())
-                .collect::<Vec<_>>();
-            bounded_contexts.push(BoundedContextReadModel {
-                name: name.to_string(),
-                aggregates: aggregate_directories,
-            });
-        });
-
-        Ok(bounded_contexts)
+        Ok(
+            bounded_context_directories
+                .into_iter()
+                .filter(|name| name != "core")
+                .map(|name| BoundedContextReadModel {
+                    aggregates: directories
+                        .get(&format!("{}/{}", Self::SOURCE_DIR, name))
+                        .map(|entries| {
+                            entries.directories
+                                .iter()
+                                .filter(|aggregate_dir| *aggregate_dir != "core")
+                                .cloned()
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    name,
+                })
+                .collect()
+        )
     }
 }
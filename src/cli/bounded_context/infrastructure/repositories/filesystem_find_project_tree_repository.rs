@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+use async_trait::async_trait;
+use crate::cli::bounded_context::{
+    application::queries::find_project_tree_use_case::{
+        AggregateTreeReadModel,
+        BoundedContextTreeReadModel,
+        ComponentTypeTreeReadModel,
+        LayerTreeReadModel,
+    },
+    domain::{
+        enums::layer_name::LayerName,
+        repositories::find_project_tree_repository::{
+            FindProjectTreeRepository,
+            FindProjectTreeRepositoryError,
+        },
+    },
+    infrastructure::services::filesystem_directory_index::{
+        DirectoryEntries,
+        FilesystemDirectoryIndex,
+    },
+};
+
+pub struct FilesystemFindProjectTreeRepository;
+
+impl FilesystemFindProjectTreeRepository {
+    const SOURCE_DIR: &'static str = "./src";
+
+    fn read_layer(
+        &self,
+        directories: &BTreeMap<String, DirectoryEntries>,
+        aggregate_path: &str,
+        layer_name: LayerName
+    ) -> LayerTreeReadModel {
+        let layer_path = format!("{}/{}", aggregate_path, layer_name);
+        let component_directories = directories
+            .get(&layer_path)
+            .map(|entries| entries.directories.clone())
+            .unwrap_or_default();
+        let component_types = layer_name
+            .get_component_types()
+            .into_iter()
+            .filter(|component_type| component_directories.contains(&component_type.to_string()))
+            .map(|component_type| ComponentTypeTreeReadModel {
+                components: directories
+                    .get(&format!("{}/{}", layer_path, component_type))
+                    .map(|entries| {
+                        entries.files
+                            .iter()
+                            .filter_map(|file| file.strip_suffix(".rs"))
+                            .filter(|name| *name != "mod")
+                            .map(|name| name.to_string())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                component_type,
+            })
+            .collect();
+        LayerTreeReadModel { layer_name, component_types }
+    }
+
+    fn read_aggregate(
+        &self,
+        directories: &BTreeMap<String, DirectoryEntries>,
+        bounded_context_path: &str,
+        name: &str
+    ) -> AggregateTreeReadModel {
+        let aggregate_path = format!("{}/{}", bounded_context_path, name);
+        let layer_directories = directories
+            .get(&aggregate_path)
+            .map(|entries| entries.directories.clone())
+            .unwrap_or_default();
+        let layers = LayerName::get_layer_names()
+            .into_iter()
+            .filter(|layer_name| layer_directories.contains(&layer_name.to_string()))
+            .map(|layer_name| self.read_layer(directories, &aggregate_path, layer_name))
+            .collect();
+        AggregateTreeReadModel { name: name.to_string(), layers }
+    }
+}
+
+#[async_trait]
+impl FindProjectTreeRepository<BoundedContextTreeReadModel> for FilesystemFindProjectTreeRepository {
+    async fn find_project_tree(
+        &self
+    ) -> Result<Vec<BoundedContextTreeReadModel>, FindProjectTreeRepositoryError> {
+        let directories = FilesystemDirectoryIndex.scan(Self::SOURCE_DIR, 4).await.map_err(|e|
+            FindProjectTreeRepositoryError::ReadError(e.to_string())
+        )?;
+        let bounded_context_names = directories
+            .get(Self::SOURCE_DIR)
+            .map(|entries| entries.directories.clone())
+            .unwrap_or_default();
+        Ok(
+            bounded_context_names
+                .into_iter()
+                .filter(|name| name != "core")
+                .map(|name| {
+                    let bounded_context_path = format!("{}/{}", Self::SOURCE_DIR, name);
+                    let aggregates = directories
+                        .get(&bounded_context_path)
+                        .map(|entries| {
+                            entries.directories
+                                .iter()
+                                .filter(|aggregate_dir| *aggregate_dir != "core")
+                                .map(|aggregate_name| {
+                                    self.read_aggregate(
+                                        &directories,
+                                        &bounded_context_path,
+                                        aggregate_name
+                                    )
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    BoundedContextTreeReadModel { name, aggregates }
+                })
+                .collect()
+        )
+    }
+}
@@ -0,0 +1,81 @@
+use std::{ fs, path::Path };
+use async_trait::async_trait;
+use serde::Deserialize;
+use crate::{
+    cli::bounded_context::domain::{
+        repositories::glossary_repository::{ GlossaryRepository, GlossaryRepositoryError },
+        value_objects::glossary::{ Glossary, GlossaryTerm, GlossaryValue },
+    },
+    core::domain::models::{ identity_object::IdentityObject, value_object::ValueObject },
+};
+
+#[derive(Deserialize, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum GlossaryEnforcement {
+    #[default]
+    Warn,
+    Fail,
+}
+
+#[derive(Deserialize)]
+struct GlossaryTermEntry {
+    name: String,
+    #[serde(default)]
+    definition: Option<String>,
+    #[serde(default)]
+    forbidden: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct GlossaryFile {
+    #[serde(default)]
+    enforcement: GlossaryEnforcement,
+    #[serde(default)]
+    terms: Vec<GlossaryTermEntry>,
+}
+
+pub struct FilesystemGlossaryRepository;
+
+impl FilesystemGlossaryRepository {
+    const SOURCE_DIR: &'static str = "./src";
+    const GLOSSARY_FILE_NAME: &'static str = "glossary.toml";
+}
+
+#[async_trait]
+impl GlossaryRepository for FilesystemGlossaryRepository {
+    async fn read_glossary(
+        &self,
+        bounded_context_id: &IdentityObject
+    ) -> Result<Option<Glossary>, GlossaryRepositoryError> {
+        let glossary_path = format!(
+            "{}/{}/{}",
+            Self::SOURCE_DIR,
+            bounded_context_id.get_value(),
+            Self::GLOSSARY_FILE_NAME
+        );
+        if !Path::new(&glossary_path).exists() {
+            return Ok(None);
+        }
+        let content = fs
+            ::read_to_string(&glossary_path)
+            .map_err(|e| GlossaryRepositoryError::ReadError(e.to_string()))?;
+        let glossary_file: GlossaryFile = toml
+            ::from_str(&content)
+            .map_err(|e| GlossaryRepositoryError::ReadError(format!("{}: {}", glossary_path, e)))?;
+        Ok(
+            Some(
+                Glossary::new(GlossaryValue {
+                    terms: glossary_file.terms
+                        .into_iter()
+                        .map(|term| GlossaryTerm {
+                            name: term.name,
+                            definition: term.definition,
+                            forbidden_synonyms: term.forbidden,
+                        })
+                        .collect(),
+                    is_strict: glossary_file.enforcement == GlossaryEnforcement::Fail,
+                })
+            )
+        )
+    }
+}
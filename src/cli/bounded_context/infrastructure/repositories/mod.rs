@@ -1,2 +1,4 @@
 pub mod filesystem_bounded_context_repository;
 pub mod filesystem_find_bounded_contexts_repository;
+pub mod filesystem_find_project_tree_repository;
+pub mod filesystem_glossary_repository;
@@ -0,0 +1,117 @@
+use std::{ collections::BTreeMap, time::UNIX_EPOCH };
+use anyhow::Result;
+use serde::{ Deserialize, Serialize };
+use tokio::{ fs, task::JoinSet };
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct DirectoryEntries {
+    pub modified: u128,
+    pub directories: Vec<String>,
+    pub files: Vec<String>,
+}
+
+// Keeps the listing of every scanned directory in `.arpeggio/index`, so that
+// directories whose modification time did not change are not read again.
+pub struct FilesystemDirectoryIndex;
+
+impl FilesystemDirectoryIndex {
+    const INDEX_DIR: &'static str = "./.arpeggio";
+    const INDEX_PATH: &'static str = "./.arpeggio/index";
+
+    pub async fn scan(
+        &self,
+        root_path: &str,
+        depth: usize
+    ) -> Result<BTreeMap<String, DirectoryEntries>> {
+        let mut index = self.read_index().await;
+        let mut is_changed = false;
+        let mut directories = BTreeMap::new();
+        let mut pending_paths = vec![root_path.to_string()];
+        for current_depth in 0..=depth {
+            let mut join_set = JoinSet::new();
+            for path in pending_paths.drain(..) {
+                let cached_entries = index.get(&path).cloned();
+                join_set.spawn(async move {
+                    let entries = Self::read_directory(&path, cached_entries).await;
+                    (path, entries)
+                });
+            }
+            while let Some(result) = join_set.join_next().await {
+                let (path, entries) = result?;
+                let (entries, is_fresh) = entries?;
+                is_changed |= is_fresh;
+                if current_depth < depth {
+                    pending_paths.extend(
+                        entries.directories.iter().map(|name| format!("{}/{}", path, name))
+                    );
+                }
+                directories.insert(path, entries);
+            }
+        }
+        let stale_paths = index
+            .keys()
+            .filter(|path| self.is_within(path, root_path, depth))
+            .filter(|path| !directories.contains_key(*path))
+            .cloned()
+            .collect::<Vec<_>>();
+        is_changed |= !stale_paths.is_empty();
+        stale_paths.iter().for_each(|path| {
+            index.remove(path);
+        });
+        if is_changed {
+            index.extend(directories.clone());
+            self.write_index(&index).await;
+        }
+        Ok(directories)
+    }
+
+    async fn read_directory(
+        path: &str,
+        cached_entries: Option<DirectoryEntries>
+    ) -> Result<(DirectoryEntries, bool)> {
+        let modified = fs::metadata(path).await?.modified()?.duration_since(UNIX_EPOCH)?.as_nanos();
+        if let Some(cached_entries) = cached_entries.filter(|entries| entries.modified == modified) {
+            return Ok((cached_entries, false));
+        }
+        let mut entries = DirectoryEntries { modified, ..Default::default() };
+        let mut read_dir = fs::read_dir(path).await?;
+        while let Some(entry) = read_dir.next_entry().await? {
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            if entry.file_type().await?.is_dir() {
+                entries.directories.push(name);
+            } else {
+                entries.files.push(name);
+            }
+        }
+        entries.directories.sort();
+        entries.files.sort();
+        Ok((entries, true))
+    }
+
+    fn is_within(&self, path: &str, root_path: &str, depth: usize) -> bool {
+        path == root_path ||
+            path
+                .strip_prefix(&format!("{}/", root_path))
+                .is_some_and(|relative_path| relative_path.split('/').count() <= depth)
+    }
+
+    async fn read_index(&self) -> BTreeMap<String, DirectoryEntries> {
+        // A missing or unreadable index is rebuilt from scratch.
+        match fs::read_to_string(Self::INDEX_PATH).await {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => BTreeMap::new(),
+        }
+    }
+
+    async fn write_index(&self, index: &BTreeMap<String, DirectoryEntries>) {
+        // The index is only a cache, so failing to persist it must not fail the command.
+        let Ok(content) = serde_json::to_string(index) else {
+            return;
+        };
+        if fs::create_dir_all(Self::INDEX_DIR).await.is_ok() {
+            let _ = fs::write(Self::INDEX_PATH, content).await;
+        }
+    }
+}
@@ -1 +1,3 @@
+pub mod filesystem_directory_index;
 pub mod filesystem_project_service;
+pub mod rust_component_generator;
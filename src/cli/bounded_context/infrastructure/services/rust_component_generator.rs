@@ -0,0 +1,2397 @@
+use std::collections::BTreeMap;
+use regex::Regex;
+use crate::{
+    cli::bounded_context::domain::{
+        enums::{ component_type::ComponentType, layer_name::LayerName },
+        services::component_generator::{ ComponentGenerator, ComponentGeneratorError },
+        value_objects::{
+            component_field::ComponentField,
+            component_name::ComponentName,
+            component_rule::ComponentRule,
+            component_specification::{ ComponentSpecification, ComponentSpecificationValue },
+            subscribed_event::SubscribedEvent,
+        },
+    },
+    core::domain::models::value_object::ValueObject,
+};
+
+// The names a query shares with its read model and find repository.
+struct QueryNames {
+    type_name: String,
+    method_name: String,
+    subject: String,
+    read_model: String,
+    error_variant: String,
+    description: String,
+}
+
+// What a CRUD use case adds to the use case template: the statements calling
+// its repository and the fields of its response.
+struct CrudUseCase {
+    imports: Vec<String>,
+    response_fields: Vec<(String, String)>,
+    statements: String,
+}
+
+// A validation rule of a value object, rendered as a guard in `try_new`.
+struct ValueRule {
+    variant: String,
+    message: String,
+    condition: String,
+    invalid_sample: Option<String>,
+}
+
+pub struct RustComponentGenerator;
+
+impl RustComponentGenerator {
+    const MAX_LINE_LENGTH: usize = 100;
+    const KNOWN_IMPORTS: [(&'static str, &'static str); 10] = [
+        ("IdentityObject", "crate::core::domain::models::identity_object::IdentityObject"),
+        ("Arc", "std::sync::Arc"),
+        ("BTreeMap", "std::collections::BTreeMap"),
+        ("BTreeSet", "std::collections::BTreeSet"),
+        ("Duration", "std::time::Duration"),
+        ("HashMap", "std::collections::HashMap"),
+        ("HashSet", "std::collections::HashSet"),
+        ("Instant", "std::time::Instant"),
+        ("Mutex", "std::sync::Mutex"),
+        ("SystemTime", "std::time::SystemTime"),
+    ];
+    const INTEGER_TYPES: [(&'static str, i128, i128); 12] = [
+        ("i8", i8::MIN as i128, i8::MAX as i128),
+        ("i16", i16::MIN as i128, i16::MAX as i128),
+        ("i32", i32::MIN as i128, i32::MAX as i128),
+        ("i64", i64::MIN as i128, i64::MAX as i128),
+        ("i128", i128::MIN, i128::MAX),
+        ("isize", i64::MIN as i128, i64::MAX as i128),
+        ("u8", 0, u8::MAX as i128),
+        ("u16", 0, u16::MAX as i128),
+        ("u32", 0, u32::MAX as i128),
+        ("u64", 0, u64::MAX as i128),
+        ("u128", 0, i128::MAX),
+        ("usize", 0, u64::MAX as i128),
+    ];
+    const FLOAT_TYPES: [&'static str; 2] = ["f32", "f64"];
+    // Tried in order to find the samples of the tests generated for a pattern.
+    const PATTERN_SAMPLES: [&'static str; 18] = [
+        "a",
+        "abc",
+        "A",
+        "Abc",
+        "ABC",
+        "1",
+        "123",
+        "a1",
+        "a-b",
+        "a_b",
+        "a.b",
+        "a b",
+        "user@example.com",
+        "https://example.com",
+        "",
+        " ",
+        "!",
+        "-",
+    ];
+
+    fn is_snake_case_identifier(name: &str) -> bool {
+        name.chars().next().is_some_and(|first| first.is_ascii_lowercase() || first == '_') &&
+            name
+                .chars()
+                .all(|character| {
+                    character.is_ascii_lowercase() || character.is_ascii_digit() || character == '_'
+                })
+    }
+
+    fn validate_fields(&self, fields: &[ComponentField]) -> Result<(), ComponentGeneratorError> {
+        for (index, field) in fields.iter().enumerate() {
+            let value = field.get_value();
+            if !Self::is_snake_case_identifier(&value.name) {
+                return Err(
+                    ComponentGeneratorError::InvalidSpecification(
+                        format!("field name <{}> is not a snake_case identifier", value.name)
+                    )
+                );
+            }
+            if value.type_name.trim().is_empty() {
+                return Err(
+                    ComponentGeneratorError::InvalidSpecification(
+                        format!("field <{}> has no type", value.name)
+                    )
+                );
+            }
+            if fields[..index].iter().any(|other| other.is_equal(field)) {
+                return Err(
+                    ComponentGeneratorError::InvalidSpecification(
+                        format!("field <{}> is declared more than once", value.name)
+                    )
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_options(
+        specification: &ComponentSpecificationValue,
+        supported_options: &[&str]
+    ) -> Result<(), ComponentGeneratorError> {
+        let options = [
+            ("--field", !specification.fields.is_empty()),
+            ("--type", specification.value_type.is_some()),
+            ("--rule", !specification.rules.is_empty()),
+            ("--on", specification.subscribed_event.is_some()),
+            ("--repository", !specification.repository_names.is_empty()),
+        ];
+        match
+            options
+                .iter()
+                .find(|(option, is_given)| *is_given && !supported_options.contains(option))
+        {
+            Some((option, _)) =>
+                Err(
+                    ComponentGeneratorError::InvalidSpecification(
+                        format!("{} is not supported for {}", option, specification.component_type)
+                    )
+                ),
+            None => Ok(()),
+        }
+    }
+
+    fn get_component_path(
+        specification: &ComponentSpecificationValue,
+        layer_name: &str,
+        component_type: &ComponentType,
+        component_name: &str
+    ) -> String {
+        format!(
+            "crate::{}::{}::{}::{}::{}",
+            specification.bounded_context_name,
+            specification.aggregate_name,
+            layer_name,
+            component_type,
+            component_name
+        )
+    }
+
+    // Identifiers written as part of a path (`std::time::SystemTime`) are
+    // left alone since they need no import.
+    fn get_referenced_types(type_name: &str) -> Vec<String> {
+        let mut referenced_types = vec![];
+        let mut identifier = String::new();
+        let mut is_path_segment = false;
+        for character in type_name.chars().chain(std::iter::once(' ')) {
+            if character.is_alphanumeric() || character == '_' {
+                identifier.push(character);
+                continue;
+            }
+            if character == ':' {
+                is_path_segment = true;
+                identifier.clear();
+                continue;
+            }
+            if !identifier.is_empty() && !is_path_segment {
+                referenced_types.push(identifier.clone());
+            }
+            identifier.clear();
+            is_path_segment = false;
+        }
+        referenced_types
+    }
+
+    fn get_type_imports(
+        &self,
+        specification: &ComponentSpecificationValue,
+        type_names: &[&str]
+    ) -> Vec<String> {
+        type_names
+            .iter()
+            .flat_map(|type_name| Self::get_referenced_types(type_name))
+            .filter_map(|referenced_type| {
+                let known_import = Self::KNOWN_IMPORTS
+                    .iter()
+                    .find(|(name, _)| *name == referenced_type)
+                    .map(|(_, path)| path.to_string());
+                let value_objects = specification.value_object_names
+                    .iter()
+                    .map(|name| (ComponentType::ValueObjects, name));
+                let events = specification.event_names
+                    .iter()
+                    .map(|name| (ComponentType::Events, name));
+                known_import.or_else(|| {
+                    value_objects
+                        .chain(events)
+                        .find(|(_, name)| name.get_type_name() == referenced_type)
+                        .map(|(component_type, name)| {
+                            format!(
+                                "{}::{}",
+                                Self::get_component_path(
+                                    specification,
+                                    "domain",
+                                    &component_type,
+                                    name.get_value()
+                                ),
+                                referenced_type
+                            )
+                        })
+                })
+            })
+            .collect()
+    }
+
+    // Imports sharing a parent module are merged into one `use` statement:
+    // `crate::core::domain::models::{ entity::Entity, value_object::ValueObject }`,
+    // and so are items of the same module: `repository::{ Repository, RepositoryError }`.
+    // `std` comes first, then external crates, then the project itself.
+    fn render_imports(imports: &[String]) -> String {
+        let mut groups: BTreeMap<(u8, String), Vec<String>> = BTreeMap::new();
+        for import in imports {
+            let segments = import.split("::").collect::<Vec<_>>();
+            let split_position = segments.len().saturating_sub(2).max(1);
+            let parent = segments[..split_position].join("::");
+            let item = segments[split_position..].join("::");
+            let origin = match segments[0] {
+                "std" => 0,
+                "crate" => 2,
+                _ => 1,
+            };
+            let items = groups.entry((origin, parent)).or_default();
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
+        groups
+            .into_iter()
+            .map(|((_, parent), items)| {
+                let mut modules: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+                for item in &items {
+                    let (module, name) = item.rsplit_once("::").unwrap_or(("", item));
+                    modules.entry(module).or_default().push(name);
+                }
+                let items = modules
+                    .into_iter()
+                    .flat_map(|(module, mut names)| {
+                        names.sort();
+                        match (module, names.len()) {
+                            ("", _) => names.iter().map(|name| name.to_string()).collect(),
+                            (_, 1) => vec![format!("{}::{}", module, names[0])],
+                            _ => vec![format!("{}::{{ {} }}", module, names.join(", "))],
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let line = match items.len() {
+                    1 => format!("use {}::{};\n", parent, items[0]),
+                    _ => format!("use {}::{{ {} }};\n", parent, items.join(", ")),
+                };
+                if line.len() <= Self::MAX_LINE_LENGTH {
+                    return line;
+                }
+                format!(
+                    "use {}::{{\n{}}};\n",
+                    parent,
+                    items
+                        .iter()
+                        .map(|item| format!("    {},\n", item))
+                        .collect::<String>()
+                )
+            })
+            .collect()
+    }
+
+    fn render_parameters(prefix: &str, parameters: &[String], suffix: &str) -> String {
+        let line = format!("{}{}{}", prefix, parameters.join(", "), suffix);
+        if line.len() <= Self::MAX_LINE_LENGTH {
+            return line;
+        }
+        let indentation = prefix.len() - prefix.trim_start().len();
+        format!(
+            "{}\n{}\n{}{}",
+            prefix,
+            parameters
+                .iter()
+                .map(|parameter| format!("{}    {}", " ".repeat(indentation), parameter))
+                .collect::<Vec<_>>()
+                .join(",\n"),
+            " ".repeat(indentation),
+            suffix
+        )
+    }
+
+    fn get_fields(specification: &ComponentSpecificationValue) -> Vec<(String, String)> {
+        specification.fields
+            .iter()
+            .map(|field| (field.get_value().name.clone(), field.get_value().type_name.clone()))
+            .collect()
+    }
+
+    fn render_getters(fields: &[(String, String)]) -> String {
+        fields
+            .iter()
+            .map(|(name, type_name)| {
+                format!(
+                    "\n    pub fn get_{}(&self) -> &{} {{\n        &self.{}\n    }}\n",
+                    name,
+                    type_name,
+                    name
+                )
+            })
+            .collect()
+    }
+
+    fn generate_entity(
+        &self,
+        specification: &ComponentSpecificationValue
+    ) -> Result<String, ComponentGeneratorError> {
+        self.validate_fields(&specification.fields)?;
+        let mut fields = Self::get_fields(specification);
+        if !fields.iter().any(|(name, _)| name == "id") {
+            fields.insert(0, ("id".to_string(), "IdentityObject".to_string()));
+        }
+        // `Entity` needs an id wrapping a `String`, which only `IdentityObject`
+        // is known to do.
+        let id_type = fields
+            .iter()
+            .find(|(name, _)| name == "id")
+            .map(|(_, type_name)| type_name.clone())
+            .unwrap_or_default();
+        if id_type != "IdentityObject" {
+            return Err(
+                ComponentGeneratorError::InvalidSpecification(
+                    format!("field <id> must be an IdentityObject, got <{}>", id_type)
+                )
+            );
+        }
+        let type_name = specification.component_name.get_type_name();
+        // Aggregate roots record their `<aggregate>_created` event on creation
+        // when the aggregate declares one.
+        let created_event_name = format!("{}_created", specification.component_name.get_value());
+        let created_event_type = specification.event_names
+            .iter()
+            .filter(|_| specification.is_aggregate_root)
+            .find(|event_name| *event_name.get_value() == created_event_name)
+            .map(|event_name| event_name.get_type_name());
+        let mut type_names = fields
+            .iter()
+            .map(|(_, type_name)| type_name.as_str())
+            .collect::<Vec<_>>();
+        type_names.extend(created_event_type.as_deref());
+        let mut imports = vec!["crate::core::domain::models::entity::Entity".to_string()];
+        if specification.is_aggregate_root {
+            imports.push("crate::core::domain::events::domain_event::DomainEvent".to_string());
+            imports.push("crate::core::domain::models::aggregate_root::AggregateRoot".to_string());
+        }
+        if created_event_type.is_some() {
+            imports.push("crate::core::domain::models::value_object::ValueObject".to_string());
+        }
+        imports.extend(self.get_type_imports(specification, &type_names));
+        let parameters = fields
+            .iter()
+            .map(|(name, type_name)| format!("{}: {}", name, type_name))
+            .collect::<Vec<_>>();
+        let mut content = format!(
+            "{}\npub struct {} {{\n{}{}}}\n\nimpl {} {{\n{} {{\n        Self {{\n\
+            {}{}        }}\n    }}\n",
+            Self::render_imports(&imports),
+            type_name,
+            fields
+                .iter()
+                .map(|(name, type_name)| format!("    {}: {},\n", name, type_name))
+                .collect::<String>(),
+            if specification.is_aggregate_root {
+                "    domain_events: Vec<Box<dyn DomainEvent>>,\n"
+            } else {
+                ""
+            },
+            type_name,
+            Self::render_parameters("    pub fn new(", &parameters, ") -> Self"),
+            fields
+                .iter()
+                .map(|(name, _)| format!("            {},\n", name))
+                .collect::<String>(),
+            if specification.is_aggregate_root {
+                "            domain_events: vec![],\n"
+            } else {
+                ""
+            }
+        );
+        if let Some(created_event_type) = &created_event_type {
+            content.push_str(
+                &format!(
+                    "\n{} {{\n        let mut aggregate_root = Self::new({});\n        \
+                    aggregate_root.add_domain_event(\n            \
+                    Box::new({}::new(aggregate_root.id.get_value().to_string()))\n        \
+                    );\n        \
+                    aggregate_root\n    }}\n",
+                    Self::render_parameters("    pub fn create(", &parameters, ") -> Self"),
+                    fields
+                        .iter()
+                        .map(|(name, _)| name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    created_event_type
+                )
+            );
+        }
+        content.push_str(
+            &format!(
+                "{}}}\n\nimpl Entity<{}> for {} {{\n    \
+                fn get_id(&self) -> &{} {{\n        &self.id\n    }}\n}}\n",
+                Self::render_getters(
+                    &fields
+                        .iter()
+                        .filter(|(name, _)| name != "id")
+                        .cloned()
+                        .collect::<Vec<_>>()
+                ),
+                id_type,
+                type_name,
+                id_type
+            )
+        );
+        if specification.is_aggregate_root {
+            content.push_str(
+                &format!(
+                    "\nimpl AggregateRoot<{}> for {} {{\n    \
+                    fn add_domain_event(&mut self, domain_event: Box<dyn DomainEvent>) {{\n        \
+                    self.domain_events.push(domain_event);\n    }}\n\n    \
+                    fn pull_domain_events(&mut self) -> Vec<Box<dyn DomainEvent>> {{\n        \
+                    std::mem::take(&mut self.domain_events)\n    }}\n}}\n",
+                    id_type,
+                    type_name
+                )
+            );
+        }
+        Ok(content)
+    }
+
+    fn generate_event(
+        &self,
+        specification: &ComponentSpecificationValue
+    ) -> Result<String, ComponentGeneratorError> {
+        self.validate_fields(&specification.fields)?;
+        let fields = Self::get_fields(specification);
+        if
+            let Some((name, _)) = fields
+                .iter()
+                .find(|(name, _)| name == "aggregate_root_id" || name == "occurring_time")
+        {
+            return Err(
+                ComponentGeneratorError::InvalidSpecification(
+                    format!("field <{}> is already part of every domain event", name)
+                )
+            );
+        }
+        let type_name = specification.component_name.get_type_name();
+        let mut imports = vec![
+            "std::any::Any".to_string(),
+            "std::time::SystemTime".to_string(),
+            "crate::core::domain::events::domain_event::DomainEvent".to_string()
+        ];
+        imports.extend(
+            self.get_type_imports(
+                specification,
+                &fields
+                    .iter()
+                    .map(|(_, type_name)| type_name.as_str())
+                    .collect::<Vec<_>>()
+            )
+        );
+        let mut parameters = vec!["aggregate_root_id: String".to_string()];
+        parameters.extend(
+            fields.iter().map(|(name, type_name)| format!("{}: {}", name, type_name))
+        );
+        Ok(
+            format!(
+                "{}\npub struct {} {{\n    aggregate_root_id: String,\n{}    \
+                occurring_time: SystemTime,\n}}\n\
+                \nimpl {} {{\n{} {{\n        Self {{\n            aggregate_root_id,\n\
+                {}            \
+                occurring_time: SystemTime::now(),\n        }}\n    }}\n{}}}\n\
+                \nimpl DomainEvent for {} {{\n    fn get_name(&self) -> String {{\n        \
+                \"{}.{}\".to_string()\n    }}\n\n    \
+                fn get_aggregate_root_id(&self) -> &String {{\n        \
+                &self.aggregate_root_id\n    }}\n\n    \
+                fn get_occurring_time(&self) -> &SystemTime {{\n        \
+                &self.occurring_time\n    }}\n\n    \
+                fn as_any(&self) -> &dyn Any {{\n        self\n    }}\n}}\n",
+                Self::render_imports(&imports),
+                type_name,
+                fields
+                    .iter()
+                    .map(|(name, type_name)| format!("    {}: {},\n", name, type_name))
+                    .collect::<String>(),
+                type_name,
+                Self::render_parameters("    pub fn new(", &parameters, ") -> Self"),
+                fields
+                    .iter()
+                    .map(|(name, _)| format!("            {},\n", name))
+                    .collect::<String>(),
+                Self::render_getters(&fields),
+                type_name,
+                specification.bounded_context_name,
+                specification.component_name.get_value()
+            )
+        )
+    }
+}
+
+impl RustComponentGenerator {
+    // `place_order` and `place_order_use_case` both generate `PlaceOrderUseCase`
+    // with its `PlaceOrderRequestModel` and `PlaceOrderResponseModel`.
+    fn generate_use_case(
+        &self,
+        specification: &ComponentSpecificationValue
+    ) -> Result<String, ComponentGeneratorError> {
+        self.validate_fields(&specification.fields)?;
+        let base_name = specification.component_name.get_use_case_name().get_type_name();
+        let fields = Self::get_fields(specification);
+        let mut imports = vec![
+            "std::error::Error".to_string(),
+            "async_trait::async_trait".to_string(),
+            "crate::core::application::use_case_input_port::UseCaseInputPort".to_string(),
+            "crate::core::application::use_case_output_port::UseCaseOutputPort".to_string()
+        ];
+        imports.extend(
+            self.get_type_imports(
+                specification,
+                &fields
+                    .iter()
+                    .map(|(_, type_name)| type_name.as_str())
+                    .collect::<Vec<_>>()
+            )
+        );
+        imports.extend(
+            specification.repository_names.iter().map(|repository_name| {
+                format!(
+                    "{}::{}",
+                    Self::get_component_path(
+                        specification,
+                        "domain",
+                        &ComponentType::Repositories,
+                        repository_name.get_value()
+                    ),
+                    repository_name.get_type_name()
+                )
+            })
+        );
+        let mut dependencies = specification.repository_names
+            .iter()
+            .map(|repository_name| {
+                (
+                    repository_name.get_value().to_string(),
+                    format!("&'a dyn {}", repository_name.get_type_name()),
+                )
+            })
+            .collect::<Vec<_>>();
+        dependencies.push((
+            "output_port".to_string(),
+            format!("&'a dyn UseCaseOutputPort<{}ResponseModel>", base_name),
+        ));
+        let crud_use_case = specification.entity_name
+            .as_ref()
+            .and_then(|entity_name| Self::get_crud_use_case(specification, entity_name));
+        let (response_fields, parameter_name, statements) = match crud_use_case {
+            Some(crud_use_case) => {
+                imports.extend(crud_use_case.imports);
+                let parameter_name = match crud_use_case.statements.contains("request_model.") {
+                    true => "request_model",
+                    false => "_request_model",
+                };
+                (crud_use_case.response_fields, parameter_name, crud_use_case.statements)
+            }
+            None => (vec![], "_request_model", "todo!()".to_string()),
+        };
+        let render_fields = |fields: &[(String, String)]| {
+            match fields.is_empty() {
+                true => String::new(),
+                false => format!(
+                    "\n{}",
+                    fields
+                        .iter()
+                        .map(|(name, type_name)| format!("    pub {}: {},\n", name, type_name))
+                        .collect::<String>()
+                ),
+            }
+        };
+        let content = format!(
+            "{}\npub struct {}RequestModel {{{}}}\n\npub struct {}ResponseModel {{{}}}\n\n\
+            pub struct {}UseCase<'a> {{\n{}}}\n\nimpl<'a> {}UseCase<'a> {{\n    \
+            pub fn new(\n{}\n    ) -> Self {{\n        Self {{\n{}        }}\n    }}\n\n    \
+            async fn try_interact(\n        &self,\n        {}: {}RequestModel\n    \
+            ) -> Result<{}ResponseModel, Box<dyn Error + Send + Sync>> {{\n        \
+            {}\n    }}\n}}\n\n#[async_trait]\n\
+            impl<'a> UseCaseInputPort<{}RequestModel> for {}UseCase<'a> {{\n    \
+            async fn interact(&self, request_model: {}RequestModel) {{\n        \
+            let result = self.try_interact(request_model).await;\n        \
+            match result {{\n            \
+            Ok(response_model) => {{\n                \
+            self.output_port.success(response_model).await;\n            }}\n            \
+            Err(error) => {{\n                self.output_port.failure(error).await;\n            \
+            }}\n        }}\n    }}\n}}\n",
+            Self::render_imports(&imports),
+            base_name,
+            render_fields(&fields),
+            base_name,
+            render_fields(&response_fields),
+            base_name,
+            dependencies
+                .iter()
+                .map(|(name, type_name)| format!("    {}: {},\n", name, type_name))
+                .collect::<String>(),
+            base_name,
+            dependencies
+                .iter()
+                .map(|(name, type_name)| format!("        {}: {}", name, type_name))
+                .collect::<Vec<_>>()
+                .join(",\n"),
+            dependencies
+                .iter()
+                .map(|(name, _)| format!("            {},\n", name))
+                .collect::<String>(),
+            parameter_name,
+            base_name,
+            base_name,
+            statements,
+            base_name,
+            base_name,
+            base_name
+        );
+        Ok(content)
+    }
+
+    // A CRUD use case calls the repository method sharing its name, rebuilding
+    // the entity from the request fields, which follow the order of its `new`.
+    fn get_crud_use_case(
+        specification: &ComponentSpecificationValue,
+        entity_name: &ComponentName
+    ) -> Option<CrudUseCase> {
+        let repository_name = specification.repository_names.first()?;
+        let method_name = specification.component_name.get_use_case_name().get_value().to_string();
+        let method_index = Self::get_crud_methods(entity_name)
+            .iter()
+            .position(|(name, _, _)| *name == method_name)?;
+        let name = entity_name.get_value();
+        let type_name = entity_name.get_type_name();
+        let plural_name = entity_name.get_plural_name().get_value().to_string();
+        let repository = format!("self.{}", repository_name.get_value());
+        let base_name = specification.component_name.get_use_case_name().get_type_name();
+        let id = "let id = IdentityObject::new(request_model.id);\n        ";
+        let mut imports = vec![
+            "crate::core::domain::models::identity_object::IdentityObject".to_string(),
+            "crate::core::domain::models::value_object::ValueObject".to_string()
+        ];
+        let (response_fields, statements) = match method_index {
+            0 | 1 => {
+                imports.push(Self::get_entity_path(specification, entity_name));
+                let arguments = Self::get_fields(specification)
+                    .iter()
+                    .map(|(field_name, _)| {
+                        match field_name.as_str() {
+                            "id" => "IdentityObject::new(request_model.id)".to_string(),
+                            _ => format!("request_model.{}", field_name),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                (
+                    vec![],
+                    format!(
+                        "{}\n        {}.{}({}).await?;\n        Ok({}ResponseModel {{}})",
+                        Self::render_parameters(
+                            &format!("        let {} = {}::new(", name, type_name),
+                            &arguments,
+                            ");"
+                        ).trim_start(),
+                        repository,
+                        method_name,
+                        name,
+                        base_name
+                    ),
+                )
+            }
+            2 =>
+                (
+                    vec![],
+                    format!(
+                        "{}{}.{}(&id).await?;\n        Ok({}ResponseModel {{}})",
+                        id,
+                        repository,
+                        method_name,
+                        base_name
+                    ),
+                ),
+            3 => {
+                imports.push("std::sync::Arc".to_string());
+                imports.push(Self::get_entity_path(specification, entity_name));
+                (
+                    vec![(name.to_string(), format!("Arc<{}>", type_name))],
+                    format!(
+                        "{}let {} = {}.{}(&id).await?;\n        Ok({}ResponseModel {{ {} }})",
+                        id,
+                        name,
+                        repository,
+                        method_name,
+                        base_name,
+                        name
+                    ),
+                )
+            }
+            _ => {
+                imports = vec![
+                    "std::sync::Arc".to_string(),
+                    Self::get_entity_path(specification, entity_name)
+                ];
+                (
+                    vec![(plural_name.clone(), format!("Vec<Arc<{}>>", type_name))],
+                    format!(
+                        "let {} = {}.{}().await?;\n        Ok({}ResponseModel {{ {} }})",
+                        plural_name,
+                        repository,
+                        method_name,
+                        base_name,
+                        plural_name
+                    ),
+                )
+            }
+        };
+        Some(CrudUseCase {
+            imports,
+            response_fields,
+            statements,
+        })
+    }
+
+    fn get_singular(word: &str) -> String {
+        if let Some(stem) = word.strip_suffix("ies") {
+            return format!("{}y", stem);
+        }
+        if ["sses", "xes", "ches", "shes"].iter().any(|suffix| word.ends_with(suffix)) {
+            return word[..word.len() - 2].to_string();
+        }
+        if word.ends_with("ss") {
+            return word.to_string();
+        }
+        word.strip_suffix('s').unwrap_or(word).to_string()
+    }
+
+    // `find_orders` responds with `orders`, a list of `OrderReadModel` found
+    // by the `find_orders` method of its `FindOrdersRepository`.
+    fn get_query_names(query_name: &ComponentName) -> QueryNames {
+        let base_name = query_name.get_use_case_name();
+        let words = base_name.get_value().split('_').collect::<Vec<_>>();
+        let (verb, subject) = match words.split_first() {
+            Some((verb, subject)) if
+                !subject.is_empty() &&
+                ["find", "get", "list", "search", "fetch"].contains(verb)
+            => (Some(*verb), subject.to_vec()),
+            _ => (None, words.clone()),
+        };
+        let mut read_model_words = subject.iter().map(|word| word.to_string()).collect::<Vec<_>>();
+        if let Some(last_word) = read_model_words.last_mut() {
+            *last_word = Self::get_singular(last_word);
+        }
+        QueryNames {
+            type_name: base_name.get_type_name(),
+            method_name: base_name.get_value().to_string(),
+            subject: subject.join("_"),
+            read_model: format!(
+                "{}ReadModel",
+                ComponentName::new(read_model_words.join("_")).get_type_name()
+            ),
+            error_variant: format!(
+                "{}Error",
+                ComponentName::new(verb.unwrap_or("query").to_string()).get_type_name()
+            ),
+            description: words.join(" "),
+        }
+    }
+
+    // Mirrors `FindBoundedContextsUseCase`: the read model is declared next to
+    // the query and the repository hands back a list of it.
+    fn generate_query(
+        &self,
+        specification: &ComponentSpecificationValue,
+        query_name: &ComponentName,
+        repository_name: &ComponentName
+    ) -> String {
+        let names = Self::get_query_names(query_name);
+        let repository_type = repository_name.get_type_name();
+        let imports = vec![
+            "async_trait::async_trait".to_string(),
+            "crate::core::application::use_case_input_port::UseCaseInputPort".to_string(),
+            "crate::core::application::use_case_output_port::UseCaseOutputPort".to_string(),
+            format!(
+                "{}::{}",
+                Self::get_component_path(
+                    specification,
+                    "domain",
+                    &ComponentType::Repositories,
+                    repository_name.get_value()
+                ),
+                repository_type
+            )
+        ];
+        let dependencies = [
+            format!("repository: &'a dyn {}<{}>", repository_type, names.read_model),
+            format!("output_port: &'a dyn UseCaseOutputPort<{}ResponseModel>", names.type_name),
+        ];
+        format!(
+            "{}\npub struct {}RequestModel;\n\n#[derive(Clone)]\npub struct {} {{}}\n\n\
+            pub struct {}ResponseModel {{\n    pub {}: Vec<{}>,\n}}\n\n\
+            pub struct {}UseCase<'a> {{\n{}}}\n\nimpl<'a> {}UseCase<'a> {{\n    \
+            pub fn new(\n{}\n    ) -> Self {{\n        Self {{\n            \
+            repository,\n            output_port,\n        }}\n    }}\n}}\n\n#[async_trait]\n\
+            impl<'a> UseCaseInputPort<{}RequestModel> for {}UseCase<'a> {{\n    \
+            async fn interact(&self, _request_model: {}RequestModel) {{\n        \
+            let {} = self.repository.{}().await;\n        match {} {{\n            \
+            Ok({}) => {{\n                \
+            self.output_port.success({}ResponseModel {{\n                    {},\n                \
+            }}).await;\n            }}\n            Err(error) => {{\n                \
+            self.output_port.failure(Box::new(error)).await;\n            }}\n        \
+            }}\n    }}\n}}\n",
+            Self::render_imports(&imports),
+            names.type_name,
+            names.read_model,
+            names.type_name,
+            names.subject,
+            names.read_model,
+            names.type_name,
+            dependencies
+                .iter()
+                .map(|dependency| format!("    {},
+", dependency))
+                .collect::<String>(),
+            names.type_name,
+            dependencies
+                .iter()
+                .map(|dependency| format!("        {}", dependency))
+                .collect::<Vec<_>>()
+                .join(",
+"),
+            names.type_name,
+            names.type_name,
+            names.type_name,
+            names.subject,
+            names.method_name,
+            names.subject,
+            names.subject,
+            names.type_name,
+            names.subject
+        )
+    }
+
+    fn generate_find_repository(
+        &self,
+        query_name: &ComponentName,
+        repository_name: &str
+    ) -> String {
+        let names = Self::get_query_names(query_name);
+        let imports = vec!["async_trait::async_trait".to_string(), "thiserror::Error".to_string()];
+        format!(
+            "{}\n#[derive(Error, Debug)]\npub enum {}Error {{\n    \
+            #[error(\"An error occurred while trying to {}: {{0}}\")] {}(String),\n}}\n\n\
+            #[async_trait]\npub trait {}<ReadModel>: Send + Sync {{\n{};\n}}\n",
+            Self::render_imports(&imports),
+            repository_name,
+            names.description,
+            names.error_variant,
+            repository_name,
+            Self::render_parameters(
+                &format!("    async fn {}(", names.method_name),
+                &["&self".to_string()],
+                &format!(") -> Result<Vec<ReadModel>, {}Error>", repository_name)
+            )
+        )
+    }
+
+    // `postgres_find_orders_repository` implements `FindOrdersRepository` as
+    // `PostgresFindOrdersRepository`, leaving the lookup itself to be written.
+    fn generate_find_repository_implementation(
+        &self,
+        specification: &ComponentSpecificationValue,
+        query_name: &ComponentName,
+        repository_name: &ComponentName
+    ) -> String {
+        let names = Self::get_query_names(query_name);
+        let repository_type = repository_name.get_type_name();
+        let type_name = specification.component_name.get_type_name();
+        let repository_path = Self::get_component_path(
+            specification,
+            "domain",
+            &ComponentType::Repositories,
+            repository_name.get_value()
+        );
+        let imports = vec![
+            "async_trait::async_trait".to_string(),
+            format!(
+                "{}::{}",
+                Self::get_component_path(
+                    specification,
+                    "application",
+                    &ComponentType::Queries,
+                    query_name.get_value()
+                ),
+                names.read_model
+            ),
+            format!("{}::{}", repository_path, repository_type),
+            format!("{}::{}Error", repository_path, repository_type)
+        ];
+        let implementation = format!(
+            "impl {}<{}> for {} {{",
+            repository_type,
+            names.read_model,
+            type_name
+        );
+        format!(
+            "{}\npub struct {};\n\n#[async_trait]\n{}\n{} {{\n        todo!()\n    }}\n}}\n",
+            Self::render_imports(&imports),
+            type_name,
+            match implementation.len() <= Self::MAX_LINE_LENGTH {
+                true => implementation,
+                false => format!(
+                    "impl {}<{}>\nfor {} {{",
+                    repository_type,
+                    names.read_model,
+                    type_name
+                ),
+            },
+            Self::render_parameters(
+                &format!("    async fn {}(", names.method_name),
+                &["&self".to_string()],
+                &format!(") -> Result<Vec<{}>, {}Error>", names.read_model, repository_type)
+            )
+        )
+    }
+
+    // The method a feature command adds to its repository. The repository's
+    // own error type is unknown, so the method returns a boxed error.
+    fn generate_repository_method(
+        &self,
+        specification: &ComponentSpecificationValue,
+        use_case_name: &ComponentName
+    ) -> String {
+        let prefix = format!("    async fn {}(", use_case_name.get_use_case_name().get_value());
+        let return_type = ") -> Result<(), Box<dyn std::error::Error + Send + Sync>>";
+        match specification.layer_name {
+            Some(LayerName::Infrastructure) => {
+                format!(
+                    "{}\n        todo!()\n    }}\n",
+                    Self::render_parameters(
+                        &prefix,
+                        &["&self".to_string()],
+                        &format!("{} {{", return_type)
+                    )
+                )
+            }
+            _ => {
+                format!(
+                    "{};\n",
+                    Self::render_parameters(&prefix, &["&self".to_string()], return_type)
+                )
+            }
+        }
+    }
+
+    // Mirrors `DiffModelController`: the controller turns its input into the
+    // request model of the use case it drives.
+    fn generate_controller(
+        &self,
+        specification: &ComponentSpecificationValue,
+        use_case_type: &ComponentType,
+        use_case_name: &ComponentName
+    ) -> String {
+        let feature_name = use_case_name.get_use_case_name();
+        let base_name = feature_name.get_type_name();
+        let type_name = specification.component_name.get_type_name();
+        let imports = vec![
+            "crate::core::application::use_case_input_port::UseCaseInputPort".to_string(),
+            format!(
+                "{}::{}RequestModel",
+                Self::get_component_path(
+                    specification,
+                    "application",
+                    use_case_type,
+                    use_case_name.get_value()
+                ),
+                base_name
+            )
+        ];
+        // Queries take a unit request model, commands an empty struct to fill.
+        let request_model = match use_case_type {
+            ComponentType::Queries => format!("{}RequestModel", base_name),
+            _ => format!("{}RequestModel {{}}", base_name),
+        };
+        format!(
+            "{}\npub struct {}<'a> {{\n    \
+            use_case: &'a dyn UseCaseInputPort<{}RequestModel>,\n}}\n\n\
+            impl<'a> {}<'a> {{\n{} {{\n        Self {{ use_case }}\n    }}\n\n    \
+            pub async fn {}(&self) {{\n        \
+            self.use_case.interact({}).await;\n    }}\n}}\n",
+            Self::render_imports(&imports),
+            type_name,
+            base_name,
+            type_name,
+            Self::render_parameters(
+                "    pub fn new(",
+                &[format!("use_case: &'a dyn UseCaseInputPort<{}RequestModel>", base_name)],
+                ") -> Self"
+            ),
+            feature_name.get_value(),
+            request_model
+        )
+    }
+
+    fn generate_presenter(
+        &self,
+        specification: &ComponentSpecificationValue,
+        use_case_type: &ComponentType,
+        use_case_name: &ComponentName
+    ) -> String {
+        let feature_name = use_case_name.get_use_case_name();
+        let base_name = feature_name.get_type_name();
+        let type_name = specification.component_name.get_type_name();
+        let imports = vec![
+            "std::error::Error".to_string(),
+            "async_trait::async_trait".to_string(),
+            "crate::core::application::use_case_output_port::UseCaseOutputPort".to_string(),
+            format!(
+                "{}::{}ResponseModel",
+                Self::get_component_path(
+                    specification,
+                    "application",
+                    use_case_type,
+                    use_case_name.get_value()
+                ),
+                base_name
+            )
+        ];
+        format!(
+            "{}\npub struct {};\n\n#[async_trait]\n\
+            impl UseCaseOutputPort<{}ResponseModel> for {} {{\n    \
+            async fn success(&self, _response_model: {}ResponseModel) {{\n        \
+            todo!()\n    }}\n\n    \
+            async fn failure(&self, error: Box<dyn Error + Send>) {{\n        \
+            eprintln!(\"Failed to {} due to: {{}}\", error)\n    }}\n}}\n",
+            Self::render_imports(&imports),
+            type_name,
+            base_name,
+            type_name,
+            base_name,
+            feature_name.get_value().replace('_', " ")
+        )
+    }
+
+    // The repository of a CRUD has one method per use case: `create_cart`,
+    // `update_cart`, `delete_cart`, `get_cart` and `list_carts`. Entities are
+    // handed out behind an `Arc` so they don't need to be `Clone`.
+    fn get_crud_methods(entity_name: &ComponentName) -> Vec<(String, String, String)> {
+        let name = entity_name.get_value();
+        let type_name = entity_name.get_type_name();
+        vec![
+            (format!("create_{}", name), format!("{}: {}", name, type_name), "()".to_string()),
+            (format!("update_{}", name), format!("{}: {}", name, type_name), "()".to_string()),
+            (format!("delete_{}", name), "id: &IdentityObject".to_string(), "()".to_string()),
+            (
+                format!("get_{}", name),
+                "id: &IdentityObject".to_string(),
+                format!("Arc<{}>", type_name),
+            ),
+            (
+                format!("list_{}", entity_name.get_plural_name().get_value()),
+                String::new(),
+                format!("Vec<Arc<{}>>", type_name),
+            )
+        ]
+    }
+
+    fn render_crud_method(
+        method: &(String, String, String),
+        repository_type: &str,
+        suffix: &str
+    ) -> String {
+        let (method_name, parameter, return_type) = method;
+        let mut parameters = vec!["&self".to_string()];
+        if !parameter.is_empty() {
+            parameters.push(parameter.clone());
+        }
+        Self::render_parameters(
+            &format!("    async fn {}(", method_name),
+            &parameters,
+            &format!(") -> Result<{}, {}Error>{}", return_type, repository_type, suffix)
+        )
+    }
+
+    fn get_entity_path(
+        specification: &ComponentSpecificationValue,
+        entity_name: &ComponentName
+    ) -> String {
+        format!(
+            "{}::{}",
+            Self::get_component_path(
+                specification,
+                "domain",
+                &ComponentType::Entities,
+                entity_name.get_value()
+            ),
+            entity_name.get_type_name()
+        )
+    }
+
+    fn generate_crud_repository(
+        &self,
+        specification: &ComponentSpecificationValue,
+        entity_name: &ComponentName
+    ) -> String {
+        let repository_type = specification.component_name.get_type_name();
+        let type_name = entity_name.get_type_name();
+        let imports = vec![
+            "std::sync::Arc".to_string(),
+            "async_trait::async_trait".to_string(),
+            "thiserror::Error".to_string(),
+            "crate::core::domain::models::identity_object::IdentityObject".to_string(),
+            Self::get_entity_path(specification, entity_name)
+        ];
+        format!(
+            "{}\n#[derive(Error, Debug)]\npub enum {}Error {{\n    \
+            #[error(\"{} <{{0}}> not found\")] NotFound(String),\n    \
+            #[error(\"{} <{{0}}> already exists\")] AlreadyExists(String),\n    \
+            #[error(\"An error occurred while trying to access {}: {{0}}\")] \
+            StorageError(String),\n}}\n\n#[async_trait]\npub trait {}: Send + Sync {{\n{}}}\n",
+            Self::render_imports(&imports),
+            repository_type,
+            type_name,
+            type_name,
+            entity_name.get_plural_name().get_value().replace('_', " "),
+            repository_type,
+            Self::get_crud_methods(entity_name)
+                .iter()
+                .map(|method| Self::render_crud_method(method, &repository_type, ";\n"))
+                .collect::<String>()
+        )
+    }
+
+    // A working implementation keeping entities in a map keyed by their id,
+    // so the use cases can be exercised before a real store exists.
+    fn generate_in_memory_repository(
+        &self,
+        specification: &ComponentSpecificationValue,
+        entity_name: &ComponentName,
+        repository_name: &ComponentName
+    ) -> String {
+        let repository_type = repository_name.get_type_name();
+        let type_name = specification.component_name.get_type_name();
+        let entity_type = entity_name.get_type_name();
+        let name = entity_name.get_value();
+        let plural_name = entity_name.get_plural_name().get_value().to_string();
+        let repository_path = Self::get_component_path(
+            specification,
+            "domain",
+            &ComponentType::Repositories,
+            repository_name.get_value()
+        );
+        let imports = vec![
+            "std::collections::HashMap".to_string(),
+            "std::sync::Arc".to_string(),
+            "std::sync::Mutex".to_string(),
+            "std::sync::MutexGuard".to_string(),
+            "async_trait::async_trait".to_string(),
+            "crate::core::domain::models::entity::Entity".to_string(),
+            "crate::core::domain::models::identity_object::IdentityObject".to_string(),
+            "crate::core::domain::models::value_object::ValueObject".to_string(),
+            Self::get_entity_path(specification, entity_name),
+            format!("{}::{}", repository_path, repository_type),
+            format!("{}::{}Error", repository_path, repository_type)
+        ];
+        let store_type = format!("HashMap<String, Arc<{}>>", entity_type);
+        let not_found = format!(
+            "None => Err({}Error::NotFound(id.to_string())),\n        }}\n",
+            repository_type
+        );
+        let bodies = [
+            format!(
+                "let mut {} = self.lock_{}()?;\n        \
+                let id = {}.get_id().get_value().to_string();\n        \
+                if {}.contains_key(&id) {{\n            \
+                return Err({}Error::AlreadyExists(id));\n        }}\n        \
+                {}.insert(id, Arc::new({}));\n        Ok(())\n",
+                plural_name,
+                plural_name,
+                name,
+                plural_name,
+                repository_type,
+                plural_name,
+                name
+            ),
+            format!(
+                "let mut {} = self.lock_{}()?;\n        \
+                let id = {}.get_id().get_value().to_string();\n        \
+                match {}.get_mut(&id) {{\n            \
+                Some(stored_{}) => {{\n                \
+                *stored_{} = Arc::new({});\n                Ok(())\n            }}\n            \
+                None => Err({}Error::NotFound(id)),\n        }}\n",
+                plural_name,
+                plural_name,
+                name,
+                plural_name,
+                name,
+                name,
+                name,
+                repository_type
+            ),
+            format!(
+                "match self.lock_{}()?.remove(id.get_value()) {{\n            \
+                Some(_) => Ok(()),\n            {}",
+                plural_name,
+                not_found
+            ),
+            format!(
+                "match self.lock_{}()?.get(id.get_value()) {{\n            \
+                Some({}) => Ok({}.clone()),\n            {}",
+                plural_name,
+                name,
+                name,
+                not_found
+            ),
+            format!("Ok(self.lock_{}()?.values().cloned().collect())\n", plural_name)
+        ];
+        format!(
+            "{}\n#[derive(Default)]\npub struct {} {{\n    {}: Mutex<{}>,\n}}\n\n\
+            impl {} {{\n{}\n        \
+            self.{}.lock().map_err(|error| {}Error::StorageError(error.to_string()))\n    \
+            }}\n}}\n\n#[async_trait]\nimpl {} for {} {{\n{}}}\n",
+            Self::render_imports(&imports),
+            type_name,
+            plural_name,
+            store_type,
+            type_name,
+            Self::render_parameters(
+                &format!("    fn lock_{}(", plural_name),
+                &["&self".to_string()],
+                &format!(
+                    ") -> Result<MutexGuard<'_, {}>, {}Error> {{",
+                    store_type,
+                    repository_type
+                )
+            ),
+            plural_name,
+            repository_type,
+            repository_type,
+            type_name,
+            Self::get_crud_methods(entity_name)
+                .iter()
+                .zip(bodies)
+                .map(|(method, body)| {
+                    format!(
+                        "{}        {}    }}\n",
+                        Self::render_crud_method(method, &repository_type, " {\n"),
+                        body
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    }
+
+    // `src/sales/order/domain/events/order_placed.rs` is imported from
+    // `crate::sales::order::domain::events::order_placed`.
+    fn get_module_path(path: &str) -> String {
+        let module_path = path
+            .trim_start_matches("./")
+            .trim_start_matches("src/")
+            .trim_end_matches(".rs")
+            .trim_end_matches("/mod")
+            .replace('/', "::");
+        format!("crate::{}", module_path)
+    }
+
+    fn generate_subscriber(
+        &self,
+        specification: &ComponentSpecificationValue,
+        subscribed_event: &SubscribedEvent
+    ) -> Result<String, ComponentGeneratorError> {
+        let event = subscribed_event.get_value();
+        let imports = vec![
+            "std::error::Error".to_string(),
+            "async_trait::async_trait".to_string(),
+            "crate::core::domain::events::domain_event::DomainEvent".to_string(),
+            "crate::core::domain::events::domain_event_subscriber::DomainEventSubscriber"
+                .to_string(),
+            format!("{}::{}", Self::get_module_path(&event.path), event.type_name)
+        ];
+        let type_name = specification.component_name.get_type_name();
+        Ok(
+            format!(
+                "{}\npub struct {};\n\n#[async_trait]\nimpl DomainEventSubscriber for {} {{\n    \
+                fn subscribed_to(&self) -> String {{\n        {:?}.to_string()\n    }}\n\n    \
+                async fn on(&self, domain_event: &dyn DomainEvent) \
+                -> Result<(), Box<dyn Error>> {{\n        \
+                match domain_event.as_any().downcast_ref::<{}>() {{\n            \
+                Some(_{}) => Ok(()),\n            None => {{\n                \
+                Err(format!(\"Unexpected domain event <{{}}>\", \
+                domain_event.get_name()).into())\n            \
+                }}\n        }}\n    }}\n}}\n",
+                Self::render_imports(&imports),
+                type_name,
+                type_name,
+                event.event_name,
+                event.type_name,
+                Self::get_snake_case(&event.type_name)
+            )
+        )
+    }
+
+    fn get_length_rule(
+        type_name: &str,
+        rule: &ComponentRule
+    ) -> Result<ValueRule, ComponentGeneratorError> {
+        let value = rule.get_value();
+        let length = value.argument
+            .parse::<usize>()
+            .map_err(|_| {
+                ComponentGeneratorError::InvalidSpecification(
+                    format!("rule <{}> expects a length, got <{}>", value.name, value.argument)
+                )
+            })?;
+        Ok(match value.name.as_str() {
+            "min_len" =>
+                ValueRule {
+                    variant: "TooShort".to_string(),
+                    message: format!("{} must be at least {} characters long", type_name, length),
+                    condition: format!("value.chars().count() < {}", length),
+                    invalid_sample: length
+                        .checked_sub(1)
+                        .map(Self::get_string_sample),
+                },
+            _ =>
+                ValueRule {
+                    variant: "TooLong".to_string(),
+                    message: format!("{} must be at most {} characters long", type_name, length),
+                    condition: format!("value.chars().count() > {}", length),
+                    invalid_sample: Some(Self::get_string_sample(length + 1)),
+                },
+        })
+    }
+
+    fn get_string_sample(length: usize) -> String {
+        match length {
+            0 => "String::new()".to_string(),
+            1 => "\"a\".to_string()".to_string(),
+            _ => format!("\"a\".repeat({})", length),
+        }
+    }
+
+    fn get_bound_rule(
+        type_name: &str,
+        value_type: &str,
+        rule: &ComponentRule
+    ) -> Result<ValueRule, ComponentGeneratorError> {
+        let value = rule.get_value();
+        let is_minimum = value.name == "min";
+        let invalid_argument = || {
+            ComponentGeneratorError::InvalidSpecification(
+                format!(
+                    "rule <{}> expects a {} value, got <{}>",
+                    value.name,
+                    value_type,
+                    value.argument
+                )
+            )
+        };
+        let (bound, invalid_sample) = match
+            Self::INTEGER_TYPES.iter().find(|(name, _, _)| *name == value_type)
+        {
+            Some((_, minimum, maximum)) => {
+                let bound = value.argument
+                    .parse::<i128>()
+                    .ok()
+                    .filter(|bound| bound >= minimum && bound <= maximum)
+                    .ok_or_else(invalid_argument)?;
+                let invalid_sample = if is_minimum {
+                    bound.checked_sub(1).filter(|sample| sample >= minimum)
+                } else {
+                    bound.checked_add(1).filter(|sample| sample <= maximum)
+                };
+                (bound.to_string(), invalid_sample.map(|sample| sample.to_string()))
+            }
+            None => {
+                let bound = value.argument
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|bound| bound.is_finite())
+                    .ok_or_else(invalid_argument)?;
+                let invalid_sample = if is_minimum { bound - 1.0 } else { bound + 1.0 };
+                (format!("{:?}", bound), Some(format!("{:?}", invalid_sample)))
+            }
+        };
+        Ok(ValueRule {
+            variant: (if is_minimum { "TooSmall" } else { "TooLarge" }).to_string(),
+            message: format!(
+                "{} must be at {} {}",
+                type_name,
+                if is_minimum { "least" } else { "most" },
+                bound
+            ),
+            condition: format!("value {} {}", if is_minimum { "<" } else { ">" }, bound),
+            invalid_sample,
+        })
+    }
+
+    fn get_value_rules(
+        &self,
+        type_name: &str,
+        value_type: &str,
+        rules: &[ComponentRule]
+    ) -> Result<Vec<ValueRule>, ComponentGeneratorError> {
+        let is_string = value_type == "String";
+        let is_number =
+            Self::INTEGER_TYPES.iter().any(|(name, _, _)| *name == value_type) ||
+            Self::FLOAT_TYPES.contains(&value_type);
+        let mut value_rules: Vec<ValueRule> = vec![];
+        for rule in rules {
+            let value = rule.get_value();
+            let value_rule = match value.name.as_str() {
+                "min_len" | "max_len" if is_string => Self::get_length_rule(type_name, rule)?,
+                "regex" if is_string => {
+                    let pattern = Self::get_pattern(&value.argument)?;
+                    ValueRule {
+                        variant: "PatternMismatch".to_string(),
+                        message: format!("{} must match the expected pattern", type_name),
+                        condition: "!PATTERN.is_match(&value)".to_string(),
+                        invalid_sample: Self::get_pattern_samples(rules)
+                            .into_iter()
+                            .find(|sample| !pattern.is_match(sample))
+                            .map(|sample| Self::render_string_sample(&sample)),
+                    }
+                }
+                "min" | "max" if is_number => Self::get_bound_rule(type_name, value_type, rule)?,
+                "min_len" | "max_len" | "regex" | "min" | "max" => {
+                    return Err(
+                        ComponentGeneratorError::InvalidSpecification(
+                            format!("rule <{}> is not supported for {}", value.name, value_type)
+                        )
+                    );
+                }
+                _ => {
+                    return Err(
+                        ComponentGeneratorError::InvalidSpecification(
+                            format!(
+                                "unknown rule <{}>, expected one of \
+                                min_len, max_len, regex, min, max",
+                                value.name
+                            )
+                        )
+                    );
+                }
+            };
+            if value_rules.iter().any(|other| other.variant == value_rule.variant) {
+                return Err(
+                    ComponentGeneratorError::InvalidSpecification(
+                        format!("rule <{}> is declared more than once", value.name)
+                    )
+                );
+            }
+            value_rules.push(value_rule);
+        }
+        // The pattern goes last so the generated samples, which can't be derived
+        // from a pattern, reach the length checks they are meant for.
+        value_rules.sort_by_key(|rule| rule.variant == "PatternMismatch");
+        Ok(value_rules)
+    }
+
+    // Patterns are checked here so that the generated code can compile them
+    // once and treat a failure as a bug.
+    fn get_pattern(pattern: &str) -> Result<Regex, ComponentGeneratorError> {
+        Regex::new(pattern).map_err(|error| {
+            ComponentGeneratorError::InvalidSpecification(
+                format!("rule <regex> has an invalid pattern: {}", error)
+            )
+        })
+    }
+
+    // Candidate strings for a pattern, limited to those passing the length
+    // rules so that only the pattern can reject them.
+    fn get_pattern_samples(rules: &[ComponentRule]) -> Vec<String> {
+        let get_length = |name: &str| {
+            rules
+                .iter()
+                .find(|rule| rule.get_value().name == name)
+                .and_then(|rule| rule.get_value().argument.parse::<usize>().ok())
+        };
+        let minimum = get_length("min_len").unwrap_or_default();
+        let maximum = get_length("max_len").unwrap_or(usize::MAX);
+        let padded_samples = [minimum, maximum]
+            .into_iter()
+            .filter(|length| *length > 1 && *length != usize::MAX)
+            .flat_map(|length| {
+                ["a", "A", "1", " ", "!"].map(|character| character.repeat(length))
+            });
+        Self::PATTERN_SAMPLES
+            .iter()
+            .map(|sample| sample.to_string())
+            .chain(padded_samples)
+            .filter(|sample| (minimum..=maximum).contains(&sample.chars().count()))
+            .collect()
+    }
+
+    fn render_string_sample(sample: &str) -> String {
+        let mut characters = sample.chars();
+        match characters.next() {
+            None => "String::new()".to_string(),
+            Some(first) if sample.len() > 1 && characters.all(|character| character == first) => {
+                format!("{:?}.repeat({})", first.to_string(), sample.chars().count())
+            }
+            Some(_) => format!("{:?}.to_string()", sample),
+        }
+    }
+
+    // The smallest value satisfying the length or bound rules, used by the
+    // generated test for valid input.
+    fn get_valid_sample(
+        value_type: &str,
+        rules: &[ComponentRule]
+    ) -> Result<String, ComponentGeneratorError> {
+        let get_argument = |name: &str| {
+            rules
+                .iter()
+                .find(|rule| rule.get_value().name == name)
+                .map(|rule| rule.get_value().argument.clone())
+        };
+        let conflicting_rules = |minimum: &str, maximum: &str| {
+            ComponentGeneratorError::InvalidSpecification(
+                format!("rule <{}> exceeds rule <{}>", minimum, maximum)
+            )
+        };
+        if value_type == "String" {
+            let minimum = get_argument("min_len").and_then(|length| length.parse::<usize>().ok());
+            let maximum = get_argument("max_len").and_then(|length| length.parse::<usize>().ok());
+            let length = minimum.unwrap_or(1).max(1).min(maximum.unwrap_or(usize::MAX));
+            if minimum.unwrap_or_default() > length {
+                return Err(conflicting_rules("min_len", "max_len"));
+            }
+            return Ok(Self::get_string_sample(length));
+        }
+        let minimum = get_argument("min").and_then(|bound| bound.parse::<f64>().ok());
+        let maximum = get_argument("max").and_then(|bound| bound.parse::<f64>().ok());
+        if minimum.zip(maximum).is_some_and(|(minimum, maximum)| minimum > maximum) {
+            return Err(conflicting_rules("min", "max"));
+        }
+        let sample = minimum.or(maximum).unwrap_or_default();
+        Ok(match Self::FLOAT_TYPES.contains(&value_type) {
+            true => format!("{:?}", sample),
+            false => get_argument("min").or_else(|| get_argument("max")).unwrap_or("0".to_string()),
+        })
+    }
+
+    fn get_raw_string(value: &str) -> String {
+        let mut hashes = String::new();
+        while value.contains(&format!("\"{}", hashes)) {
+            hashes.push('#');
+        }
+        format!("r{}\"{}\"{}", hashes, value, hashes)
+    }
+
+    fn generate_value_object(
+        &self,
+        specification: &ComponentSpecificationValue
+    ) -> Result<Option<String>, ComponentGeneratorError> {
+        if specification.value_type.is_none() && specification.rules.is_empty() {
+            return Ok(None);
+        }
+        let value_type = specification.value_type.as_deref().unwrap_or("String").trim();
+        if value_type.is_empty() {
+            return Err(
+                ComponentGeneratorError::InvalidSpecification(
+                    "value object type is empty".to_string()
+                )
+            );
+        }
+        let type_name = specification.component_name.get_type_name();
+        let error_name = format!("{}Error", type_name);
+        let value_rules = self.get_value_rules(&type_name, value_type, &specification.rules)?;
+        let pattern = specification.rules
+            .iter()
+            .find(|rule| rule.get_value().name == "regex")
+            .map(|rule| rule.get_value().argument.clone());
+        let mut imports = vec![
+            "crate::core::domain::models::value_object::ValueObject".to_string()
+        ];
+        imports.extend(self.get_type_imports(specification, &[value_type]));
+        if !value_rules.is_empty() {
+            imports.push("thiserror::Error".to_string());
+        }
+        if pattern.is_some() {
+            imports.push("std::sync::LazyLock".to_string());
+            imports.push("regex::Regex".to_string());
+        }
+        let mut content = Self::render_imports(&imports);
+        if let Some(pattern) = &pattern {
+            content.push_str(
+                &format!(
+                    "\nstatic PATTERN: LazyLock<Regex> = LazyLock::new(|| {{\n    \
+                    Regex::new({}).expect(\"PATTERN is a valid regular expression\")\n}});\n",
+                    Self::get_raw_string(pattern)
+                )
+            );
+        }
+        if !value_rules.is_empty() {
+            content.push_str(
+                &format!(
+                    "\n#[derive(Error, Debug, PartialEq)]\npub enum {} {{\n{}}}\n",
+                    error_name,
+                    value_rules
+                        .iter()
+                        .map(|rule| {
+                            format!("    #[error(\"{}\")] {},\n", rule.message, rule.variant)
+                        })
+                        .collect::<String>()
+                )
+            );
+        }
+        content.push_str(
+            &format!(
+                "\n#[derive(Clone, Debug)]\npub struct {} {{\n    value: {},\n}}\n",
+                type_name,
+                value_type
+            )
+        );
+        if !value_rules.is_empty() {
+            content.push_str(
+                &format!(
+                    "\nimpl {} {{\n    \
+                    // `ValueObject::new` trusts its input, values from callers go through here.\n\
+                    {} {{\n{}        Ok(Self::new(value))\n    }}\n}}\n",
+                    type_name,
+                    Self::render_parameters(
+                        "    pub fn try_new(",
+                        &[format!("value: {}", value_type)],
+                        &format!(") -> Result<Self, {}>", error_name)
+                    ),
+                    value_rules
+                        .iter()
+                        .map(|rule| {
+                            format!(
+                                "        if {} {{\n            return Err({}::{});\n        }}\n",
+                                rule.condition,
+                                error_name,
+                                rule.variant
+                            )
+                        })
+                        .collect::<String>()
+                )
+            );
+        }
+        content.push_str(
+            &format!(
+                "\nimpl ValueObject<{}> for {} {{\n    fn new(value: {}) -> Self {{\n        \
+                Self {{ value }}\n    }}\n\n    fn get_value(&self) -> &{} {{\n        \
+                &self.value\n    }}\n\n    \
+                fn is_equal(&self, other: &Self) -> bool {{\n        \
+                self.value == other.value\n    }}\n}}\n",
+                value_type,
+                type_name,
+                value_type,
+                value_type
+            )
+        );
+        if !value_rules.is_empty() {
+            let mut valid_sample = Self::get_valid_sample(value_type, &specification.rules)?;
+            let mut ignore_attribute = "";
+            if let Some(pattern) = &pattern {
+                let pattern = Self::get_pattern(pattern)?;
+                match
+                    Self::get_pattern_samples(&specification.rules)
+                        .into_iter()
+                        .find(|sample| pattern.is_match(sample))
+                {
+                    Some(sample) => {
+                        valid_sample = Self::render_string_sample(&sample);
+                    }
+                    None => {
+                        ignore_attribute =
+                            "    #[ignore = \"replace the sample with a value matching PATTERN\"]\
+                            \n";
+                    }
+                }
+            }
+            content.push_str(
+                &format!(
+                    "\n#[cfg(test)]\nmod tests {{\n    use super::{{ {}, {} }};\n\n    #[test]\n\
+                    {}    fn should_accept_valid_values() {{\n        \
+                    assert!({}::try_new({}).is_ok());\n    }}\n{}}}\n",
+                    type_name,
+                    error_name,
+                    ignore_attribute,
+                    type_name,
+                    valid_sample,
+                    value_rules
+                        .iter()
+                        .filter_map(|rule| {
+                            rule.invalid_sample.as_ref().map(|invalid_sample| {
+                                format!(
+                                    "\n    #[test]\n    \
+                                    fn should_reject_{}_values() {{\n        \
+                                    assert_eq!(\n            \
+                                    {}::try_new({}).unwrap_err(),\n            {}::{}\n        \
+                                    );\n    }}\n",
+                                    Self::get_snake_case(&rule.variant),
+                                    type_name,
+                                    invalid_sample,
+                                    error_name,
+                                    rule.variant
+                                )
+                            })
+                        })
+                        .collect::<String>()
+                )
+            );
+        }
+        Ok(Some(content))
+    }
+
+    fn get_snake_case(name: &str) -> String {
+        name.chars()
+            .enumerate()
+            .flat_map(|(index, character)| {
+                let separator = (index > 0 && character.is_uppercase()).then_some('_');
+                separator.into_iter().chain(character.to_lowercase())
+            })
+            .collect()
+    }
+}
+
+impl ComponentGenerator for RustComponentGenerator {
+    fn generate_component(
+        &self,
+        component_specification: &ComponentSpecification
+    ) -> Result<Option<String>, ComponentGeneratorError> {
+        let specification = component_specification.get_value();
+        match specification.component_type {
+            ComponentType::Entities => {
+                Self::validate_options(specification, &["--field"])?;
+                Ok(Some(self.generate_entity(specification)?))
+            }
+            ComponentType::Events => {
+                Self::validate_options(specification, &["--field"])?;
+                Ok(Some(self.generate_event(specification)?))
+            }
+            ComponentType::ValueObjects => {
+                Self::validate_options(specification, &["--type", "--rule"])?;
+                self.generate_value_object(specification)
+            }
+            ComponentType::Subscribers => {
+                Self::validate_options(specification, &["--on"])?;
+                match &specification.subscribed_event {
+                    Some(subscribed_event) => {
+                        Ok(Some(self.generate_subscriber(specification, subscribed_event)?))
+                    }
+                    None => Ok(None),
+                }
+            }
+            ComponentType::Commands => {
+                Self::validate_options(specification, &["--repository", "--field"])?;
+                Ok(Some(self.generate_use_case(specification)?))
+            }
+            ComponentType::Controllers | ComponentType::Presenters => {
+                Self::validate_options(specification, &[])?;
+                match (&specification.use_case_type, &specification.use_case_name) {
+                    (Some(use_case_type), Some(use_case_name)) => {
+                        Ok(
+                            Some(match specification.component_type {
+                                ComponentType::Controllers => {
+                                    self.generate_controller(
+                                        specification,
+                                        use_case_type,
+                                        use_case_name
+                                    )
+                                }
+                                _ => {
+                                    self.generate_presenter(
+                                        specification,
+                                        use_case_type,
+                                        use_case_name
+                                    )
+                                }
+                            })
+                        )
+                    }
+                    _ => Ok(None),
+                }
+            }
+            ComponentType::Queries => {
+                match &specification.query_name {
+                    Some(query_name) => {
+                        Self::validate_options(specification, &["--repository"])?;
+                        match specification.repository_names.first() {
+                            Some(repository_name) => {
+                                Ok(
+                                    Some(
+                                        self.generate_query(
+                                            specification,
+                                            query_name,
+                                            repository_name
+                                        )
+                                    )
+                                )
+                            }
+                            None => Ok(None),
+                        }
+                    }
+                    // Queries reading through the aggregate's repository, as
+                    // CRUD ones do, take the shape of commands.
+                    None => {
+                        Self::validate_options(specification, &["--repository", "--field"])?;
+                        Ok(Some(self.generate_use_case(specification)?))
+                    }
+                }
+            }
+            // Only the find repositories of a query and the repository of a
+            // CRUD have a known shape.
+            ComponentType::Repositories => {
+                let supported_options: &[&str] = match
+                    (&specification.query_name, &specification.entity_name)
+                {
+                    (None, None) => &[],
+                    _ => &["--repository"],
+                };
+                Self::validate_options(specification, supported_options)?;
+                let implemented_repository_name = match specification.layer_name {
+                    Some(LayerName::Infrastructure) => specification.repository_names.first(),
+                    _ => None,
+                };
+                let content = match
+                    (
+                        &specification.query_name,
+                        &specification.entity_name,
+                        implemented_repository_name,
+                    )
+                {
+                    (Some(query_name), _, Some(repository_name)) => {
+                        self.generate_find_repository_implementation(
+                            specification,
+                            query_name,
+                            repository_name
+                        )
+                    }
+                    (Some(query_name), _, None) => {
+                        self.generate_find_repository(
+                            query_name,
+                            &specification.component_name.get_type_name()
+                        )
+                    }
+                    (None, Some(entity_name), Some(repository_name)) => {
+                        self.generate_in_memory_repository(
+                            specification,
+                            entity_name,
+                            repository_name
+                        )
+                    }
+                    (None, Some(entity_name), None) => {
+                        self.generate_crud_repository(specification, entity_name)
+                    }
+                    (None, None, _) => {
+                        return Ok(None);
+                    }
+                };
+                Ok(Some(content))
+            }
+            _ => {
+                Self::validate_options(specification, &[])?;
+                Ok(None)
+            }
+        }
+    }
+
+    fn generate_component_method(
+        &self,
+        component_specification: &ComponentSpecification
+    ) -> Result<String, ComponentGeneratorError> {
+        let specification = component_specification.get_value();
+        match (&specification.component_type, &specification.use_case_name) {
+            (ComponentType::Repositories, Some(use_case_name)) => {
+                Ok(self.generate_repository_method(specification, use_case_name))
+            }
+            _ => {
+                Err(
+                    ComponentGeneratorError::InvalidSpecification(
+                        "only repositories get methods, named after the use case calling them"
+                            .to_string()
+                    )
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::bounded_context::{
+            domain::{
+                enums::{ component_type::ComponentType, layer_name::LayerName },
+                services::component_generator::ComponentGenerator,
+                value_objects::{
+                    component_field::{ ComponentField, ComponentFieldValue },
+                    component_name::ComponentName,
+                    component_rule::{ ComponentRule, ComponentRuleValue },
+                    component_specification::{
+                        ComponentSpecification,
+                        ComponentSpecificationValue,
+                    },
+                    subscribed_event::{ SubscribedEvent, SubscribedEventValue },
+                },
+            },
+            infrastructure::services::rust_component_generator::RustComponentGenerator,
+        },
+        core::domain::models::value_object::ValueObject,
+    };
+
+    fn field(name: &str, type_name: &str) -> ComponentField {
+        ComponentField::new(ComponentFieldValue {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+        })
+    }
+
+    fn rule(name: &str, argument: &str) -> ComponentRule {
+        ComponentRule::new(ComponentRuleValue {
+            name: name.to_string(),
+            argument: argument.to_string(),
+        })
+    }
+
+    fn specification(
+        component_type: ComponentType,
+        component_name: &str,
+        fields: Vec<ComponentField>
+    ) -> ComponentSpecification {
+        value_specification(component_type, component_name, fields, None, vec![])
+    }
+
+    fn value_specification(
+        component_type: ComponentType,
+        component_name: &str,
+        fields: Vec<ComponentField>,
+        value_type: Option<&str>,
+        rules: Vec<ComponentRule>
+    ) -> ComponentSpecification {
+        ComponentSpecification::new(ComponentSpecificationValue {
+            bounded_context_name: "sales".to_string(),
+            aggregate_name: "order".to_string(),
+            component_type,
+            component_name: ComponentName::new(component_name.to_string()),
+            layer_name: None,
+            fields,
+            value_type: value_type.map(|value_type| value_type.to_string()),
+            rules,
+            is_aggregate_root: false,
+            subscribed_event: None,
+            value_object_names: vec![ComponentName::new("money".to_string())],
+            event_names: vec![],
+            repository_names: vec![],
+            query_name: None,
+            use_case_type: None,
+            use_case_name: None,
+            entity_name: None,
+        })
+    }
+
+    #[test]
+    fn should_generate_entities_with_fields_and_imports() {
+        let content = RustComponentGenerator.generate_component(
+            &specification(ComponentType::Entities, "order", vec![
+                field("id", "IdentityObject"),
+                field("total", "Money"),
+                field("lines", "Vec<std::time::SystemTime>"),
+                field("placed_at", "SystemTime")
+            ])
+        ).unwrap();
+        assert_eq!(
+            content.unwrap(),
+            "use std::time::SystemTime;\n\
+            use crate::core::domain::models::{ entity::Entity, identity_object::IdentityObject };\n\
+            use crate::sales::order::domain::value_objects::money::Money;\n\
+            \n\
+            pub struct Order {\n    id: IdentityObject,\n    total: Money,\n    \
+            lines: Vec<std::time::SystemTime>,\n    placed_at: SystemTime,\n}\n\
+            \n\
+            impl Order {\n    pub fn new(\n        \
+            id: IdentityObject,\n        total: Money,\n        \
+            lines: Vec<std::time::SystemTime>,\n        \
+            placed_at: SystemTime\n    ) -> Self {\n        \
+            Self {\n            id,\n            total,\n            \
+            lines,\n            placed_at,\n        \
+            }\n    }\n\
+            \n    pub fn get_total(&self) -> &Money {\n        &self.total\n    }\n\
+            \n    pub fn get_lines(&self) -> &Vec<std::time::SystemTime> {\n        \
+            &self.lines\n    }\n\
+            \n    pub fn get_placed_at(&self) -> &SystemTime {\n        &self.placed_at\n    }\n}\n\
+            \n\
+            impl Entity<IdentityObject> for Order {\n    \
+            fn get_id(&self) -> &IdentityObject {\n        \
+            &self.id\n    }\n}\n"
+        );
+        assert!(
+            RustComponentGenerator.generate_component(
+                &specification(ComponentType::Entities, "order", vec![field("Total", "Money")])
+            ).is_err()
+        );
+        assert!(
+            RustComponentGenerator.generate_component(
+                &specification(ComponentType::Entities, "order", vec![field("id", "u64")])
+            ).is_err()
+        );
+        assert!(
+            RustComponentGenerator.generate_component(
+                &specification(ComponentType::Subscribers, "notify_buyer", vec![field("id", "u32")])
+            ).is_err()
+        );
+    }
+
+    #[test]
+    fn should_generate_value_objects_with_validation_rules() {
+        let content = RustComponentGenerator.generate_component(
+            &value_specification(ComponentType::ValueObjects, "quantity", vec![], Some("u8"), vec![
+                rule("min", "1"),
+                rule("max", "255")
+            ])
+        ).unwrap();
+        assert_eq!(
+            content.unwrap(),
+            "use thiserror::Error;\n\
+            use crate::core::domain::models::value_object::ValueObject;\n\
+            \n\
+            #[derive(Error, Debug, PartialEq)]\npub enum QuantityError {\n    \
+            #[error(\"Quantity must be at least 1\")] TooSmall,\n    \
+            #[error(\"Quantity must be at most 255\")] TooLarge,\n}\n\
+            \n\
+            #[derive(Clone, Debug)]\npub struct Quantity {\n    value: u8,\n}\n\
+            \n\
+            impl Quantity {\n    \
+            // `ValueObject::new` trusts its input, values from callers go through here.\n    \
+            pub fn try_new(value: u8) -> Result<Self, QuantityError> {\n        \
+            if value < 1 {\n            return Err(QuantityError::TooSmall);\n        }\n        \
+            if value > 255 {\n            return Err(QuantityError::TooLarge);\n        }\n        \
+            Ok(Self::new(value))\n    }\n}\n\
+            \n\
+            impl ValueObject<u8> for Quantity {\n    fn new(value: u8) -> Self {\n        \
+            Self { value }\n    }\n\n    fn get_value(&self) -> &u8 {\n        &self.value\n    \
+            }\n\n    fn is_equal(&self, other: &Self) -> bool {\n        \
+            self.value == other.value\n    }\n}\n\
+            \n\
+            #[cfg(test)]\nmod tests {\n    use super::{ Quantity, QuantityError };\n\n    \
+            #[test]\n    fn should_accept_valid_values() {\n        \
+            assert!(Quantity::try_new(1).is_ok());\n    }\n\n    \
+            #[test]\n    fn should_reject_too_small_values() {\n        assert_eq!(\n            \
+            Quantity::try_new(0).unwrap_err(),\n            \
+            QuantityError::TooSmall\n        );\n    \
+            }\n}\n"
+        );
+        assert!(
+            RustComponentGenerator.generate_component(
+                &value_specification(ComponentType::ValueObjects, "email", vec![], None, vec![
+                    rule("min", "1")
+                ])
+            ).is_err()
+        );
+    }
+
+    #[test]
+    fn should_generate_value_objects_matching_a_pattern() {
+        let content = RustComponentGenerator.generate_component(
+            &value_specification(ComponentType::ValueObjects, "email", vec![], None, vec![
+                rule("regex", r"^[^@\s]+@[^@\s]+$"),
+                rule("max_len", "254")
+            ])
+        ).unwrap().unwrap();
+        assert!(content.starts_with("use std::sync::LazyLock;\nuse regex::Regex;\n"));
+        assert!(
+            content.contains(
+                "static PATTERN: LazyLock<Regex> = LazyLock::new(|| {\n    \
+                Regex::new(r\"^[^@\\s]+@[^@\\s]+$\")\
+                .expect(\"PATTERN is a valid regular expression\")\n});\n"
+            )
+        );
+        assert!(content.contains("if !PATTERN.is_match(&value) {"));
+        assert!(!content.contains("#[ignore"));
+        assert!(
+            content.contains("assert!(Email::try_new(\"user@example.com\".to_string()).is_ok());")
+        );
+        assert!(content.contains("Email::try_new(\"a\".to_string()).unwrap_err(),"));
+        assert!(
+            RustComponentGenerator.generate_component(
+                &value_specification(ComponentType::ValueObjects, "email", vec![], None, vec![
+                    rule("regex", "[a-z")
+                ])
+            ).is_err()
+        );
+    }
+
+    #[test]
+    fn should_generate_aggregate_roots_recording_their_created_event() {
+        let root_specification = ComponentSpecification::new(ComponentSpecificationValue {
+            is_aggregate_root: true,
+            event_names: vec![ComponentName::new("order_created".to_string())],
+            ..specification(ComponentType::Entities, "order", vec![]).get_value().clone()
+        });
+        let content = RustComponentGenerator.generate_component(&root_specification)
+            .unwrap()
+            .unwrap();
+        assert!(
+            content.contains("use crate::sales::order::domain::events::order_created::OrderCreated")
+        );
+        assert!(content.contains("    domain_events: Vec<Box<dyn DomainEvent>>,\n"));
+        assert!(
+            content.contains("Box::new(OrderCreated::new(aggregate_root.id.get_value()")
+        );
+        assert!(content.contains("        std::mem::take(&mut self.domain_events)\n"));
+        let event_content = RustComponentGenerator.generate_component(
+            &specification(ComponentType::Events, "order_created", vec![])
+        )
+            .unwrap()
+            .unwrap();
+        assert!(event_content.contains("impl DomainEvent for OrderCreated {"));
+        assert!(event_content.contains("        \"sales.order_created\".to_string()\n"));
+    }
+
+    #[test]
+    fn should_generate_subscribers_downcasting_their_event() {
+        let subscriber_specification = ComponentSpecification::new(ComponentSpecificationValue {
+            subscribed_event: Some(
+                SubscribedEvent::new(SubscribedEventValue {
+                    event_name: "sales.order_placed".to_string(),
+                    type_name: "OrderPlaced".to_string(),
+                    path: "src/sales/order/domain/events/order_placed.rs".to_string(),
+                })
+            ),
+            ..specification(ComponentType::Subscribers, "create_invoice", vec![])
+                .get_value()
+                .clone()
+        });
+        let content = RustComponentGenerator.generate_component(&subscriber_specification)
+            .unwrap()
+            .unwrap();
+        assert!(
+            content.contains("use crate::sales::order::domain::events::order_placed::OrderPlaced;")
+        );
+        assert!(content.contains("impl DomainEventSubscriber for CreateInvoice {"));
+        assert!(content.contains("        \"sales.order_placed\".to_string()\n"));
+        assert!(content.contains("match domain_event.as_any().downcast_ref::<OrderPlaced>() {"));
+    }
+
+    #[test]
+    fn should_generate_command_use_cases_with_their_repositories() {
+        let command_specification = ComponentSpecification::new(ComponentSpecificationValue {
+            repository_names: vec![ComponentName::new("order_repository".to_string())],
+            ..specification(ComponentType::Commands, "place_order_use_case", vec![])
+                .get_value()
+                .clone()
+        });
+        let content = RustComponentGenerator.generate_component(&command_specification)
+            .unwrap()
+            .unwrap();
+        assert!(
+            content.contains(
+                "use crate::sales::order::domain::repositories::order_repository::OrderRepository;"
+            )
+        );
+        assert!(content.contains("pub struct PlaceOrderRequestModel {}\n"));
+        assert!(content.contains("pub struct PlaceOrderResponseModel {}\n"));
+        assert!(content.contains("    order_repository: &'a dyn OrderRepository,\n"));
+        assert!(
+            content.contains(
+                "    output_port: &'a dyn UseCaseOutputPort<PlaceOrderResponseModel>,\n"
+            )
+        );
+        assert!(content.contains("        _request_model: PlaceOrderRequestModel\n"));
+        assert!(
+            content.contains(
+                "impl<'a> UseCaseInputPort<PlaceOrderRequestModel> for PlaceOrderUseCase<'a> {"
+            )
+        );
+    }
+
+    #[test]
+    fn should_generate_queries_with_their_read_model_and_find_repository() {
+        let query_specification = ComponentSpecification::new(ComponentSpecificationValue {
+            repository_names: vec![ComponentName::new("list_categories_repository".to_string())],
+            query_name: Some(ComponentName::new("list_categories_use_case".to_string())),
+            ..specification(ComponentType::Queries, "list_categories_use_case", vec![])
+                .get_value()
+                .clone()
+        });
+        let content = RustComponentGenerator.generate_component(&query_specification)
+            .unwrap()
+            .unwrap();
+        assert!(content.contains("#[derive(Clone)]\npub struct CategoryReadModel {}\n"));
+        assert!(content.contains("    pub categories: Vec<CategoryReadModel>,\n"));
+        assert!(
+            content.contains(
+                "    repository: &'a dyn ListCategoriesRepository<CategoryReadModel>,\n"
+            )
+        );
+        assert!(content.contains("let categories = self.repository.list_categories().await;"));
+
+        let repository_specification = ComponentSpecification::new(ComponentSpecificationValue {
+            component_type: ComponentType::Repositories,
+            component_name: ComponentName::new("list_categories_repository".to_string()),
+            layer_name: Some(LayerName::Domain),
+            repository_names: vec![],
+            ..query_specification.get_value().clone()
+        });
+        let content = RustComponentGenerator.generate_component(&repository_specification)
+            .unwrap()
+            .unwrap();
+        assert!(content.contains("pub enum ListCategoriesRepositoryError {\n"));
+        assert!(content.contains("to list categories: {0}\")] ListError(String),\n"));
+        assert!(
+            content.contains("pub trait ListCategoriesRepository<ReadModel>: Send + Sync {\n")
+        );
+
+        let implementation_specification = ComponentSpecification::new(
+            ComponentSpecificationValue {
+                component_name: ComponentName::new(
+                    "sqlite_list_categories_repository".to_string()
+                ),
+                layer_name: Some(LayerName::Infrastructure),
+                repository_names: vec![
+                    ComponentName::new("list_categories_repository".to_string())
+                ],
+                ..repository_specification.get_value().clone()
+            }
+        );
+        let content = RustComponentGenerator.generate_component(&implementation_specification)
+            .unwrap()
+            .unwrap();
+        assert!(
+            content.contains("::{ ListCategoriesRepository, ListCategoriesRepositoryError },\n")
+        );
+        assert!(content.contains("for SqliteListCategoriesRepository {\n"));
+    }
+
+    #[test]
+    fn should_generate_controllers_and_presenters_wired_to_their_use_case() {
+        let controller_specification = ComponentSpecification::new(ComponentSpecificationValue {
+            use_case_type: Some(ComponentType::Queries),
+            use_case_name: Some(ComponentName::new("find_orders_use_case".to_string())),
+            ..specification(ComponentType::Controllers, "find_orders_controller", vec![])
+                .get_value()
+                .clone()
+        });
+        let content = RustComponentGenerator.generate_component(&controller_specification)
+            .unwrap()
+            .unwrap();
+        assert!(
+            content.contains(
+                "use crate::sales::order::application::queries::find_orders_use_case::\
+                FindOrdersRequestModel;\n"
+            )
+        );
+        assert!(content.contains("use_case: &'a dyn UseCaseInputPort<FindOrdersRequestModel>,\n"));
+        assert!(content.contains("self.use_case.interact(FindOrdersRequestModel).await;\n"));
+
+        let presenter_specification = ComponentSpecification::new(ComponentSpecificationValue {
+            component_type: ComponentType::Presenters,
+            component_name: ComponentName::new("find_orders_presenter".to_string()),
+            use_case_type: Some(ComponentType::Commands),
+            use_case_name: Some(ComponentName::new("place_order".to_string())),
+            ..controller_specification.get_value().clone()
+        });
+        let content = RustComponentGenerator.generate_component(&presenter_specification)
+            .unwrap()
+            .unwrap();
+        assert!(
+            content.contains(
+                "impl UseCaseOutputPort<PlaceOrderResponseModel> for FindOrdersPresenter {\n"
+            )
+        );
+        assert!(content.contains("eprintln!(\"Failed to place order due to: {}\", error)"));
+        assert!(
+            RustComponentGenerator.generate_component(
+                &specification(ComponentType::Controllers, "place_order_controller", vec![])
+            )
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn should_generate_crud_repositories_with_an_in_memory_implementation() {
+        let repository_specification = ComponentSpecification::new(ComponentSpecificationValue {
+            layer_name: Some(LayerName::Domain),
+            entity_name: Some(ComponentName::new("category".to_string())),
+            ..specification(ComponentType::Repositories, "category_repository", vec![])
+                .get_value()
+                .clone()
+        });
+        let content = RustComponentGenerator.generate_component(&repository_specification)
+            .unwrap()
+            .unwrap();
+        assert!(content.contains("use crate::sales::order::domain::entities::category::Category;"));
+        assert!(content.contains("pub trait CategoryRepository: Send + Sync {\n"));
+        assert!(
+            content.contains(
+                "    async fn create_category(&self, category: Category) \
+                -> Result<(), CategoryRepositoryError>;\n"
+            )
+        );
+        assert!(content.contains("async fn list_categories(&self) -> Result<Vec<Arc<Category>>"));
+
+        let implementation_specification = ComponentSpecification::new(
+            ComponentSpecificationValue {
+                component_name: ComponentName::new("in_memory_category_repository".to_string()),
+                layer_name: Some(LayerName::Infrastructure),
+                repository_names: vec![ComponentName::new("category_repository".to_string())],
+                ..repository_specification.get_value().clone()
+            }
+        );
+        let content = RustComponentGenerator.generate_component(&implementation_specification)
+            .unwrap()
+            .unwrap();
+        assert!(content.contains("    categories: Mutex<HashMap<String, Arc<Category>>>,\n"));
+        assert!(content.contains("impl CategoryRepository for InMemoryCategoryRepository {\n"));
+        assert!(
+            content.contains("return Err(CategoryRepositoryError::AlreadyExists(id));\n")
+        );
+
+        let query_specification = ComponentSpecification::new(ComponentSpecificationValue {
+            component_type: ComponentType::Queries,
+            component_name: ComponentName::new("get_category_use_case".to_string()),
+            layer_name: None,
+            fields: vec![field("id", "String")],
+            repository_names: vec![ComponentName::new("category_repository".to_string())],
+            ..repository_specification.get_value().clone()
+        });
+        let content = RustComponentGenerator.generate_component(&query_specification)
+            .unwrap()
+            .unwrap();
+        assert!(content.contains("pub struct GetCategoryRequestModel {\n    pub id: String,\n}\n"));
+        assert!(
+            content.contains(
+                "pub struct GetCategoryResponseModel {\n    pub category: Arc<Category>,\n}\n"
+            )
+        );
+        assert!(content.contains("    category_repository: &'a dyn CategoryRepository,\n"));
+        assert!(
+            content.contains(
+                "let id = IdentityObject::new(request_model.id);\n        \
+                let category = self.category_repository.get_category(&id).await?;\n        \
+                Ok(GetCategoryResponseModel { category })\n"
+            )
+        );
+
+        let command_specification = ComponentSpecification::new(ComponentSpecificationValue {
+            component_type: ComponentType::Commands,
+            component_name: ComponentName::new("create_category_use_case".to_string()),
+            fields: vec![field("id", "String"), field("label", "String")],
+            ..query_specification.get_value().clone()
+        });
+        let content = RustComponentGenerator.generate_component(&command_specification)
+            .unwrap()
+            .unwrap();
+        assert!(
+            content.contains(
+                "let category = Category::new(IdentityObject::new(request_model.id), \
+                request_model.label);\n        \
+                self.category_repository.create_category(category).await?;\n"
+            )
+        );
+    }
+}
@@ -1 +1,2 @@
+pub mod architecture;
 pub mod bounded_context;
@@ -1,5 +1,6 @@
 use std::{ any::Any, time::SystemTime };
 
+#[allow(dead_code)]
 pub trait DomainEvent: Send + Sync {
     fn get_name(&self) -> String;
     fn get_aggregate_root_id(&self) -> &String;
@@ -4,6 +4,7 @@ use crate::core::domain::events::domain_event::DomainEvent;
 use crate::core::domain::events::domain_event_subscriber::DomainEventSubscriber;
 
 #[async_trait]
+#[allow(dead_code)]
 pub trait DomainEventBus: Send + Sync {
     async fn publish(&self, domain_events: Vec<Box<dyn DomainEvent>>) -> Result<(), Box<dyn Error>>;
     async fn add_subscribers(
@@ -3,6 +3,7 @@ use std::error::Error;
 use crate::core::domain::events::domain_event::DomainEvent;
 
 #[async_trait]
+#[allow(dead_code)]
 pub trait DomainEventSubscriber: Send + Sync {
     fn subscribed_to(&self) -> String;
     async fn on(&self, domain_event: &dyn DomainEvent) -> Result<(), Box<dyn Error>>;
@@ -8,6 +8,7 @@ use crate::core::domain::events::{
 use std::collections::HashMap;
 
 #[derive(Default)]
+#[allow(dead_code)]
 pub struct InMemoryDomainEventBus {
     subscribers: HashMap<String, Vec<Box<dyn DomainEventSubscriber>>>,
 }
@@ -1,38 +1,131 @@
 mod core;
 mod cli;
-use clap::Parser;
-use anyhow::Result;
+use clap::{ ArgGroup, Parser, ValueEnum };
+use anyhow::{ Context, Result };
 use std::sync::{ Arc, Mutex };
 use dialoguer::{ MultiSelect, Select };
-use core::application::use_case_input_port::UseCaseInputPort;
-use crate::cli::bounded_context::{
+use core::application::{
+    use_case_input_port::UseCaseInputPort,
+    use_case_output_port::UseCaseOutputPort,
+};
+use crate::cli::architecture::{
     adapters::presenters::{
-        add_aggregate_presenter::AddAggregatePresenter,
-        add_component_presenter::AddComponentPresenter,
-        create_bounded_context_presenter::CreateBoundedContextPresenter,
-        find_bounded_contexts_presenter::FindBoundedContextsPresenter,
+        check_architecture_presenter::CheckArchitecturePresenter,
+        diagnose_project_presenter::DiagnoseProjectPresenter,
+        dot_context_map_presenter::DotContextMapPresenter,
+        dot_event_flow_presenter::DotEventFlowPresenter,
+        find_project_status_presenter::FindProjectStatusPresenter,
+        generate_docs_presenter::GenerateDocsPresenter,
+        html_report_presenter::{ HtmlReportPage, HtmlReportPresenter },
+        junit_report_presenter::JunitReportPresenter,
+        mermaid_context_map_presenter::MermaidContextMapPresenter,
+        mermaid_event_flow_presenter::MermaidEventFlowPresenter,
+        plantuml_aggregate_diagram_presenter::PlantumlAggregateDiagramPresenter,
+        sarif_report_presenter::SarifReportPresenter,
+        show_aggregate_presenter::ShowAggregatePresenter,
+        structurizr_c4_presenter::StructurizrC4Presenter,
+    },
+    application::{
+        commands::{
+            diagnose_project_use_case::{
+                DiagnoseProjectRequestModel,
+                DiagnoseProjectResponseModel,
+                DiagnoseProjectUseCase,
+            },
+            generate_docs_use_case::{ GenerateDocsRequestModel, GenerateDocsUseCase },
+        },
+        queries::{
+            check_architecture_use_case::{
+                CheckArchitectureRequestModel,
+                CheckArchitectureResponseModel,
+                CheckArchitectureUseCase,
+            },
+            find_aggregate_diagram_use_case::{
+                FindAggregateDiagramRequestModel,
+                FindAggregateDiagramUseCase,
+            },
+            find_c4_model_use_case::{ FindC4ModelRequestModel, FindC4ModelUseCase },
+            find_context_map_use_case::{
+                FindContextMapRequestModel,
+                FindContextMapResponseModel,
+                FindContextMapUseCase,
+            },
+            find_event_flow_use_case::{
+                FindEventFlowRequestModel,
+                FindEventFlowResponseModel,
+                FindEventFlowUseCase,
+            },
+            find_project_status_use_case::{
+                FindProjectStatusRequestModel,
+                FindProjectStatusUseCase,
+            },
+            show_aggregate_use_case::{ ShowAggregateRequestModel, ShowAggregateUseCase },
+        },
+    },
+    infrastructure::{
+        repositories::{
+            filesystem_context_readme_repository::FilesystemContextReadmeRepository,
+            filesystem_project_config_repository::FilesystemProjectConfigRepository,
+            filesystem_project_structure_repository::FilesystemProjectStructureRepository,
+            filesystem_source_file_repository::FilesystemSourceFileRepository,
+        },
+        services::lexical_source_analyzer::LexicalSourceAnalyzer,
+    },
+};
+use crate::cli::bounded_context::{
+    adapters::{
+        controllers::{
+            diff_model_controller::DiffModelController,
+            import_model_controller::ImportModelController,
+        },
+        presenters::{
+            add_aggregate_presenter::AddAggregatePresenter,
+            add_component_presenter::AddComponentPresenter,
+            add_crud_presenter::AddCrudPresenter,
+            create_bounded_context_presenter::CreateBoundedContextPresenter,
+            explain_layers_presenter::ExplainLayersPresenter,
+            diff_model_presenter::DiffModelPresenter,
+            export_model_presenter::ExportModelPresenter,
+            find_bounded_contexts_presenter::FindBoundedContextsPresenter,
+            find_components_presenter::FindComponentsPresenter,
+            find_project_tree_presenter::FindProjectTreePresenter,
+            import_model_presenter::ImportModelPresenter,
+        },
     },
     application::{
         commands::{
             add_aggregate_use_case::{
                 AddAggregateLayerRequestModel,
                 AddAggregateRequestModel,
+                AddAggregateRootRequestModel,
                 AddAggregateUseCase,
             },
             add_component_use_case::{
                 AddComponentRequestModel,
                 AddComponentUseCase,
+                ComponentFieldRequestModel,
                 ComponentRequestModel,
+                ComponentRuleRequestModel,
+                SubscribedEventRequestModel,
             },
+            add_crud_use_case::{ AddCrudRequestModel, AddCrudUseCase },
             create_bounded_context_use_case::{
                 CreateBoundedContextRequestModel,
                 CreateBoundedContextUseCase,
             },
+            import_model_use_case::ImportModelUseCase,
         },
-        queries::find_bounded_contexts_use_case::{
-            BoundedContextReadModel,
-            FindBoundedContextsRequestModel,
-            FindBoundedContextsUseCase,
+        queries::{
+            explain_layers_use_case::{ ExplainLayersRequestModel, ExplainLayersUseCase },
+            diff_model_use_case::DiffModelUseCase,
+            export_model_use_case::{ ExportModelRequestModel, ExportModelUseCase },
+            find_bounded_contexts_use_case::{
+                BoundedContextReadModel,
+                FindBoundedContextsRequestModel,
+                FindBoundedContextsUseCase,
+            },
+            find_components_use_case::{ FindComponentsRequestModel, FindComponentsUseCase },
+            find_project_tree_use_case::{ FindProjectTreeRequestModel, FindProjectTreeUseCase },
         },
     },
     domain::{
@@ -43,8 +136,13 @@ use crate::cli::bounded_context::{
         repositories::{
             filesystem_bounded_context_repository::FilesystemBoundedContextRepository,
             filesystem_find_bounded_contexts_repository::FilesystemFindBoundedContextsRepository,
+            filesystem_find_project_tree_repository::FilesystemFindProjectTreeRepository,
+            filesystem_glossary_repository::FilesystemGlossaryRepository,
+        },
+        services::{
+            filesystem_project_service::FilesystemProjectService,
+            rust_component_generator::RustComponentGenerator,
         },
-        services::filesystem_project_service::FilesystemProjectService,
     },
 };
 
@@ -52,9 +150,136 @@ use crate::cli::bounded_context::{
 enum ArpeggioCommand {
     New(NewCommand),
     Add(AddCommand),
+    Check(CheckCommand),
+    Doctor(DoctorCommand),
+    Explain(ExplainCommand),
+    List(FindCommand),
+    Tree(FindCommand),
+    Show(ShowCommand),
+    Status,
+    Export,
+    Import(ImportCommand),
+    Sync(SpecCommand),
+    Diff(SpecCommand),
+    Graph(GraphCommand),
+    Report(ReportCommand),
+    Docs,
     Version,
 }
 
+#[derive(Parser)]
+struct ImportCommand {
+    model_path: String,
+}
+
+#[derive(Parser)]
+struct SpecCommand {
+    #[clap(long, default_value = "arpeggio.spec.json")]
+    spec: String,
+}
+
+#[derive(Clone, ValueEnum)]
+enum ContextMapFormat {
+    Dot,
+    Mermaid,
+}
+
+#[derive(Parser)]
+struct ContextMapCommand {
+    #[clap(long, value_enum, default_value_t = ContextMapFormat::Mermaid)]
+    format: ContextMapFormat,
+}
+
+#[derive(Clone, ValueEnum)]
+enum C4Format {
+    Structurizr,
+}
+
+#[derive(Parser)]
+struct C4Command {
+    #[clap(long, value_enum, default_value_t = C4Format::Structurizr)]
+    format: C4Format,
+}
+
+#[derive(Clone, ValueEnum)]
+enum AggregateDiagramFormat {
+    Plantuml,
+}
+
+#[derive(Parser)]
+struct AggregateDiagramCommand {
+    aggregate_name: String,
+    bounded_context_name: Option<String>,
+    #[clap(long, value_enum, default_value_t = AggregateDiagramFormat::Plantuml)]
+    format: AggregateDiagramFormat,
+}
+
+#[derive(Clone, ValueEnum)]
+enum EventFlowFormat {
+    Dot,
+    Mermaid,
+}
+
+#[derive(Parser)]
+struct EventFlowCommand {
+    #[clap(long, value_enum, default_value_t = EventFlowFormat::Mermaid)]
+    format: EventFlowFormat,
+}
+
+#[derive(Parser)]
+enum GraphTopic {
+    ContextMap(ContextMapCommand),
+    C4(C4Command),
+    Aggregate(AggregateDiagramCommand),
+    Events(EventFlowCommand),
+}
+
+#[derive(Parser)]
+struct GraphCommand {
+    #[clap(subcommand)]
+    topic: GraphTopic,
+}
+
+#[derive(Parser)]
+struct ReportCommand {
+    #[clap(long)]
+    html: String,
+    #[clap(long)]
+    source_url: Option<String>,
+}
+
+#[derive(Parser)]
+enum ShowTopic {
+    Aggregate(AggregateCommand),
+}
+
+#[derive(Parser)]
+struct ShowCommand {
+    #[clap(subcommand)]
+    topic: ShowTopic,
+}
+
+#[derive(Parser)]
+struct FindCommand {
+    #[clap(long = "type")]
+    component_type: Option<ComponentType>,
+    #[clap(long)]
+    layer: Option<LayerName>,
+    #[clap(long)]
+    context: Option<String>,
+}
+
+#[derive(Parser)]
+enum ExplainTopic {
+    Layers,
+}
+
+#[derive(Parser)]
+struct ExplainCommand {
+    #[clap(subcommand)]
+    topic: ExplainTopic,
+}
+
 #[derive(Parser)]
 enum AddComponentCommand {
     BoundedContext(BoundedContextCommand),
@@ -69,6 +294,8 @@ enum AddComponentCommand {
     Repository(ComponentCommand),
     Service(ComponentCommand),
     ValueObject(ComponentCommand),
+    Feature(FeatureCommand),
+    Crud(CrudCommand),
 }
 
 #[derive(Parser)]
@@ -80,6 +307,10 @@ struct BoundedContextCommand {
 struct AggregateCommand {
     aggregate_name: String,
     bounded_context_name: Option<String>,
+    #[clap(long)]
+    root: bool,
+    #[clap(long = "event", requires = "root")]
+    events: Vec<String>,
 }
 
 #[derive(Parser)]
@@ -87,6 +318,27 @@ struct NewCommand {
     project_name: String,
 }
 
+#[derive(Clone, ValueEnum)]
+enum ReportFormat {
+    Human,
+    Sarif,
+    Junit,
+}
+
+#[derive(Parser)]
+struct CheckCommand {
+    #[clap(long, value_enum, default_value_t = ReportFormat::Human)]
+    format: ReportFormat,
+}
+
+#[derive(Parser)]
+struct DoctorCommand {
+    #[clap(long)]
+    fix: bool,
+    #[clap(long, value_enum, default_value_t = ReportFormat::Human)]
+    format: ReportFormat,
+}
+
 #[derive(Parser)]
 struct AddCommand {
     #[clap(subcommand)]
@@ -98,6 +350,68 @@ struct ComponentCommand {
     component_name: String,
     aggregate_name: Option<String>,
     bounded_context_name: Option<String>,
+    #[clap(long)]
+    layer: Option<LayerName>,
+    #[clap(long = "field", value_parser = parse_component_field)]
+    fields: Vec<ComponentFieldRequestModel>,
+    #[clap(long = "type")]
+    value_type: Option<String>,
+    #[clap(long = "rule", value_parser = parse_component_rule)]
+    rules: Vec<ComponentRuleRequestModel>,
+    #[clap(long)]
+    on: Option<String>,
+    #[clap(long, requires = "on")]
+    from: Option<String>,
+    #[clap(long = "repository")]
+    repositories: Vec<String>,
+    #[clap(long)]
+    implementation: Option<String>,
+}
+
+#[derive(Parser)]
+#[clap(group(ArgGroup::new("kind").required(true).args(["command", "query"])))]
+struct FeatureCommand {
+    feature_name: String,
+    aggregate_name: Option<String>,
+    bounded_context_name: Option<String>,
+    #[clap(long)]
+    command: bool,
+    #[clap(long)]
+    query: bool,
+    #[clap(long = "repository", conflicts_with = "query")]
+    repositories: Vec<String>,
+    #[clap(long)]
+    implementation: Option<String>,
+}
+
+#[derive(Parser)]
+struct CrudCommand {
+    aggregate_name: String,
+    bounded_context_name: Option<String>,
+}
+
+fn parse_component_field(field: &str) -> Result<ComponentFieldRequestModel, String> {
+    match field.split_once(':') {
+        Some((name, type_name)) if !name.is_empty() && !type_name.is_empty() => {
+            Ok(ComponentFieldRequestModel {
+                name: name.to_string(),
+                type_name: type_name.to_string(),
+            })
+        }
+        _ => Err(format!("expected <NAME:TYPE>, got <{}>", field)),
+    }
+}
+
+fn parse_component_rule(rule: &str) -> Result<ComponentRuleRequestModel, String> {
+    match rule.split_once(':') {
+        Some((name, argument)) if !name.is_empty() && !argument.is_empty() => {
+            Ok(ComponentRuleRequestModel {
+                name: name.to_string(),
+                argument: argument.to_string(),
+            })
+        }
+        _ => Err(format!("expected <NAME:ARGUMENT>, got <{}>", rule)),
+    }
 }
 
 #[derive(Parser)]
@@ -115,6 +429,57 @@ fn ask_option_selection(options: Vec<String>) -> Result<String> {
     Ok(options[selection].to_string())
 }
 
+async fn get_bounded_context_name(bounded_context_name: Option<String>) -> Result<String> {
+    if let Some(bounded_context_name) = bounded_context_name {
+        return Ok(bounded_context_name);
+    }
+    let bounded_contexts = get_bounded_contexts().await?;
+    if bounded_contexts.is_empty() {
+        return Err(anyhow::anyhow!("No bounded contexts found"));
+    }
+    ask_option_selection(
+        bounded_contexts
+            .iter()
+            .map(|bc| bc.name.clone())
+            .collect()
+    )
+}
+
+async fn get_aggregate_name(
+    aggregate_name: Option<String>,
+    bounded_context_name: &str
+) -> Result<String> {
+    if let Some(aggregate_name) = aggregate_name {
+        return Ok(aggregate_name);
+    }
+    let bounded_context = get_bounded_contexts()
+        .await?
+        .into_iter()
+        .find(|bc| bc.name == bounded_context_name)
+        .ok_or_else(|| anyhow::anyhow!("Bounded context <{}> not found", bounded_context_name))?;
+    ask_option_selection(bounded_context.aggregates)
+}
+
+async fn get_aggregate_bounded_context_name(
+    aggregate_name: &str,
+    bounded_context_name: Option<String>
+) -> Result<String> {
+    if let Some(bounded_context_name) = bounded_context_name {
+        return Ok(bounded_context_name);
+    }
+    let mut bounded_context_names = get_bounded_contexts()
+        .await?
+        .iter()
+        .filter(|bc| bc.aggregates.iter().any(|aggregate| aggregate == aggregate_name))
+        .map(|bc| bc.name.clone())
+        .collect::<Vec<_>>();
+    match bounded_context_names.len() {
+        0 => Err(anyhow::anyhow!("Aggregate <{}> not found", aggregate_name)),
+        1 => Ok(bounded_context_names.remove(0)),
+        _ => ask_option_selection(bounded_context_names),
+    }
+}
+
 async fn get_bounded_contexts() -> Result<Vec<BoundedContextReadModel>> {
     let caught_bounded_contexts = Arc::new(Mutex::new(Vec::new()));
     let filesystem_find_bounded_contexts_repository = FilesystemFindBoundedContextsRepository;
@@ -170,50 +535,172 @@ fn ask_aggregate_layers() -> Result<Option<Vec<AddAggregateLayerRequestModel>>>
 async fn add_component(
     component_command: ComponentCommand,
     component_type: ComponentType,
-    bounded_contexts: Vec<BoundedContextReadModel>,
     bounded_context_repository: &FilesystemBoundedContextRepository
 ) -> Result<()> {
-    if bounded_contexts.is_empty() {
-        return Err(anyhow::anyhow!("No bounded contexts found"));
-    }
     let add_component_use_case = AddComponentUseCase::new(
         bounded_context_repository,
+        &FilesystemGlossaryRepository,
+        &RustComponentGenerator,
+        &FilesystemSourceFileRepository,
+        &LexicalSourceAnalyzer,
+        &FilesystemProjectConfigRepository,
         &AddComponentPresenter
     );
-    let bounded_context_name = component_command.bounded_context_name.unwrap_or(
-        ask_option_selection(
-            bounded_contexts
-                .iter()
-                .map(|bc| bc.name.clone())
-                .collect()
-        )?
-    );
-    let aggregate_name = component_command.aggregate_name.unwrap_or(
-        ask_option_selection(
-            bounded_contexts
-                .iter()
-                .find(|bc| bc.name == bounded_context_name)
-                .unwrap()
-                .aggregates.to_vec()
-        )?
-    );
+    let bounded_context_name = get_bounded_context_name(
+        component_command.bounded_context_name
+    ).await?;
+    let aggregate_name = get_aggregate_name(
+        component_command.aggregate_name,
+        &bounded_context_name
+    ).await?;
     add_component_use_case.interact(AddComponentRequestModel {
         bounded_context_name,
         aggregate_name,
         component: ComponentRequestModel {
             component_type,
             component_name: component_command.component_name,
+            layer_name: component_command.layer,
+            fields: component_command.fields,
+            value_type: component_command.value_type,
+            rules: component_command.rules,
+            repository_names: (!component_command.repositories.is_empty()).then_some(
+                component_command.repositories
+            ),
+            implementation_name: component_command.implementation,
+            is_feature: false,
+            subscribed_event: component_command.on.map(|event| SubscribedEventRequestModel {
+                event,
+                module_name: component_command.from,
+            }),
         },
     }).await;
     Ok(())
 }
 
+async fn add_feature(
+    feature_command: FeatureCommand,
+    bounded_context_repository: &FilesystemBoundedContextRepository
+) -> Result<()> {
+    let add_component_use_case = AddComponentUseCase::new(
+        bounded_context_repository,
+        &FilesystemGlossaryRepository,
+        &RustComponentGenerator,
+        &FilesystemSourceFileRepository,
+        &LexicalSourceAnalyzer,
+        &FilesystemProjectConfigRepository,
+        &AddComponentPresenter
+    );
+    let bounded_context_name = get_bounded_context_name(
+        feature_command.bounded_context_name
+    ).await?;
+    let aggregate_name = get_aggregate_name(
+        feature_command.aggregate_name,
+        &bounded_context_name
+    ).await?;
+    add_component_use_case.interact(AddComponentRequestModel {
+        bounded_context_name,
+        aggregate_name,
+        component: ComponentRequestModel {
+            component_type: match feature_command.query {
+                true => ComponentType::Queries,
+                false => ComponentType::Commands,
+            },
+            component_name: feature_command.feature_name,
+            layer_name: None,
+            fields: vec![],
+            value_type: None,
+            rules: vec![],
+            repository_names: (!feature_command.repositories.is_empty()).then_some(
+                feature_command.repositories
+            ),
+            implementation_name: feature_command.implementation,
+            subscribed_event: None,
+            is_feature: true,
+        },
+    }).await;
+    Ok(())
+}
+
+fn read_model_document(model_path: &str) -> Result<String> {
+    std::fs
+        ::read_to_string(model_path)
+        .with_context(|| format!("Failed to read <{}>", model_path))
+}
+
+async fn import_model(model_path: &str) -> Result<()> {
+    let content = read_model_document(model_path)?;
+    let import_model_presenter = ImportModelPresenter;
+    let import_model_use_case = ImportModelUseCase::new(
+        &FilesystemBoundedContextRepository,
+        &FilesystemGlossaryRepository,
+        &import_model_presenter
+    );
+    let import_model_controller = ImportModelController::new(&import_model_use_case);
+    import_model_controller.import_model(&content).await
+}
+
+async fn find_context_map(output_port: &dyn UseCaseOutputPort<FindContextMapResponseModel>) {
+    let find_context_map_use_case = FindContextMapUseCase::new(
+        &FilesystemSourceFileRepository,
+        &FilesystemProjectConfigRepository,
+        &LexicalSourceAnalyzer,
+        output_port
+    );
+    find_context_map_use_case.interact(FindContextMapRequestModel).await;
+}
+
+async fn find_event_flow(output_port: &dyn UseCaseOutputPort<FindEventFlowResponseModel>) {
+    let find_event_flow_use_case = FindEventFlowUseCase::new(
+        &FilesystemSourceFileRepository,
+        &LexicalSourceAnalyzer,
+        output_port
+    );
+    find_event_flow_use_case.interact(FindEventFlowRequestModel).await;
+}
+
+fn write_report_site(directory: &str, pages: &[HtmlReportPage]) -> Result<()> {
+    for page in pages {
+        let page_path = std::path::Path::new(directory).join(&page.path);
+        if let Some(parent) = page_path.parent() {
+            std::fs
+                ::create_dir_all(parent)
+                .with_context(|| format!("Failed to create <{}>", parent.display()))?;
+        }
+        std::fs
+            ::write(&page_path, &page.content)
+            .with_context(|| format!("Failed to write <{}>", page_path.display()))?;
+    }
+    Ok(())
+}
+
+async fn check_architecture(
+    output_port: &dyn UseCaseOutputPort<CheckArchitectureResponseModel>
+) {
+    let check_architecture_use_case = CheckArchitectureUseCase::new(
+        &FilesystemSourceFileRepository,
+        &FilesystemGlossaryRepository,
+        &LexicalSourceAnalyzer,
+        output_port
+    );
+    check_architecture_use_case.interact(CheckArchitectureRequestModel).await;
+}
+
+async fn diagnose_project(
+    fix: bool,
+    output_port: &dyn UseCaseOutputPort<DiagnoseProjectResponseModel>
+) {
+    let diagnose_project_use_case = DiagnoseProjectUseCase::new(
+        &FilesystemProjectStructureRepository,
+        output_port
+    );
+    diagnose_project_use_case.interact(DiagnoseProjectRequestModel { fix }).await;
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let project_service = FilesystemProjectService;
     let filesystem_bounded_context_repository = FilesystemBoundedContextRepository;
     let args = ArpeggioCli::parse();
-    let bounded_contexts = get_bounded_contexts().await?;
     match args.command {
         ArpeggioCommand::Version => {
             println!("Arpeggio CLI v{}", env!("CARGO_PKG_VERSION"));
@@ -222,6 +709,242 @@ async fn main() -> Result<()> {
             println!("Creating project...");
             project_service.create_project(&new_command.project_name).await?;
         }
+        ArpeggioCommand::Check(check_command) => {
+            let has_failed = match check_command.format {
+                ReportFormat::Human => {
+                    let check_architecture_presenter = CheckArchitecturePresenter::default();
+                    check_architecture(&check_architecture_presenter).await;
+                    check_architecture_presenter.has_failed()
+                }
+                ReportFormat::Sarif => {
+                    let sarif_report_presenter = SarifReportPresenter::default();
+                    check_architecture(&sarif_report_presenter).await;
+                    sarif_report_presenter.has_failed()
+                }
+                ReportFormat::Junit => {
+                    let junit_report_presenter = JunitReportPresenter::default();
+                    check_architecture(&junit_report_presenter).await;
+                    junit_report_presenter.has_failed()
+                }
+            };
+            if has_failed {
+                return Err(anyhow::anyhow!("Architecture check failed"));
+            }
+        }
+        ArpeggioCommand::Doctor(doctor_command) => {
+            let has_failed = match doctor_command.format {
+                ReportFormat::Human => {
+                    let diagnose_project_presenter = DiagnoseProjectPresenter::default();
+                    diagnose_project(doctor_command.fix, &diagnose_project_presenter).await;
+                    diagnose_project_presenter.has_failed()
+                }
+                ReportFormat::Sarif => {
+                    let sarif_report_presenter = SarifReportPresenter::default();
+                    diagnose_project(doctor_command.fix, &sarif_report_presenter).await;
+                    sarif_report_presenter.has_failed()
+                }
+                ReportFormat::Junit => {
+                    let junit_report_presenter = JunitReportPresenter::default();
+                    diagnose_project(doctor_command.fix, &junit_report_presenter).await;
+                    junit_report_presenter.has_failed()
+                }
+            };
+            if has_failed {
+                return Err(anyhow::anyhow!("Project diagnosis found structural issues"));
+            }
+        }
+        ArpeggioCommand::Explain(explain_command) => {
+            match explain_command.topic {
+                ExplainTopic::Layers => {
+                    let explain_layers_presenter = ExplainLayersPresenter;
+                    let explain_layers_use_case = ExplainLayersUseCase::new(
+                        &explain_layers_presenter
+                    );
+                    explain_layers_use_case.interact(ExplainLayersRequestModel).await;
+                }
+            }
+        }
+        ArpeggioCommand::List(find_command) => {
+            let find_components_presenter = FindComponentsPresenter;
+            let find_components_use_case = FindComponentsUseCase::new(
+                &FilesystemFindProjectTreeRepository,
+                &find_components_presenter
+            );
+            find_components_use_case.interact(FindComponentsRequestModel {
+                bounded_context_name: find_command.context,
+                layer_name: find_command.layer,
+                component_type: find_command.component_type,
+            }).await;
+        }
+        ArpeggioCommand::Tree(find_command) => {
+            let find_project_tree_presenter = FindProjectTreePresenter;
+            let find_project_tree_use_case = FindProjectTreeUseCase::new(
+                &FilesystemFindProjectTreeRepository,
+                &find_project_tree_presenter
+            );
+            find_project_tree_use_case.interact(FindProjectTreeRequestModel {
+                bounded_context_name: find_command.context,
+                layer_name: find_command.layer,
+                component_type: find_command.component_type,
+            }).await;
+        }
+        ArpeggioCommand::Show(show_command) => {
+            match show_command.topic {
+                ShowTopic::Aggregate(aggregate_command) => {
+                    let bounded_context_name = get_aggregate_bounded_context_name(
+                        &aggregate_command.aggregate_name,
+                        aggregate_command.bounded_context_name
+                    ).await?;
+                    let show_aggregate_presenter = ShowAggregatePresenter;
+                    let show_aggregate_use_case = ShowAggregateUseCase::new(
+                        &filesystem_bounded_context_repository,
+                        &FilesystemSourceFileRepository,
+                        &LexicalSourceAnalyzer,
+                        &show_aggregate_presenter
+                    );
+                    show_aggregate_use_case.interact(ShowAggregateRequestModel {
+                        bounded_context_name,
+                        aggregate_name: aggregate_command.aggregate_name,
+                    }).await;
+                }
+            }
+        }
+        ArpeggioCommand::Status => {
+            let find_project_status_presenter = FindProjectStatusPresenter;
+            let find_project_status_use_case = FindProjectStatusUseCase::new(
+                &FilesystemSourceFileRepository,
+                &LexicalSourceAnalyzer,
+                &find_project_status_presenter
+            );
+            find_project_status_use_case.interact(FindProjectStatusRequestModel).await;
+        }
+        ArpeggioCommand::Graph(graph_command) => {
+            match graph_command.topic {
+                GraphTopic::ContextMap(context_map_command) => {
+                    match context_map_command.format {
+                        ContextMapFormat::Dot => {
+                            find_context_map(&DotContextMapPresenter).await;
+                        }
+                        ContextMapFormat::Mermaid => {
+                            find_context_map(&MermaidContextMapPresenter).await;
+                        }
+                    }
+                }
+                GraphTopic::C4(c4_command) => {
+                    match c4_command.format {
+                        C4Format::Structurizr => {
+                            let structurizr_c4_presenter = StructurizrC4Presenter;
+                            let find_c4_model_use_case = FindC4ModelUseCase::new(
+                                &FilesystemSourceFileRepository,
+                                &FilesystemProjectConfigRepository,
+                                &LexicalSourceAnalyzer,
+                                &structurizr_c4_presenter
+                            );
+                            find_c4_model_use_case.interact(FindC4ModelRequestModel).await;
+                        }
+                    }
+                }
+                GraphTopic::Aggregate(aggregate_diagram_command) => {
+                    let bounded_context_name = get_aggregate_bounded_context_name(
+                        &aggregate_diagram_command.aggregate_name,
+                        aggregate_diagram_command.bounded_context_name
+                    ).await?;
+                    match aggregate_diagram_command.format {
+                        AggregateDiagramFormat::Plantuml => {
+                            let plantuml_presenter = PlantumlAggregateDiagramPresenter;
+                            let find_aggregate_diagram_use_case = FindAggregateDiagramUseCase::new(
+                                &filesystem_bounded_context_repository,
+                                &FilesystemSourceFileRepository,
+                                &LexicalSourceAnalyzer,
+                                &plantuml_presenter
+                            );
+                            find_aggregate_diagram_use_case.interact(
+                                FindAggregateDiagramRequestModel {
+                                    bounded_context_name,
+                                    aggregate_name: aggregate_diagram_command.aggregate_name,
+                                }
+                            ).await;
+                        }
+                    }
+                }
+                GraphTopic::Events(event_flow_command) => {
+                    match event_flow_command.format {
+                        EventFlowFormat::Dot => {
+                            find_event_flow(&DotEventFlowPresenter).await;
+                        }
+                        EventFlowFormat::Mermaid => {
+                            find_event_flow(&MermaidEventFlowPresenter).await;
+                        }
+                    }
+                }
+            }
+        }
+        ArpeggioCommand::Report(report_command) => {
+            let source_url = match report_command.source_url {
+                Some(source_url) => source_url,
+                None => format!("file://{}", std::env::current_dir()?.display()),
+            };
+            let html_report_presenter = HtmlReportPresenter::new(source_url);
+            let export_model_use_case = ExportModelUseCase::new(
+                &FilesystemFindBoundedContextsRepository,
+                &filesystem_bounded_context_repository,
+                &html_report_presenter
+            );
+            export_model_use_case.interact(ExportModelRequestModel).await;
+            find_context_map(&html_report_presenter).await;
+            find_event_flow(&html_report_presenter).await;
+            if html_report_presenter.has_failed() {
+                return Err(anyhow::anyhow!("Failed to build the architecture report"));
+            }
+            let pages = html_report_presenter.get_pages();
+            write_report_site(&report_command.html, &pages)?;
+            println!(
+                "Architecture report written to <{}/index.html> ({} pages)",
+                report_command.html.trim_end_matches('/'),
+                pages.len()
+            );
+        }
+        ArpeggioCommand::Docs => {
+            let generate_docs_presenter = GenerateDocsPresenter;
+            let generate_docs_use_case = GenerateDocsUseCase::new(
+                &FilesystemFindBoundedContextsRepository,
+                &filesystem_bounded_context_repository,
+                &FilesystemSourceFileRepository,
+                &LexicalSourceAnalyzer,
+                &FilesystemContextReadmeRepository,
+                &generate_docs_presenter
+            );
+            generate_docs_use_case.interact(GenerateDocsRequestModel).await;
+        }
+        ArpeggioCommand::Export => {
+            let export_model_presenter = ExportModelPresenter;
+            let export_model_use_case = ExportModelUseCase::new(
+                &FilesystemFindBoundedContextsRepository,
+                &filesystem_bounded_context_repository,
+                &export_model_presenter
+            );
+            export_model_use_case.interact(ExportModelRequestModel).await;
+        }
+        ArpeggioCommand::Import(import_command) => {
+            import_model(&import_command.model_path).await?;
+        }
+        ArpeggioCommand::Sync(spec_command) => {
+            import_model(&spec_command.spec).await?;
+        }
+        ArpeggioCommand::Diff(spec_command) => {
+            let content = read_model_document(&spec_command.spec)?;
+            let diff_model_presenter = DiffModelPresenter::default();
+            let diff_model_use_case = DiffModelUseCase::new(
+                &FilesystemFindBoundedContextsRepository,
+                &filesystem_bounded_context_repository,
+                &diff_model_presenter
+            );
+            let diff_model_controller = DiffModelController::new(&diff_model_use_case);
+            diff_model_controller.diff_model(&content).await?;
+            if diff_model_presenter.has_failed() {
+                return Err(anyhow::anyhow!("Project drifted from <{}>", spec_command.spec));
+            }
+        }
         ArpeggioCommand::Add(add_command) => {
             match add_command.component {
                 AddComponentCommand::BoundedContext(command) => {
@@ -236,22 +959,16 @@ async fn main() -> Result<()> {
                     }).await;
                 }
                 AddComponentCommand::Aggregate(aggregate_command) => {
-                    if bounded_contexts.is_empty() {
-                        return Err(anyhow::anyhow!("No bounded contexts found"));
-                    }
                     let add_aggregate_presenter = AddAggregatePresenter;
                     let add_aggregate_use_case = AddAggregateUseCase::new(
                         &filesystem_bounded_context_repository,
+                        &FilesystemGlossaryRepository,
+                        &RustComponentGenerator,
                         &add_aggregate_presenter
                     );
-                    let bounded_context_name = aggregate_command.bounded_context_name.unwrap_or(
-                        ask_option_selection(
-                            bounded_contexts
-                                .iter()
-                                .map(|bc| bc.name.clone())
-                                .collect()
-                        )?
-                    );
+                    let bounded_context_name = get_bounded_context_name(
+                        aggregate_command.bounded_context_name
+                    ).await?;
                     let is_default_layers_mode = ask_is_default_layers_mode()?;
                     let mut aggregate_layers: Option<Vec<AddAggregateLayerRequestModel>> = None;
                     if !is_default_layers_mode {
@@ -261,13 +978,17 @@ async fn main() -> Result<()> {
                         aggregate_name: aggregate_command.aggregate_name,
                         bounded_context_name,
                         aggregate_layers,
+                        aggregate_root: aggregate_command.root.then_some(
+                            AddAggregateRootRequestModel {
+                                event_names: aggregate_command.events,
+                            }
+                        ),
                     }).await;
                 }
                 AddComponentCommand::Controller(component_command) => {
                     add_component(
                         component_command,
                         ComponentType::Controllers,
-                        bounded_contexts,
                         &filesystem_bounded_context_repository
                     ).await?;
                 }
@@ -275,7 +996,6 @@ async fn main() -> Result<()> {
                     add_component(
                         component_command,
                         ComponentType::Presenters,
-                        bounded_contexts,
                         &filesystem_bounded_context_repository
                     ).await?;
                 }
@@ -283,7 +1003,6 @@ async fn main() -> Result<()> {
                     add_component(
                         component_command,
                         ComponentType::Commands,
-                        bounded_contexts,
                         &filesystem_bounded_context_repository
                     ).await?;
                 }
@@ -291,7 +1010,6 @@ async fn main() -> Result<()> {
                     add_component(
                         component_command,
                         ComponentType::Queries,
-                        bounded_contexts,
                         &filesystem_bounded_context_repository
                     ).await?;
                 }
@@ -299,7 +1017,6 @@ async fn main() -> Result<()> {
                     add_component(
                         component_command,
                         ComponentType::Subscribers,
-                        bounded_contexts,
                         &filesystem_bounded_context_repository
                     ).await?;
                 }
@@ -307,7 +1024,6 @@ async fn main() -> Result<()> {
                     add_component(
                         component_command,
                         ComponentType::Entities,
-                        bounded_contexts,
                         &filesystem_bounded_context_repository
                     ).await?;
                 }
@@ -315,7 +1031,6 @@ async fn main() -> Result<()> {
                     add_component(
                         component_command,
                         ComponentType::Events,
-                        bounded_contexts,
                         &filesystem_bounded_context_repository
                     ).await?;
                 }
@@ -323,7 +1038,6 @@ async fn main() -> Result<()> {
                     add_component(
                         component_command,
                         ComponentType::Repositories,
-                        bounded_contexts,
                         &filesystem_bounded_context_repository
                     ).await?;
                 }
@@ -331,7 +1045,6 @@ async fn main() -> Result<()> {
                     add_component(
                         component_command,
                         ComponentType::Services,
-                        bounded_contexts,
                         &filesystem_bounded_context_repository
                     ).await?;
                 }
@@ -339,10 +1052,29 @@ async fn main() -> Result<()> {
                     add_component(
                         component_command,
                         ComponentType::ValueObjects,
-                        bounded_contexts,
                         &filesystem_bounded_context_repository
                     ).await?;
                 }
+                AddComponentCommand::Feature(feature_command) => {
+                    add_feature(feature_command, &filesystem_bounded_context_repository).await?;
+                }
+                AddComponentCommand::Crud(crud_command) => {
+                    let add_crud_use_case = AddCrudUseCase::new(
+                        &filesystem_bounded_context_repository,
+                        &FilesystemGlossaryRepository,
+                        &RustComponentGenerator,
+                        &FilesystemSourceFileRepository,
+                        &LexicalSourceAnalyzer,
+                        &AddCrudPresenter
+                    );
+                    let bounded_context_name = get_bounded_context_name(
+                        crud_command.bounded_context_name
+                    ).await?;
+                    add_crud_use_case.interact(AddCrudRequestModel {
+                        bounded_context_name,
+                        aggregate_name: crud_command.aggregate_name,
+                    }).await;
+                }
             }
         }
     }